@@ -1,3 +1,4 @@
+use anyhow::bail;
 use creusot_args::options::*;
 use creusot_setup as setup;
 use std::{
@@ -9,13 +10,16 @@ use tempdir::TempDir;
 
 mod helpers;
 use helpers::*;
+mod prove;
+mod session;
 mod why3_launcher;
 use why3_launcher::*;
 
 enum Subcommand {
     // subcommand to pass on to creusot-rustc
     Creusot(Option<CreusotSubCommand>),
-    // subcommand to handle in cargo-creusot
+    // subcommands to handle in cargo-creusot
+    Prove { timeouts: Vec<u32>, transformations: Vec<String> },
     Setup(SetupSubCommand),
 }
 use Subcommand::*;
@@ -34,9 +38,21 @@ fn main() -> Result<()> {
         cargs.options.output_file = Some(coma_filename.to_string_lossy().into_owned());
     }
 
+    // `creusot-rustc` is not run from the current directory
+    if let Some(CargoCreusotSubCommand::Creusot(CreusotSubCommand::Why3 {
+        report: Some(report),
+        ..
+    })) = &mut cargs.subcommand
+    {
+        *report = env::current_dir()?.join(&*report);
+    }
+
     let subcommand = match cargs.subcommand {
         None => Creusot(None),
         Some(CargoCreusotSubCommand::Creusot(cmd)) => Creusot(Some(cmd)),
+        Some(CargoCreusotSubCommand::Prove { timeouts, transformations, .. }) => {
+            Prove { timeouts, transformations }
+        }
         Some(CargoCreusotSubCommand::Setup { command }) => Setup(command),
     };
 
@@ -89,6 +105,39 @@ fn main() -> Result<()> {
 
             Ok(())
         }
+        Prove { timeouts, transformations } => {
+            let config_args = setup::status_for_creusot()?;
+            let creusot_args = CreusotArgs {
+                options: cargs.options,
+                why3_path: config_args.why3_path.clone(),
+                why3_config_file: config_args.why3_config.clone(),
+                subcommand: None,
+                rust_flags: cargs.rust_flags,
+            };
+
+            invoke_cargo(&creusot_args);
+
+            let mut b = Why3LauncherBuilder::new();
+            b.why3_path(config_args.why3_path);
+            b.config_file(config_args.why3_config);
+            b.output_file(coma_filename.clone());
+            b.mode(Why3Mode::Prove);
+            let why3 = b.build()?;
+
+            let provers = setup::provers()?
+                .into_iter()
+                .map(|(name, version)| session::Prover { name: name.to_owned(), version });
+            let options = prove::ProveOptions {
+                provers: provers.collect(),
+                parallelism: config_args.provers_parallelism,
+                timeouts,
+                transformations,
+            };
+            if !prove::prove(&why3, &coma_filename, &options)? {
+                bail!("some goals could not be proved")
+            }
+            Ok(())
+        }
         Setup(SetupSubCommand::Status) => setup::status(),
         Setup(SetupSubCommand::Install { provers_parallelism, external, no_check_version }) => {
             let extflag =
@@ -0,0 +1,398 @@
+//! `cargo creusot prove`: searches for proofs of the generated coma file without user interaction,
+//! and records them in its Why3 session.
+//!
+//! Every goal is first tried with the first prover and the smallest time limit. Goals which remain
+//! unproved are then tried again with escalating time limits, first directly and then after each
+//! of the configured transformations, with every prover in turn. Proofs already recorded in the
+//! session are tried before anything else.
+//!
+//! Modules whose proofs are all recorded in the session are not proved again as long as their
+//! fingerprint does not change. The fingerprint of a module covers its text, except for source
+//! locations, and the fingerprints of the modules it uses, so that it changes whenever a
+//! declaration the module depends on changes.
+
+use super::{
+    helpers::Result,
+    session::{Goal, Proof, Prover, Session, Theory, Transf, DEFAULT_MEMLIMIT},
+    why3_launcher::{Why3Launcher, PRELUDE},
+};
+use anyhow::anyhow;
+use include_dir::Dir;
+use serde_json::Deserializer;
+use sha2::{Digest, Sha256};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    process::Stdio,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+use tempdir::TempDir;
+use why3::ce_models;
+
+pub struct ProveOptions {
+    pub provers: Vec<Prover>,
+    pub parallelism: usize,
+    /// Time limits to try, in seconds
+    pub timeouts: Vec<u32>,
+    pub transformations: Vec<String>,
+}
+
+/// A single run of a prover on a goal, or on all the goals of a theory.
+#[derive(Clone, PartialEq)]
+struct Attempt {
+    prover: Prover,
+    timelimit: u32,
+    transformation: Option<String>,
+}
+
+struct Prove<'a> {
+    launcher: &'a Why3Launcher,
+    prelude_dir: &'a Path,
+    options: &'a ProveOptions,
+    old_session: Option<Session>,
+}
+
+/// Tries to prove all the goals of `coma_file` and updates its session. Returns whether every goal
+/// was proved.
+pub fn prove(launcher: &Why3Launcher, coma_file: &Path, options: &ProveOptions) -> Result<bool> {
+    if options.provers.is_empty() || options.timeouts.is_empty() {
+        return Err(anyhow!("no prover or time limit to try"));
+    }
+    let session_dir = coma_file.with_extension("");
+    let prelude_dir = TempDir::new("creusot_why3_prelude")?;
+    launcher.extract_prelude(prelude_dir.path())?;
+    let prove = Prove {
+        launcher,
+        prelude_dir: prelude_dir.path(),
+        options,
+        old_session: Session::read(&session_dir)?,
+    };
+
+    let first = Attempt {
+        prover: options.provers[0].clone(),
+        timelimit: options.timeouts[0],
+        transformation: None,
+    };
+    let fingerprints = fingerprints(&std::fs::read_to_string(coma_file)?);
+    let cache = read_cache(&session_dir)?;
+    let cached = |name: &String| {
+        let session = prove.old_session.as_ref()?;
+        let theory = session.theory(name).filter(|theory| theory.proved())?;
+        (cache.get(name)? == &fingerprints[name]).then_some(theory)
+    };
+    let theory_names: Vec<_> = fingerprints.keys().cloned().collect();
+    let theories = parallel_map(&theory_names, options.parallelism, |theory| {
+        if let Some(cached) = cached(theory) {
+            return Ok(cached.clone());
+        }
+        let results = prove.run(theory, None, &first)?;
+        let goals =
+            results.iter().map(|result| prove.record(new_goal(result, None), &first, result));
+        Ok(Theory { name: theory.clone(), goals: goals.collect() })
+    });
+    let mut theories = theories.into_iter().collect::<Result<Vec<_>>>()?;
+
+    let pending: Vec<(usize, usize)> = theories
+        .iter()
+        .enumerate()
+        .flat_map(|(i, theory)| {
+            theory
+                .goals
+                .iter()
+                .enumerate()
+                .filter(|(_, goal)| !goal.proved())
+                .map(move |(j, _)| (i, j))
+        })
+        .collect();
+    let proved = parallel_map(&pending, options.parallelism, |&(i, j)| {
+        prove.prove_goal(&theories[i].name, theories[i].goals[j].clone(), &first)
+    });
+    for ((i, j), goal) in pending.into_iter().zip(proved) {
+        theories[i].goals[j] = goal?;
+    }
+
+    for theory in &theories {
+        if cached(&theory.name).is_some() {
+            println!("{}: unchanged", theory.name);
+            continue;
+        }
+        let proved = theory.goals.iter().filter(|goal| goal.proved()).count();
+        println!("{}: {proved}/{} goals proved", theory.name, theory.goals.len());
+        for goal in theory.goals.iter().filter(|goal| !goal.proved()) {
+            println!("  unproved: {}", goal.name);
+        }
+    }
+
+    let all_proved = theories.iter().all(Theory::proved);
+    let file = PathBuf::from("..").join(coma_file.file_name().unwrap());
+    let provers = prove.old_session.map(|session| session.provers).unwrap_or_default();
+    Session { provers, file, theories }.write(&session_dir)?;
+    std::fs::write(session_dir.join(CACHE_FILE), serde_json::to_string_pretty(&fingerprints)?)?;
+    Ok(all_proved)
+}
+
+impl Prove<'_> {
+    /// Tries the remaining attempts on `goal`, which was not proved by `first`.
+    fn prove_goal(&self, theory: &str, mut goal: Goal, first: &Attempt) -> Result<Goal> {
+        let old_goal = self.old_session.as_ref().and_then(|s| s.theory(theory)?.goal(&goal.name));
+        let mut attempts = old_goal.map(|old| self.hints(old)).unwrap_or_default();
+        for &timelimit in &self.options.timeouts {
+            let transformations = self.options.transformations.iter().cloned().map(Some);
+            for transformation in [None].into_iter().chain(transformations) {
+                for prover in &self.options.provers {
+                    let prover = prover.clone();
+                    attempts.push(Attempt {
+                        prover,
+                        timelimit,
+                        transformation: transformation.clone(),
+                    });
+                }
+            }
+        }
+
+        let mut tried = vec![first.clone()];
+        for attempt in attempts {
+            if tried.contains(&attempt) {
+                continue;
+            }
+            let results = self.run(theory, Some(&goal.name), &attempt)?;
+            goal = match &attempt.transformation {
+                None => match &results[..] {
+                    [result] => self.record(goal, &attempt, result),
+                    _ => return Err(anyhow!("expected a single result for goal {}", goal.name)),
+                },
+                Some(name) => self.record_transf(goal, name, &attempt, &results),
+            };
+            if goal.proved() {
+                // Partial results of other transformations are not worth keeping
+                goal.transfs.retain(Transf::proved);
+                return Ok(goal);
+            }
+            tried.push(attempt);
+        }
+
+        // Do not discard a proof we could not redo, it may have been made by hand in the IDE
+        match old_goal {
+            Some(old) if old.proved() => Ok(old.clone()),
+            _ => Ok(goal),
+        }
+    }
+
+    /// Attempts which proved `goal` in the previous session.
+    fn hints(&self, goal: &Goal) -> Vec<Attempt> {
+        let known = |prover: &Prover| self.options.provers.contains(prover);
+        let mut attempts = Vec::new();
+        for proof in goal.proofs.iter().filter(|proof| proof.valid() && known(&proof.prover)) {
+            let prover = proof.prover.clone();
+            attempts.push(Attempt { prover, timelimit: proof.timelimit, transformation: None });
+        }
+        // Transformations with arguments can only be applied from the IDE
+        for transf in goal.transfs.iter().filter(|t| t.proved() && t.args.is_empty()) {
+            let proofs = transf.goals.iter().flat_map(|goal| &goal.proofs);
+            let timelimit = proofs.clone().map(|proof| proof.timelimit).max().unwrap_or(0);
+            for proof in proofs.filter(|proof| known(&proof.prover)) {
+                let attempt = Attempt {
+                    prover: proof.prover.clone(),
+                    timelimit,
+                    transformation: Some(transf.name.clone()),
+                };
+                if !attempts.contains(&attempt) {
+                    attempts.push(attempt)
+                }
+            }
+        }
+        attempts
+    }
+
+    /// Records the result of `attempt` on `goal` if it is a proof.
+    fn record(&self, mut goal: Goal, attempt: &Attempt, result: &ce_models::Goal) -> Goal {
+        let ce_models::ProverResult { answer, time, step, .. } = &result.prover_result;
+        if answer == "Valid" {
+            goal.proofs.push(Proof {
+                prover: attempt.prover.clone(),
+                timelimit: attempt.timelimit,
+                memlimit: DEFAULT_MEMLIMIT,
+                status: "valid".into(),
+                time: *time as f64,
+                steps: (*step >= 0).then_some(*step as i64),
+            })
+        }
+        goal
+    }
+
+    /// Records the results of `attempt` on the subgoals generated by the transformation `name`.
+    fn record_transf(
+        &self,
+        mut goal: Goal,
+        name: &str,
+        attempt: &Attempt,
+        results: &[ce_models::Goal],
+    ) -> Goal {
+        let index = match goal.transfs.iter().position(|t| t.name == name) {
+            Some(index) => index,
+            None => {
+                let transf = Transf { name: name.into(), args: Vec::new(), goals: Vec::new() };
+                goal.transfs.push(transf);
+                goal.transfs.len() - 1
+            }
+        };
+        let transf = &mut goal.transfs[index];
+        // Subgoals are only identified by their position
+        if transf.goals.len() != results.len() {
+            let subgoals = results.iter().enumerate();
+            let parent = &goal.name;
+            transf.goals =
+                subgoals.map(|(i, result)| new_goal(result, Some((parent, i)))).collect();
+        }
+        for (subgoal, result) in transf.goals.iter_mut().zip(results) {
+            if !subgoal.proved() {
+                *subgoal = self.record(subgoal.clone(), attempt, result);
+            }
+        }
+        goal
+    }
+
+    /// Runs `why3 prove` on the goal `goal` of `theory`, or on all its goals.
+    fn run(
+        &self,
+        theory: &str,
+        goal: Option<&str>,
+        attempt: &Attempt,
+    ) -> Result<Vec<ce_models::Goal>> {
+        let mut command = self.launcher.command(self.prelude_dir);
+        command.arg("--json");
+        command.arg("-P").arg(attempt.prover.to_string());
+        command.arg("-t").arg(attempt.timelimit.to_string());
+        if let Some(transformation) = &attempt.transformation {
+            command.arg("-a").arg(transformation);
+        }
+        command.arg("-T").arg(theory);
+        if let Some(goal) = goal {
+            command.arg("-G").arg(goal);
+        }
+        let output = command.stderr(Stdio::piped()).output()?;
+        let results = Deserializer::from_slice(&output.stdout).into_iter::<ce_models::Goal>();
+        let results = results.collect::<std::result::Result<Vec<_>, _>>()?;
+        if results.is_empty() && !output.status.success() {
+            return Err(anyhow!(
+                "why3 failed on {theory}:\n{}",
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+        Ok(results)
+    }
+}
+
+/// A goal without proofs, named after `result` or, for subgoals, after their parent and position.
+fn new_goal(result: &ce_models::Goal, parent: Option<(&str, usize)>) -> Goal {
+    let name = match parent {
+        Some((parent, i)) => format!("{parent}.{i}"),
+        None => result.term.goal_name.clone(),
+    };
+    Goal::new(name, result.term.explanations.first().cloned())
+}
+
+/// File of the session directory storing the fingerprints of the modules of the last run.
+const CACHE_FILE: &str = "creusot-cache.json";
+
+fn read_cache(session_dir: &Path) -> Result<HashMap<String, String>> {
+    match std::fs::read_to_string(session_dir.join(CACHE_FILE)) {
+        Ok(text) => Ok(serde_json::from_str(&text)?),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(HashMap::new()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// The fingerprints of the modules of a coma file, in order.
+fn fingerprints(coma: &str) -> indexmap::IndexMap<String, String> {
+    let mut prelude = Sha256::new();
+    hash_dir(&mut prelude, &PRELUDE);
+
+    let mut fingerprints = indexmap::IndexMap::<String, String>::new();
+    let mut lines = coma.lines().peekable();
+    while let Some(line) = lines.next() {
+        let Some(name) = line.strip_prefix("module ") else { continue };
+        let mut hasher = prelude.clone();
+        while let Some(line) = lines.next_if(|line| !line.starts_with("module ")) {
+            let line = line.trim();
+            // Source locations do not matter for the proofs
+            if line.starts_with("let%span ") {
+                continue;
+            }
+            hasher.update(strip_locations(line));
+            hasher.update("\n");
+            // Modules can only use the modules declared before them
+            let used = line.strip_prefix("use ").and_then(|used| used.split_whitespace().next());
+            if let Some(fingerprint) = used.and_then(|used| fingerprints.get(used)) {
+                hasher.update(fingerprint);
+            }
+        }
+        fingerprints.insert(name.trim().to_owned(), hex::encode(hasher.finalize()));
+    }
+    fingerprints
+}
+
+fn hash_dir(hasher: &mut Sha256, dir: &Dir) {
+    for file in dir.files() {
+        hasher.update(file.path().to_string_lossy().as_bytes());
+        hasher.update(file.contents());
+    }
+    for dir in dir.dirs() {
+        hash_dir(hasher, dir)
+    }
+}
+
+/// Removes the location attributes `[#"file" l c l c]` from a line of coma.
+fn strip_locations(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(start) = rest.find("[#\"") {
+        out.push_str(&rest[..start]);
+        match rest[start..].find(']') {
+            Some(end) => rest = &rest[start + end + 1..],
+            None => {
+                rest = &rest[start..];
+                break;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Applies `f` to every element of `items`, using at most `parallelism` threads.
+fn parallel_map<T: Sync, U: Send>(
+    items: &[T],
+    parallelism: usize,
+    f: impl Fn(&T) -> U + Sync,
+) -> Vec<U> {
+    let next = AtomicUsize::new(0);
+    let results: Vec<Mutex<Option<U>>> = items.iter().map(|_| Mutex::new(None)).collect();
+    std::thread::scope(|scope| {
+        for _ in 0..parallelism.clamp(1, items.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else { break };
+                *results[i].lock().unwrap() = Some(f(item));
+            });
+        }
+    });
+    results.into_iter().map(|result| result.into_inner().unwrap().unwrap()).collect()
+}
+
+#[test]
+fn fingerprints_ignore_locations() {
+    let coma = |ty: &str, loc: &str| {
+        format!(
+            "module T\n  type t = {ty}\nend\nmodule M\n  use T as T'0\n  let%span s0 = \"a.rs\" {loc}\n  let rec f [#\"a.rs\" {loc}] = any\nend\n"
+        )
+    };
+    let base = fingerprints(&coma("A", "1 0 1 4"));
+    assert_eq!(base, fingerprints(&coma("A", "2 0 2 4")));
+    let changed = fingerprints(&coma("B", "1 0 1 4"));
+    assert_ne!(base["T"], changed["T"]);
+    assert_ne!(base["M"], changed["M"]);
+}
@@ -0,0 +1,401 @@
+//! Reading and writing Why3 sessions (`why3session.xml`).
+//!
+//! Only the parts of the format used by `cargo creusot prove` are modelled: provers, theories,
+//! goals, transformations and proof attempts. Shapes (`why3shapes.gz`) are not handled.
+
+use super::helpers::Result;
+use anyhow::{anyhow, Context};
+use roxmltree::{Document, Node};
+use std::{
+    fmt::Write as _,
+    path::{Path, PathBuf},
+};
+
+pub const SESSION_FILE: &str = "why3session.xml";
+pub const SHAPES_FILE: &str = "why3shapes.gz";
+
+/// A prover, identified by its Why3 name and version.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Prover {
+    pub name: String,
+    pub version: String,
+}
+
+impl std::fmt::Display for Prover {
+    /// Formats the prover in the syntax expected by `why3 prove -P`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.name, self.version)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Session {
+    /// The provers declared by the session, with their default limits.
+    pub provers: Vec<ProverLimits>,
+    /// The coma file the session is about, relative to the session directory.
+    pub file: PathBuf,
+    pub theories: Vec<Theory>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ProverLimits {
+    pub prover: Prover,
+    pub timelimit: u32,
+    pub memlimit: u32,
+}
+
+#[derive(Clone, Debug)]
+pub struct Theory {
+    pub name: String,
+    pub goals: Vec<Goal>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Goal {
+    pub name: String,
+    pub expl: Option<String>,
+    pub proofs: Vec<Proof>,
+    pub transfs: Vec<Transf>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Transf {
+    pub name: String,
+    /// Arguments of the transformation, for transformations applied interactively.
+    pub args: Vec<String>,
+    pub goals: Vec<Goal>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Proof {
+    pub prover: Prover,
+    pub timelimit: u32,
+    pub memlimit: u32,
+    pub status: String,
+    pub time: f64,
+    pub steps: Option<i64>,
+}
+
+/// Default memory limit of Why3, in megabytes.
+pub const DEFAULT_MEMLIMIT: u32 = 1000;
+
+impl Goal {
+    pub fn new(name: String, expl: Option<String>) -> Self {
+        Goal { name, expl, proofs: Vec::new(), transfs: Vec::new() }
+    }
+
+    pub fn proved(&self) -> bool {
+        self.proofs.iter().any(Proof::valid) || self.transfs.iter().any(Transf::proved)
+    }
+}
+
+impl Transf {
+    pub fn proved(&self) -> bool {
+        self.goals.iter().all(Goal::proved)
+    }
+}
+
+impl Proof {
+    pub fn valid(&self) -> bool {
+        self.status == "valid"
+    }
+}
+
+impl Theory {
+    pub fn proved(&self) -> bool {
+        self.goals.iter().all(Goal::proved)
+    }
+
+    pub fn goal(&self, name: &str) -> Option<&Goal> {
+        self.goals.iter().find(|goal| goal.name == name)
+    }
+}
+
+impl Session {
+    pub fn theory(&self, name: &str) -> Option<&Theory> {
+        self.theories.iter().find(|theory| theory.name == name)
+    }
+
+    /// Reads the session stored in `dir`, if there is one.
+    pub fn read(dir: &Path) -> Result<Option<Self>> {
+        let path = dir.join(SESSION_FILE);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let text = std::fs::read_to_string(&path)?;
+        let session = Self::parse(&text).with_context(|| format!("reading {}", path.display()))?;
+        Ok(Some(session))
+    }
+
+    fn parse(text: &str) -> Result<Self> {
+        let doc = Document::parse(text)?;
+        let root = doc.root_element();
+
+        let mut ids = Vec::new();
+        let mut session = Session::default();
+        for node in elements(root, "prover") {
+            ids.push(attribute(node, "id")?.parse()?);
+            let prover = Prover {
+                name: attribute(node, "name")?.into(),
+                version: attribute(node, "version")?.into(),
+            };
+            let timelimit = node.attribute("timelimit").map(str::parse).transpose()?.unwrap_or(0);
+            let memlimit =
+                node.attribute("memlimit").map(str::parse).transpose()?.unwrap_or(DEFAULT_MEMLIMIT);
+            session.provers.push(ProverLimits { prover, timelimit, memlimit });
+        }
+        let provers: Vec<(usize, &ProverLimits)> = ids.into_iter().zip(&session.provers).collect();
+
+        // A session can in principle contain several files, but Creusot only ever produces one.
+        let Some(file) = elements(root, "file").next() else { return Ok(session) };
+        let path = elements(file, "path").map(|p| attribute(p, "name")).collect::<Result<_>>()?;
+        let mut theories = Vec::new();
+        for theory in elements(file, "theory") {
+            let theory = Theory {
+                name: attribute(theory, "name")?.into(),
+                goals: parse_goals(theory, &provers)?,
+            };
+            theories.push(theory);
+        }
+        session.file = path;
+        session.theories = theories;
+        Ok(session)
+    }
+
+    /// Writes the session to `dir`. Shapes are not computed: any stale shapes file is removed,
+    /// and Why3 recreates it the next time it saves the session.
+    pub fn write(&self, dir: &Path) -> Result<()> {
+        std::fs::create_dir_all(dir)?;
+        std::fs::write(dir.join(SESSION_FILE), self.to_xml())?;
+        match std::fs::remove_file(dir.join(SHAPES_FILE)) {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err.into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn to_xml(&self) -> String {
+        // Provers which are not declared yet get the limits of their first proof attempt
+        let mut provers = self.provers.clone();
+        let mut stack: Vec<&Goal> = self.theories.iter().flat_map(|t| &t.goals).rev().collect();
+        while let Some(goal) = stack.pop() {
+            for proof in &goal.proofs {
+                if !provers.iter().any(|p| p.prover == proof.prover) {
+                    let (timelimit, memlimit) = (proof.timelimit, proof.memlimit);
+                    provers.push(ProverLimits {
+                        prover: proof.prover.clone(),
+                        timelimit,
+                        memlimit,
+                    });
+                }
+            }
+            stack.extend(goal.transfs.iter().rev().flat_map(|t| t.goals.iter().rev()));
+        }
+
+        let mut out = String::new();
+        out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        out.push_str("<!DOCTYPE why3session PUBLIC \"-//Why3//proof session v5//EN\"\n");
+        out.push_str("\"https://www.why3.org/why3session.dtd\">\n");
+        out.push_str("<why3session shape_version=\"6\">\n");
+        for (id, ProverLimits { prover, timelimit, memlimit }) in provers.iter().enumerate() {
+            writeln!(
+                out,
+                "<prover id=\"{id}\" name=\"{}\" version=\"{}\" timelimit=\"{timelimit}\" steplimit=\"0\" memlimit=\"{memlimit}\"/>",
+                escape(&prover.name),
+                escape(&prover.version),
+            )
+            .unwrap();
+        }
+        let proved = self.theories.iter().all(Theory::proved);
+        writeln!(out, "<file format=\"coma\"{}>", proved_attr(proved)).unwrap();
+        for component in self.file.iter() {
+            write!(out, "<path name=\"{}\"/>", escape(&component.to_string_lossy())).unwrap();
+        }
+        out.push('\n');
+        for theory in &self.theories {
+            writeln!(
+                out,
+                "<theory name=\"{}\"{}>",
+                escape(&theory.name),
+                proved_attr(theory.proved())
+            )
+            .unwrap();
+            for goal in &theory.goals {
+                write_goal(&mut out, goal, 1, &provers);
+            }
+            out.push_str("</theory>\n");
+        }
+        out.push_str("</file>\n");
+        out.push_str("</why3session>\n");
+        out
+    }
+}
+
+fn write_goal(out: &mut String, goal: &Goal, depth: usize, provers: &[ProverLimits]) {
+    let indent = " ".repeat(depth);
+    let expl = match &goal.expl {
+        Some(expl) => format!(" expl=\"{}\"", escape(expl)),
+        None => String::new(),
+    };
+    writeln!(
+        out,
+        "{indent}<goal name=\"{}\"{expl}{}>",
+        escape(&goal.name),
+        proved_attr(goal.proved())
+    )
+    .unwrap();
+    for proof in &goal.proofs {
+        let id = provers.iter().position(|p| p.prover == proof.prover).unwrap();
+        let mut limits = String::new();
+        if proof.timelimit != provers[id].timelimit {
+            write!(limits, " timelimit=\"{}\"", proof.timelimit).unwrap();
+        }
+        if proof.memlimit != provers[id].memlimit {
+            write!(limits, " memlimit=\"{}\"", proof.memlimit).unwrap();
+        }
+        let steps = match proof.steps {
+            Some(steps) => format!(" steps=\"{steps}\""),
+            None => String::new(),
+        };
+        writeln!(
+            out,
+            "{indent}<proof prover=\"{id}\"{limits}><result status=\"{}\" time=\"{:.6}\"{steps}/></proof>",
+            escape(&proof.status),
+            proof.time,
+        )
+        .unwrap();
+    }
+    for transf in &goal.transfs {
+        let mut args = String::new();
+        for (i, arg) in transf.args.iter().enumerate() {
+            write!(args, " arg{}=\"{}\"", i + 1, escape(arg)).unwrap();
+        }
+        writeln!(
+            out,
+            "{indent}<transf name=\"{}\"{}{args} >",
+            escape(&transf.name),
+            proved_attr(transf.proved())
+        )
+        .unwrap();
+        for goal in &transf.goals {
+            write_goal(out, goal, depth + 1, provers);
+        }
+        writeln!(out, "{indent}</transf>").unwrap();
+    }
+    writeln!(out, "{indent}</goal>").unwrap();
+}
+
+fn proved_attr(proved: bool) -> &'static str {
+    if proved {
+        " proved=\"true\""
+    } else {
+        ""
+    }
+}
+
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn elements<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+    tag: &'a str,
+) -> impl Iterator<Item = Node<'a, 'input>> + 'a {
+    node.children().filter(move |n| n.is_element() && n.tag_name().name() == tag)
+}
+
+fn attribute<'a>(node: Node<'a, '_>, name: &str) -> Result<&'a str> {
+    node.attribute(name).ok_or_else(|| {
+        anyhow!("missing attribute `{name}` on <{}> in Why3 session", node.tag_name().name())
+    })
+}
+
+fn parse_goals(node: Node, provers: &[(usize, &ProverLimits)]) -> Result<Vec<Goal>> {
+    elements(node, "goal").map(|goal| parse_goal(goal, provers)).collect()
+}
+
+fn parse_goal(node: Node, provers: &[(usize, &ProverLimits)]) -> Result<Goal> {
+    let mut goal =
+        Goal::new(attribute(node, "name")?.into(), node.attribute("expl").map(Into::into));
+    for proof in elements(node, "proof") {
+        let id: usize = attribute(proof, "prover")?.parse()?;
+        let Some((_, limits)) = provers.iter().find(|p| p.0 == id) else {
+            return Err(anyhow!("unknown prover {id} in Why3 session"));
+        };
+        // Attempts which were never run have no result.
+        let Some(result) = elements(proof, "result").next() else { continue };
+        goal.proofs.push(Proof {
+            prover: limits.prover.clone(),
+            timelimit: proof
+                .attribute("timelimit")
+                .map(str::parse)
+                .transpose()?
+                .unwrap_or(limits.timelimit),
+            memlimit: proof
+                .attribute("memlimit")
+                .map(str::parse)
+                .transpose()?
+                .unwrap_or(limits.memlimit),
+            status: attribute(result, "status")?.into(),
+            time: result.attribute("time").map(str::parse).transpose()?.unwrap_or(0.),
+            steps: result.attribute("steps").map(str::parse).transpose()?,
+        });
+    }
+    for transf in elements(node, "transf") {
+        let args = (1..).map_while(|i| transf.attribute(&*format!("arg{i}"))).map(Into::into);
+        goal.transfs.push(Transf {
+            name: attribute(transf, "name")?.into(),
+            args: args.collect(),
+            goals: parse_goals(transf, provers)?,
+        });
+    }
+    Ok(goal)
+}
+
+#[test]
+fn session_roundtrip() {
+    let text = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE why3session PUBLIC "-//Why3//proof session v5//EN"
+"https://www.why3.org/why3session.dtd">
+<why3session shape_version="6">
+<prover id="0" name="Z3" version="4.12.4" timelimit="1" steplimit="0" memlimit="1000"/>
+<prover id="1" name="Alt-Ergo" version="2.5.3" timelimit="1" steplimit="0" memlimit="1000"/>
+<file format="coma">
+<path name=".."/><path name="foo.coma"/>
+<theory name="M_foo">
+ <goal name="vc_foo&#39;0" proved="true">
+ <transf name="split_vc" proved="true" >
+  <goal name="vc_foo&#39;0.0" expl="precondition" proved="true">
+  <proof prover="1"><result status="valid" time="0.013110" steps="4"/></proof>
+  </goal>
+  <goal name="vc_foo&#39;0.1" expl="postcondition" proved="true">
+  <proof prover="0" timelimit="5"><result status="valid" time="0.015236" steps="35045"/></proof>
+  </goal>
+ </transf>
+ </goal>
+ <goal name="vc_bar&#39;0">
+ <proof prover="0"><result status="timeout" time="1.000000"/></proof>
+ </goal>
+</theory>
+</file>
+</why3session>
+"#;
+    let session = Session::parse(text).unwrap();
+    assert_eq!(session.file, PathBuf::from("../foo.coma"));
+    let theory = session.theory("M_foo").unwrap();
+    assert!(!theory.proved());
+    assert!(theory.goal("vc_foo'0").unwrap().proved());
+    assert_eq!(theory.goal("vc_foo'0").unwrap().transfs[0].goals[1].proofs[0].timelimit, 5);
+    assert!(!theory.goal("vc_bar'0").unwrap().proved());
+    assert_eq!(session.to_xml(), text);
+}
@@ -7,17 +7,19 @@ use super::helpers::Result;
 use anyhow::anyhow;
 use include_dir::{include_dir, Dir};
 
-static PRELUDE: Dir<'static> = include_dir!("$CARGO_MANIFEST_DIR/../prelude");
+pub(crate) static PRELUDE: Dir<'static> = include_dir!("$CARGO_MANIFEST_DIR/../prelude");
 
 #[derive(Copy, Clone, Debug)]
 pub enum Why3Mode {
     Ide,
+    Prove,
 }
 
 impl std::fmt::Display for Why3Mode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Why3Mode::Ide => f.write_str("ide"),
+            Why3Mode::Prove => f.write_str("prove"),
         }
     }
 }
@@ -43,7 +45,12 @@ impl Why3Launcher {
     }
 
     pub fn make(&self, temp_dir: &Path) -> Result<Command> {
-        let mode = self.mode.to_string();
+        self.extract_prelude(temp_dir)?;
+        Ok(self.command(temp_dir))
+    }
+
+    /// Extracts the prelude into `temp_dir`, for use by commands built with [Self::command].
+    pub fn extract_prelude(&self, temp_dir: &Path) -> Result<()> {
         let mut prelude_dir: PathBuf = temp_dir.into();
         prelude_dir.push("prelude");
         std::fs::create_dir(&prelude_dir)?;
@@ -51,7 +58,12 @@ impl Why3Launcher {
         PRELUDE
             .extract(prelude_dir)
             .expect("can't launch why3, could extract prelude into temp dir");
+        Ok(())
+    }
 
+    /// Builds the command, assuming the prelude was already extracted into `temp_dir`.
+    pub fn command(&self, temp_dir: &Path) -> Command {
+        let mode = self.mode.to_string();
         let mut command =
             if let Some(p) = &self.why3_path { Command::new(p) } else { Command::new("why3") };
         command
@@ -73,7 +85,7 @@ impl Why3Launcher {
             }
         }
 
-        Ok(command)
+        command
     }
 }
 
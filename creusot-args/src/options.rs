@@ -60,6 +60,9 @@ pub enum CreusotSubCommand {
         /// Why3 subcommand to run
         #[clap(value_enum)]
         command: Why3SubCommand,
+        /// Write a JSON report of the proof results to this file (`prove` only)
+        #[clap(long)]
+        report: Option<PathBuf>,
         /// Extra arguments to pass to why3
         #[clap(default_value_t = String::default())]
         args: String,
@@ -72,7 +75,7 @@ pub enum CreusotSubCommand {
 pub struct CargoCreusotArgs {
     #[clap(flatten)]
     pub options: CommonOptions,
-    /// Subcommand: why3, setup
+    /// Subcommand: why3, prove, setup
     #[command(subcommand)]
     pub subcommand: Option<CargoCreusotSubCommand>,
     #[clap(last = true)]
@@ -87,6 +90,17 @@ pub enum CargoCreusotSubCommand {
         #[command(subcommand)]
         command: SetupSubCommand,
     },
+    /// Prove the generated coma without user interaction, updating its Why3 session
+    Prove {
+        /// Time limits (in seconds) to try, in increasing order
+        #[arg(long, value_delimiter = ',', default_values_t = [1, 5, 20])]
+        timeouts: Vec<u32>,
+        /// Transformations to try on goals which cannot be proved directly
+        #[arg(long, value_delimiter = ',', default_values_t = ["split_vc".to_owned()])]
+        transformations: Vec<String>,
+        #[clap(last = true)]
+        rust_flags: Vec<String>,
+    },
     #[command(flatten)]
     Creusot(CreusotSubCommand),
 }
@@ -175,6 +189,7 @@ impl CargoCreusotArgs {
             Some(CargoCreusotSubCommand::Creusot(CreusotSubCommand::Why3 {
                 rust_flags, ..
             })) => rust_flags,
+            Some(CargoCreusotSubCommand::Prove { rust_flags, .. }) => rust_flags,
             _ => return,
         };
         let rust_flags = std::mem::take(rust_flags);
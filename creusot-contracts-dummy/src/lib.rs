@@ -2,31 +2,72 @@ extern crate proc_macro;
 
 use proc_macro::TokenStream as TS1;
 
+#[cfg(feature = "runtime-checks")]
+mod runtime;
+
+#[cfg(not(feature = "runtime-checks"))]
 #[proc_macro_attribute]
 pub fn requires(_: TS1, tokens: TS1) -> TS1 {
     tokens
 }
 
+#[cfg(feature = "runtime-checks")]
+#[proc_macro_attribute]
+pub fn requires(attr: TS1, tokens: TS1) -> TS1 {
+    runtime::requires(attr.into(), tokens.into()).into()
+}
+
+#[cfg(not(feature = "runtime-checks"))]
 #[proc_macro_attribute]
 pub fn ensures(_: TS1, tokens: TS1) -> TS1 {
     tokens
 }
 
+#[cfg(feature = "runtime-checks")]
+#[proc_macro_attribute]
+pub fn ensures(attr: TS1, tokens: TS1) -> TS1 {
+    runtime::ensures(attr.into(), tokens.into()).into()
+}
+
+#[proc_macro_attribute]
+pub fn panics_if(_: TS1, tokens: TS1) -> TS1 {
+    tokens
+}
+
+#[proc_macro_attribute]
+pub fn may_panic(_: TS1, tokens: TS1) -> TS1 {
+    tokens
+}
+
 #[proc_macro_attribute]
 pub fn variant(_: TS1, tokens: TS1) -> TS1 {
     tokens
 }
 
+#[cfg(not(feature = "runtime-checks"))]
 #[proc_macro_attribute]
 pub fn invariant(_: TS1, tokens: TS1) -> TS1 {
     tokens
 }
 
+#[cfg(feature = "runtime-checks")]
+#[proc_macro_attribute]
+pub fn invariant(attr: TS1, tokens: TS1) -> TS1 {
+    runtime::invariant(attr.into(), tokens.into()).into()
+}
+
+#[cfg(not(feature = "runtime-checks"))]
 #[proc_macro]
 pub fn proof_assert(_: TS1) -> TS1 {
     TS1::new()
 }
 
+#[cfg(feature = "runtime-checks")]
+#[proc_macro]
+pub fn proof_assert(assertion: TS1) -> TS1 {
+    runtime::proof_assert(assertion.into()).into()
+}
+
 #[proc_macro]
 pub fn snapshot(_: TS1) -> TS1 {
     quote::quote! { creusot_contracts::snapshot::Snapshot::from_fn(|| std::process::abort()) }
@@ -58,6 +99,16 @@ pub fn predicate(_: TS1, _: TS1) -> TS1 {
     TS1::new()
 }
 
+#[proc_macro_attribute]
+pub fn inductive(_: TS1, _: TS1) -> TS1 {
+    TS1::new()
+}
+
+#[proc_macro_attribute]
+pub fn coinductive(_: TS1, _: TS1) -> TS1 {
+    TS1::new()
+}
+
 #[proc_macro_attribute]
 pub fn law(_: TS1, _: TS1) -> TS1 {
     TS1::new()
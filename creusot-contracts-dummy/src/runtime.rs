@@ -0,0 +1,525 @@
+//! Runtime checking of contracts, enabled by the `runtime-checks` feature.
+//!
+//! Only the executable subset of Pearlite is turned into assertions: boolean and arithmetic
+//! operators, variables, fields, indexing, constructors, `old`, the final value `^x` of a
+//! mutable borrow parameter and quantifiers over a bounded range of machine integers. Contracts
+//! which use anything else (models, logic functions, ...) are erased, as they are without the
+//! feature.
+
+use pearlite_syn::{term::*, Term as RT};
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use std::collections::HashMap;
+use syn::{
+    parse::Parser, parse_quote, punctuated::Punctuated, BinOp, Expr, ExprForLoop, ExprLoop,
+    ExprWhile, FnArg, Ident, ItemFn, Pat, ReturnType, Signature, Stmt, Token, Type, UnOp,
+};
+
+pub(crate) fn requires(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let (Ok(term), Ok(mut f)) =
+        (syn::parse2::<RT>(attr.clone()), syn::parse2::<ItemFn>(item.clone()))
+    else {
+        return item;
+    };
+    let params = params(&f.sig);
+    let Some(cond) = Lower::new(State::Entry, &params).term(&term) else { return item };
+    let check = check(cond, format!("precondition failed: {attr}"));
+    f.block.stmts.insert(0, parse_quote! { #check });
+    f.into_token_stream()
+}
+
+pub(crate) fn ensures(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let (Ok(term), Ok(mut f)) =
+        (syn::parse2::<RT>(attr.clone()), syn::parse2::<ItemFn>(item.clone()))
+    else {
+        return item;
+    };
+    // The body is wrapped in a closure, which is not possible in these functions.
+    if f.sig.asyncness.is_some() || f.sig.constness.is_some() {
+        return item;
+    }
+    let params = params(&f.sig);
+    let mut lower = Lower::new(State::Exit, &params);
+    let Some(cond) = lower.term(&term) else { return item };
+    let ret = match &f.sig.output {
+        ReturnType::Default => quote! { () },
+        ReturnType::Type(_, ty) => {
+            let ty = ty.to_token_stream();
+            // `impl Trait` cannot be named in a `let`, and the final value of a borrow cannot be
+            // read while the result still borrows from it.
+            if matches!(syn::parse2(ty.clone()), Ok(Type::Never(_)))
+                || contains(&ty, &|t, _| matches!(t, TokenTree::Ident(i) if i == "impl"))
+                || lower.uses_final && contains(&ty, &is_mut_ref)
+            {
+                return item;
+            }
+            ty
+        }
+    };
+    let (olds, values): (Vec<_>, Vec<_>) = lower.olds.into_iter().unzip();
+    let mut check = check(cond, format!("postcondition failed: {attr}"));
+    if !olds.is_empty() {
+        // Values which cannot be cloned are `None`, and the postcondition is not checked.
+        check = quote! {
+            if let (#(::core::option::Option::Some(#olds),)*) = (#(#olds,)*) {
+                #check
+            }
+        };
+    }
+    let result = Ident::new("result", Span::call_site());
+    let body = &f.block;
+    *f.block = parse_quote!({
+        #[allow(dead_code)]
+        struct __CreusotOld<'a, T: ?Sized>(&'a T);
+        #[allow(dead_code)]
+        trait __CreusotClone<T> {
+            fn __creusot_old(&self) -> ::core::option::Option<T>;
+        }
+        impl<T: ::core::clone::Clone> __CreusotClone<T> for __CreusotOld<'_, T> {
+            fn __creusot_old(&self) -> ::core::option::Option<T> {
+                ::core::option::Option::Some(::core::clone::Clone::clone(self.0))
+            }
+        }
+        #[allow(dead_code)]
+        trait __CreusotNoClone<T> {
+            fn __creusot_old(&self) -> ::core::option::Option<T> {
+                ::core::option::Option::None
+            }
+        }
+        impl<T> __CreusotNoClone<T> for &__CreusotOld<'_, T> {}
+        #(let #olds = (&__CreusotOld(&(#values))).__creusot_old();)*
+        fn __creusot_call_once<R, F: ::core::ops::FnOnce() -> R>(f: F) -> R {
+            f()
+        }
+        let #result: #ret = __creusot_call_once(|| -> #ret #body);
+        #check
+        #result
+    });
+    f.into_token_stream()
+}
+
+pub(crate) fn invariant(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let (Ok(term), Ok(mut expr)) =
+        (syn::parse2::<RT>(attr.clone()), syn::parse2::<Expr>(item.clone()))
+    else {
+        return item;
+    };
+    let params = HashMap::new();
+    let Some(cond) = Lower::new(State::Body, &params).term(&term) else { return item };
+    let check = check(cond, format!("loop invariant failed: {attr}"));
+    let check: Stmt = parse_quote! { #check };
+    match &mut expr {
+        // Checking the invariant in the condition also checks it when the loop exits.
+        Expr::While(ExprWhile { cond, .. }) if !matches!(**cond, Expr::Let(_)) => {
+            *cond = parse_quote!({ #check #cond });
+        }
+        Expr::While(ExprWhile { body, .. })
+        | Expr::Loop(ExprLoop { body, .. })
+        | Expr::ForLoop(ExprForLoop { body, .. }) => body.stmts.insert(0, check),
+        _ => return item,
+    }
+    expr.into_token_stream()
+}
+
+pub(crate) fn proof_assert(assertion: TokenStream) -> TokenStream {
+    let Ok(block) = TBlock::parse_within.parse2(assertion.clone()) else {
+        return TokenStream::new();
+    };
+    let params = HashMap::new();
+    let Some(cond) = Lower::new(State::Body, &params).block(&block) else {
+        return TokenStream::new();
+    };
+    check(cond, format!("assertion failed: {assertion}"))
+}
+
+/// Asserts `cond`. An equality between values which do not implement `PartialEq` cannot be
+/// evaluated: it returns early from the closure `cond` is evaluated in, and the assertion is
+/// skipped.
+fn check(cond: TokenStream, msg: String) -> TokenStream {
+    quote! {{
+        #[allow(dead_code)]
+        struct __CreusotEq<'a, L: ?Sized, R: ?Sized>(&'a L, &'a R);
+        #[allow(dead_code)]
+        trait __CreusotPartialEq {
+            fn __creusot_eq(&self) -> ::core::option::Option<bool>;
+        }
+        impl<L: ::core::cmp::PartialEq<R> + ?Sized, R: ?Sized> __CreusotPartialEq
+            for __CreusotEq<'_, L, R>
+        {
+            fn __creusot_eq(&self) -> ::core::option::Option<bool> {
+                ::core::option::Option::Some(self.0 == self.1)
+            }
+        }
+        #[allow(dead_code)]
+        trait __CreusotNoEq {
+            fn __creusot_eq(&self) -> ::core::option::Option<bool> {
+                ::core::option::Option::None
+            }
+        }
+        impl<L: ?Sized, R: ?Sized> __CreusotNoEq for &__CreusotEq<'_, L, R> {}
+        #[allow(clippy::redundant_closure_call, clippy::needless_question_mark)]
+        let __creusot_cond =
+            (|| -> ::core::option::Option<bool> { ::core::option::Option::Some(#cond) })();
+        if let ::core::option::Option::Some(__creusot_cond) = __creusot_cond {
+            ::core::assert!(__creusot_cond, "{}", #msg);
+        }
+    }}
+}
+
+/// The parameters of a function, and whether they are mutable borrows.
+fn params(sig: &Signature) -> HashMap<String, bool> {
+    sig.inputs
+        .iter()
+        .filter_map(|arg| match arg {
+            FnArg::Receiver(r) => {
+                Some(("self".to_string(), r.reference.is_some() && r.mutability.is_some()))
+            }
+            FnArg::Typed(arg) => match (&*arg.pat, &*arg.ty) {
+                (Pat::Ident(p), Type::Reference(r)) => {
+                    Some((p.ident.to_string(), r.mutability.is_some()))
+                }
+                (Pat::Ident(p), _) => Some((p.ident.to_string(), false)),
+                _ => None,
+            },
+        })
+        .collect()
+}
+
+/// Does a token of `tokens`, followed by the next token at the same level, satisfy `pred`?
+fn contains(tokens: &TokenStream, pred: &dyn Fn(&TokenTree, Option<&TokenTree>) -> bool) -> bool {
+    let tokens: Vec<_> = tokens.clone().into_iter().collect();
+    tokens.iter().enumerate().any(|(i, t)| {
+        pred(t, tokens.get(i + 1))
+            || matches!(t, TokenTree::Group(g) if contains(&g.stream(), pred))
+    })
+}
+
+fn is_mut_ref(t: &TokenTree, next: Option<&TokenTree>) -> bool {
+    matches!((t, next), (TokenTree::Punct(p), Some(TokenTree::Ident(i))) if p.as_char() == '&' && i == "mut")
+}
+
+/// When a contract is evaluated, which determines the value of the function's parameters.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// On entry of the function: preconditions and the arguments of `old`.
+    Entry,
+    /// On exit of the function: postconditions. Parameters refer to their value on entry,
+    /// which is saved beforehand.
+    Exit,
+    /// Inside a body: loop invariants and assertions. Dereferences are not checked, as they
+    /// may refer to snapshots, which do not exist at runtime.
+    Body,
+}
+
+struct Lower<'a> {
+    state: State,
+    params: &'a HashMap<String, bool>,
+    /// Values saved on entry of the function, for use in a postcondition.
+    olds: Vec<(Ident, TokenStream)>,
+    /// Does the postcondition read the final value of a mutable borrow?
+    uses_final: bool,
+}
+
+impl<'a> Lower<'a> {
+    fn new(state: State, params: &'a HashMap<String, bool>) -> Self {
+        Lower { state, params, olds: Vec::new(), uses_final: false }
+    }
+
+    /// Translates an executable term to a Rust expression, or returns `None`.
+    fn term(&mut self, term: &RT) -> Option<TokenStream> {
+        Some(match term {
+            RT::Lit(TermLit { lit }) => quote! { #lit },
+            RT::Path(TermPath { inner }) => match inner.path.get_ident() {
+                Some(ident) if inner.qself.is_none() => self.variable(ident)?,
+                _ => quote! { #inner },
+            },
+            RT::Paren(TermParen { expr, .. }) | RT::Group(TermGroup { expr, .. }) => {
+                let expr = self.term(expr)?;
+                quote! { (#expr) }
+            }
+            RT::Binary(TermBinary { left, op: BinOp::Eq(_), right })
+            | RT::LogEq(TermLogEq { lhs: left, rhs: right, .. }) => {
+                let (left, right) = (self.term(left)?, self.term(right)?);
+                quote! { (&__CreusotEq(&#left, &#right)).__creusot_eq()? }
+            }
+            RT::Binary(TermBinary { left, op: BinOp::Ne(_), right }) => {
+                let (left, right) = (self.term(left)?, self.term(right)?);
+                quote! { !(&__CreusotEq(&#left, &#right)).__creusot_eq()? }
+            }
+            RT::Binary(TermBinary { left, op, right }) => {
+                let (left, right) = (self.term(left)?, self.term(right)?);
+                quote! { (#left #op #right) }
+            }
+            RT::Impl(TermImpl { hyp, cons, .. }) => {
+                let (hyp, cons) = (self.term(hyp)?, self.term(cons)?);
+                quote! { (!#hyp || #cons) }
+            }
+            RT::Unary(TermUnary { op: UnOp::Deref(_), expr }) => self.deref(expr)?,
+            RT::Unary(TermUnary { op, expr }) => {
+                let expr = self.term(expr)?;
+                quote! { (#op #expr) }
+            }
+            RT::Final(TermFinal { term, .. }) => match self.mut_param(term) {
+                Some(ident) if self.state == State::Exit => {
+                    self.uses_final = true;
+                    quote! { (*#ident) }
+                }
+                _ => return None,
+            },
+            RT::Field(TermField { base, member, .. }) => {
+                let base = self.term(base)?;
+                quote! { #base.#member }
+            }
+            RT::Index(TermIndex { expr, index, .. }) => {
+                let (expr, index) = (self.term(expr)?, self.term(index)?);
+                quote! { #expr[#index] }
+            }
+            RT::Cast(TermCast { expr, ty, .. }) => {
+                let expr = self.term(expr)?;
+                quote! { (#expr as #ty) }
+            }
+            RT::Reference(TermReference { mutability: None, expr, .. }) => {
+                let expr = self.term(expr)?;
+                quote! { (&#expr) }
+            }
+            RT::Tuple(TermTuple { elems, .. }) => {
+                let elems = self.terms(elems)?;
+                quote! { (#(#elems,)*) }
+            }
+            RT::Array(TermArray { elems, .. }) => {
+                let elems = self.terms(elems)?;
+                quote! { [#(#elems),*] }
+            }
+            RT::Block(TermBlock { label: None, block }) => self.block(&block.stmts)?,
+            RT::If(TermIf { cond, then_branch, else_branch: Some((_, else_branch)), .. }) => {
+                let cond = self.term(cond)?;
+                let then_branch = self.block(&then_branch.stmts)?;
+                let else_branch = self.term(else_branch)?;
+                quote! { if #cond { #then_branch } else { #else_branch } }
+            }
+            RT::Call(TermCall { func, args, .. }) => match &**func {
+                RT::Path(p) if p.inner.path.is_ident("old") && args.len() == 1 => {
+                    self.old(&args[0])?
+                }
+                // Only constructors: every other function of a contract is a logic function.
+                RT::Path(p) if is_constructor(&p.inner.path) => {
+                    let args = self.terms(args)?;
+                    quote! { #p(#(#args),*) }
+                }
+                _ => return None,
+            },
+            RT::Forall(TermForall { args, term, .. }) => {
+                let RT::Impl(TermImpl { hyp, cons, .. }) = strip_parens(term) else {
+                    return None;
+                };
+                let vars: Vec<_> = args.iter().collect();
+                self.quantifier(true, &vars, conjuncts(hyp), Some(cons))?
+            }
+            RT::Exists(TermExists { args, term, .. }) => {
+                let vars: Vec<_> = args.iter().collect();
+                self.quantifier(false, &vars, conjuncts(term), None)?
+            }
+            _ => return None,
+        })
+    }
+
+    fn terms(&mut self, terms: &Punctuated<RT, Token![,]>) -> Option<Vec<TokenStream>> {
+        terms.iter().map(|t| self.term(t)).collect()
+    }
+
+    fn block(&mut self, stmts: &[TermStmt]) -> Option<TokenStream> {
+        match stmts {
+            [TermStmt::Expr(term)] => self.term(term),
+            _ => None,
+        }
+    }
+
+    fn variable(&mut self, ident: &Ident) -> Option<TokenStream> {
+        if ident == "result" {
+            return (self.state == State::Exit).then(|| quote! { #ident });
+        }
+        match self.params.get(&ident.to_string()) {
+            Some(&is_mut) if self.state == State::Exit => {
+                let value = if is_mut {
+                    quote! { *#ident }
+                } else {
+                    quote! { #ident }
+                };
+                Some(self.save(format_ident!("__creusot_old_{}", ident), value))
+            }
+            _ => Some(quote! { #ident }),
+        }
+    }
+
+    fn deref(&mut self, expr: &RT) -> Option<TokenStream> {
+        match (self.state, self.mut_param(expr)) {
+            (State::Body, _) => None,
+            // The current value of a mutable borrow, as it was on entry.
+            (State::Exit, Some(ident)) => self.variable(&ident),
+            _ => {
+                let expr = self.term(expr)?;
+                Some(quote! { (*#expr) })
+            }
+        }
+    }
+
+    fn old(&mut self, term: &RT) -> Option<TokenStream> {
+        if self.state != State::Exit {
+            return None;
+        }
+        self.state = State::Entry;
+        let value = self.term(term);
+        self.state = State::Exit;
+        let ident = format_ident!("__creusot_old_{}", self.olds.len());
+        Some(self.save(ident, value?))
+    }
+
+    /// Saves `value` on entry of the function in the variable `ident`.
+    fn save(&mut self, ident: Ident, value: TokenStream) -> TokenStream {
+        let ident = Ident::new(&ident.to_string(), Span::mixed_site());
+        if !self.olds.iter().any(|(old, _)| *old == ident) {
+            self.olds.push((ident.clone(), value));
+        }
+        quote! { #ident }
+    }
+
+    fn mut_param(&self, term: &RT) -> Option<Ident> {
+        match strip_parens(term) {
+            RT::Path(TermPath { inner }) if inner.qself.is_none() => {
+                let ident = inner.path.get_ident()?;
+                (self.params.get(&ident.to_string()) == Some(&true)).then(|| ident.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// Translates a quantifier whose variables all range over a bounded interval, given by
+    /// the conditions `conds`, to iterations over these intervals.
+    fn quantifier(
+        &mut self,
+        forall: bool,
+        vars: &[&QuantArg],
+        mut conds: Vec<&RT>,
+        cons: Option<&RT>,
+    ) -> Option<TokenStream> {
+        let Some((var, vars)) = vars.split_first() else {
+            let conds = conds.into_iter().map(|c| self.term(c)).collect::<Option<Vec<_>>>()?;
+            let conds = quote! { true #(&& #conds)* };
+            return match cons {
+                Some(cons) => {
+                    let cons = self.term(cons)?;
+                    Some(quote! { (!(#conds) || #cons) })
+                }
+                None => Some(conds),
+            };
+        };
+        let unsigned = match &*var.ty {
+            Type::Path(ty) if ty.qself.is_none() => match ty.path.get_ident()?.to_string().as_str()
+            {
+                "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => true,
+                "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => false,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        let (mut lower, mut upper) = (None, None);
+        conds.retain(|cond| match bound(cond, &var.ident) {
+            Some(Bound::Lower(term, strict)) if lower.is_none() => {
+                lower = Some((term, strict));
+                false
+            }
+            Some(Bound::Upper(term, inclusive)) if upper.is_none() => {
+                upper = Some((term, inclusive));
+                false
+            }
+            _ => true,
+        });
+        let lower = match lower {
+            Some((term, false)) => self.term(term)?,
+            Some((term, true)) => {
+                let term = self.term(term)?;
+                quote! { (#term + 1) }
+            }
+            None if unsigned => quote! { 0 },
+            None => return None,
+        };
+        let (upper, inclusive) = upper?;
+        let upper = self.term(upper)?;
+        let range = if inclusive {
+            quote! { (#lower..=#upper) }
+        } else {
+            quote! { (#lower..#upper) }
+        };
+        let body = self.quantifier(forall, vars, conds, cons)?;
+        let (ident, ty) = (&var.ident, &var.ty);
+        // A loop rather than `all` or `any`, so that equalities which cannot be evaluated return
+        // from the enclosing closure.
+        let stop = if forall {
+            quote! { !(#body) }
+        } else {
+            quote! { (#body) }
+        };
+        Some(quote! {{
+            let mut __creusot_quant = #forall;
+            for #ident in #range {
+                let #ident: #ty = #ident;
+                if #stop {
+                    __creusot_quant = !#forall;
+                    break;
+                }
+            }
+            __creusot_quant
+        }})
+    }
+}
+
+enum Bound<'a> {
+    /// A lower bound, which may be strict
+    Lower(&'a RT, bool),
+    /// An upper bound, which may be inclusive
+    Upper(&'a RT, bool),
+}
+
+/// Recognizes a comparison between `var` and another term.
+fn bound<'a>(cond: &'a RT, var: &Ident) -> Option<Bound<'a>> {
+    let RT::Binary(TermBinary { left, op, right }) = strip_parens(cond) else { return None };
+    let is_var = |t: &RT| matches!(strip_parens(t), RT::Path(p) if p.inner.path.is_ident(var));
+    match (is_var(left), is_var(right), op) {
+        (true, false, BinOp::Lt(_)) | (false, true, BinOp::Gt(_)) => {
+            Some(Bound::Upper(if is_var(left) { right } else { left }, false))
+        }
+        (true, false, BinOp::Le(_)) | (false, true, BinOp::Ge(_)) => {
+            Some(Bound::Upper(if is_var(left) { right } else { left }, true))
+        }
+        (true, false, BinOp::Gt(_)) | (false, true, BinOp::Lt(_)) => {
+            Some(Bound::Lower(if is_var(left) { right } else { left }, true))
+        }
+        (true, false, BinOp::Ge(_)) | (false, true, BinOp::Le(_)) => {
+            Some(Bound::Lower(if is_var(left) { right } else { left }, false))
+        }
+        _ => None,
+    }
+}
+
+fn conjuncts(term: &RT) -> Vec<&RT> {
+    match strip_parens(term) {
+        RT::Binary(TermBinary { left, op: BinOp::And(_), right }) => {
+            let mut conds = conjuncts(left);
+            conds.extend(conjuncts(right));
+            conds
+        }
+        term => vec![term],
+    }
+}
+
+fn strip_parens(term: &RT) -> &RT {
+    match term {
+        RT::Paren(TermParen { expr, .. }) | RT::Group(TermGroup { expr, .. }) => strip_parens(expr),
+        _ => term,
+    }
+}
+
+/// Enum variants and tuple structs, such as `Some` or `Option::Some`.
+fn is_constructor(path: &syn::Path) -> bool {
+    path.segments.last().is_some_and(|s| s.ident.to_string().starts_with(char::is_uppercase))
+}
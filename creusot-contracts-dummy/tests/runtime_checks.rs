@@ -0,0 +1,110 @@
+#![cfg(feature = "runtime-checks")]
+#![feature(stmt_expr_attributes, proc_macro_hygiene)]
+
+use creusot_contracts_dummy::{ensures, invariant, proof_assert, requires};
+use std::panic::catch_unwind;
+
+#[requires(x < 10u32)]
+#[ensures(result == x + 1)]
+fn incr(x: u32) -> u32 {
+    x + 1
+}
+
+#[ensures(result == x + 1)]
+fn wrong_incr(x: u32) -> u32 {
+    x + 2
+}
+
+#[ensures(^x == old(*x) + 1)]
+#[ensures(*x < u32::MAX)]
+fn incr_mut(x: &mut u32) {
+    *x += 1;
+}
+
+#[requires(forall<i: usize> 0 <= i && i < n ==> a[i] > 0)]
+#[ensures(exists<i: usize> i < n && a[i] == result)]
+fn first(a: &[u32; 4], n: usize) -> u32 {
+    if n == 0 {
+        return 0;
+    }
+    a[0]
+}
+
+#[ensures(result == Some(x))]
+fn some(x: u32) -> Option<u32> {
+    Some(x)
+}
+
+struct Opaque(u32);
+
+#[ensures(result == x)]
+#[ensures(result.0 == old(x.0))]
+fn opaque(x: Opaque) -> Opaque {
+    x
+}
+
+#[ensures(result != Opaque(n))]
+#[ensures(result.0 == n)]
+fn opaque_new(n: u32) -> Opaque {
+    Opaque(n)
+}
+
+#[ensures(result@ == x)]
+fn unchecked(x: u32) -> u32 {
+    x + 1
+}
+
+fn count(n: u32) -> u32 {
+    let mut i = 0;
+    #[invariant(i <= n)]
+    while i < n {
+        i += 1;
+    }
+    proof_assert!(i == n);
+    i
+}
+
+fn bad_count(n: u32) -> u32 {
+    let mut i = 0;
+    #[invariant(i < n)]
+    loop {
+        if i == n {
+            break;
+        }
+        i += 1;
+    }
+    i
+}
+
+#[test]
+fn preconditions() {
+    assert_eq!(incr(3), 4);
+    assert!(catch_unwind(|| incr(10)).is_err());
+    assert_eq!(first(&[1, 2, 0, 0], 2), 1);
+    assert!(catch_unwind(|| first(&[1, 0, 0, 0], 2)).is_err());
+}
+
+#[test]
+fn postconditions() {
+    assert!(catch_unwind(|| wrong_incr(3)).is_err());
+    let mut x = 1;
+    incr_mut(&mut x);
+    assert_eq!(x, 2);
+    assert_eq!(some(3), Some(3));
+    // No element is equal to the result when `n` is zero
+    assert!(catch_unwind(|| first(&[0; 4], 0)).is_err());
+}
+
+#[test]
+fn non_executable() {
+    assert_eq!(unchecked(1), 2);
+    assert_eq!(opaque(Opaque(1)).0, 1);
+    // The first postcondition cannot be evaluated, and is not checked
+    assert_eq!(opaque_new(1).0, 1);
+}
+
+#[test]
+fn loops() {
+    assert_eq!(count(5), 5);
+    assert!(catch_unwind(|| bad_count(5)).is_err());
+}
@@ -78,6 +78,7 @@ struct ImplData {
 pub struct FlatSpec {
     span: Span,
     attrs: Vec<Attribute>,
+    unsafety: Option<Unsafe>,
     path: ExprPath,
     generics: Generics,
     inputs: Punctuated<FnArg, Comma>,
@@ -204,7 +205,7 @@ impl FlatSpec {
         let sig = Signature {
             constness: None,
             asyncness: None,
-            unsafety: None,
+            unsafety: self.unsafety,
             abi: None,
             fn_token: Token![fn](self.span),
             ident,
@@ -257,7 +258,7 @@ impl syn::visit_mut::VisitMut for SelfEscape {
 fn escape_self_in_contracts(attrs: &mut Vec<Attribute>) -> Result<()> {
     for attr in attrs {
         if let Some(id) = attr.path().get_ident() {
-            if id == "ensures" || id == "requires" {
+            if id == "ensures" || id == "requires" || id == "panics_if" {
                 match &mut attr.meta {
                     Meta::List(l) => {
                         let tokens = std::mem::take(&mut l.tokens);
@@ -379,8 +380,13 @@ fn escape_self_in_term(t: &mut Term) {
             escape_self_in_term(hyp);
             escape_self_in_term(cons);
         }
-        Term::Forall(TermForall { term, .. }) => escape_self_in_term(term),
-        Term::Exists(TermExists { term, .. }) => escape_self_in_term(term),
+        Term::Forall(TermForall { triggers, term, .. })
+        | Term::Exists(TermExists { triggers, term, .. }) => {
+            for trigger in triggers {
+                trigger.terms.iter_mut().for_each(escape_self_in_term);
+            }
+            escape_self_in_term(term)
+        }
         Term::Absurd(TermAbsurd { .. }) => {}
         Term::Pearlite(TermPearlite { block, .. }) => escape_self_in_tblock(block),
         Term::Lit(TermLit { .. }) => {}
@@ -479,6 +485,7 @@ fn flatten(
             flat.push(FlatSpec {
                 span: fun.sig.span(),
                 attrs: fun.attrs,
+                unsafety: fun.sig.unsafety,
                 path: prefix,
                 impl_data,
                 generics: fun.sig.generics,
@@ -512,7 +519,9 @@ impl Parse for ExternSpec {
             Ok(ExternSpec::Impl(input.parse()?))
         } else if lookahead.peek(Token![trait]) {
             Ok(ExternSpec::Trait(input.parse()?))
-        } else if lookahead.peek(Token![fn]) {
+        } else if lookahead.peek(Token![fn])
+            || input.peek(Token![unsafe]) && input.peek2(Token![fn])
+        {
             let mut f: ExternMethod = input.parse()?;
             f.attrs.extend(attrs);
             Ok(ExternSpec::Fn(f))
@@ -42,6 +42,40 @@ impl ToTokens for Invariant {
     }
 }
 
+/// A `#[variant]` attached to a loop, which must decrease at each iteration.
+#[derive(Debug)]
+struct LoopVariant {
+    span: Span,
+    variant: pearlite_syn::Term,
+}
+
+impl syn::parse::Parse for LoopVariant {
+    fn parse(tokens: syn::parse::ParseStream) -> Result<Self> {
+        let span = tokens.span();
+        let variant = tokens.parse()?;
+
+        Ok(LoopVariant { span, variant })
+    }
+}
+
+impl ToTokens for LoopVariant {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let s = self.span;
+        let var_body = pretyping::encode_term(&self.variant).unwrap_or_else(|e| e.into_tokens());
+        let var_body = quote_spanned! {s=> #var_body};
+
+        tokens.extend(quote_spanned! {s=>
+            #[allow(unused_must_use)]
+            let _ = {
+                #[creusot::no_translate]
+                #[creusot::spec]
+                #[creusot::spec::variant::loop_]
+                ||{ ::creusot_contracts::__stubs::variant_check(#var_body) }
+            };
+        })
+    }
+}
+
 enum LoopKind {
     For(ExprForLoop),
     While(ExprWhile),
@@ -51,52 +85,88 @@ enum LoopKind {
 pub struct Loop {
     span: Span,
     invariants: Vec<Invariant>,
+    variant: Option<LoopVariant>,
     kind: LoopKind,
 }
 
-fn filter_invariants(attrs: &mut Vec<Attribute>) -> Vec<Attribute> {
+impl Loop {
+    fn set_variant(&mut self, variant: TokenStream) -> Result<()> {
+        if self.variant.is_some() {
+            return Err(Error::new_spanned(
+                variant,
+                "only one variant can be provided for each loop",
+            ));
+        }
+        self.variant = Some(syn::parse2(variant)?);
+        Ok(())
+    }
+}
+
+fn filter_loop_specs(attrs: &mut Vec<Attribute>) -> Vec<Attribute> {
     attrs
-        .extract_if(|attr| attr.path().get_ident().map(|i| i == "invariant").unwrap_or(false))
+        .extract_if(|attr| {
+            attr.path().get_ident().map(|i| i == "invariant" || i == "variant").unwrap_or(false)
+        })
         .collect()
 }
 
-pub fn parse(invariant: TokenStream, loopb: TokenStream) -> Result<Loop> {
+/// Parses a loop, along with the `#[invariant]` and `#[variant]` attributes attached to it.
+fn parse_loop(loopb: TokenStream, what: &str) -> Result<Loop> {
     let body: Expr = syn::parse2(loopb)?;
     let span = body.span();
     let (attrs, lkind) = match body {
-        Expr::ForLoop(mut floop) => (filter_invariants(&mut floop.attrs), LoopKind::For(floop)),
-        Expr::While(mut wloop) => (filter_invariants(&mut wloop.attrs), LoopKind::While(wloop)),
-        Expr::Loop(mut lloop) => (filter_invariants(&mut lloop.attrs), LoopKind::Loop(lloop)),
+        Expr::ForLoop(mut floop) => (filter_loop_specs(&mut floop.attrs), LoopKind::For(floop)),
+        Expr::While(mut wloop) => (filter_loop_specs(&mut wloop.attrs), LoopKind::While(wloop)),
+        Expr::Loop(mut lloop) => (filter_loop_specs(&mut lloop.attrs), LoopKind::Loop(lloop)),
         _ => {
             return Err(Error::new_spanned(
                 body,
-                "invariants must be attached to either a `for`, `loop` or `while`",
+                format!("{what} must be attached to either a `for`, `loop` or `while`"),
             ))
         }
     };
 
-    let mut invariants = vec![syn::parse2(invariant)?];
+    let mut loop_ = Loop { invariants: Vec::new(), variant: None, span, kind: lkind };
 
     for attr in attrs {
+        let is_variant = attr.path().is_ident("variant");
         if let Meta::List(l) = attr.meta {
-            invariants.push(syn::parse2(l.tokens)?);
+            if is_variant {
+                loop_.set_variant(l.tokens)?;
+            } else {
+                loop_.invariants.push(syn::parse2(l.tokens)?);
+            }
         } else {
             panic!()
         }
     }
 
-    Ok(Loop { invariants, span, kind: lkind })
+    Ok(loop_)
+}
+
+pub fn parse(invariant: TokenStream, loopb: TokenStream) -> Result<Loop> {
+    let mut loop_ = parse_loop(loopb, "invariants")?;
+    loop_.invariants.insert(0, syn::parse2(invariant)?);
+    Ok(loop_)
+}
+
+pub fn parse_variant(variant: TokenStream, loopb: TokenStream) -> Result<Loop> {
+    let mut loop_ = parse_loop(loopb, "variants")?;
+    loop_.set_variant(variant)?;
+    Ok(loop_)
 }
 
 pub fn lower(loop_: Loop) -> TokenStream {
     let invariants = loop_.invariants;
+    let variant = loop_.variant;
     match loop_.kind {
-        LoopKind::For(floop) => desugar_for(invariants, floop),
+        LoopKind::For(floop) => desugar_for(invariants, variant, floop),
         LoopKind::While(l) => {
             let mut tokens = TokenStream::new();
             for i in invariants {
                 i.to_tokens(&mut tokens);
             }
+            variant.to_tokens(&mut tokens);
             let sp = loop_.span;
             quote_spanned! {sp=>{
                 #tokens
@@ -106,6 +176,7 @@ pub fn lower(loop_: Loop) -> TokenStream {
         LoopKind::Loop(l) => {
             quote! {{
                 #(#invariants;)*
+                #variant
                 #l
             }}
         }
@@ -113,7 +184,11 @@ pub fn lower(loop_: Loop) -> TokenStream {
 }
 
 // Lowers for loops to `loop` and inserts the structural invariant that we get 'for free'
-fn desugar_for(mut invariants: Vec<Invariant>, f: ExprForLoop) -> TokenStream {
+fn desugar_for(
+    mut invariants: Vec<Invariant>,
+    variant: Option<LoopVariant>,
+    f: ExprForLoop,
+) -> TokenStream {
     let lbl = f.label;
     let pat = f.pat;
     let iter = f.expr;
@@ -147,6 +222,7 @@ fn desugar_for(mut invariants: Vec<Invariant>, f: ExprForLoop) -> TokenStream {
         let #iter_old = snapshot! { #it };
         let mut #produced = snapshot! { ::creusot_contracts::logic::Seq::EMPTY };
         #(#invariants;)*
+        #variant
         #(#outer)*
         #lbl
         loop {
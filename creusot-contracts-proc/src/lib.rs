@@ -7,7 +7,7 @@ use proc_macro2::{Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
 use std::iter;
 use syn::{
-    parse::{discouraged::Speculative, Parse, Result},
+    parse::{Parse, Result},
     spanned::Spanned,
     *,
 };
@@ -298,22 +298,38 @@ pub fn ensures(attr: TS1, tokens: TS1) -> TS1 {
     }
 }
 
-enum VariantAnnotation {
-    Fn(ItemFn),
-    WhileLoop(ExprWhile),
-}
+#[proc_macro_attribute]
+pub fn panics_if(attr: TS1, tokens: TS1) -> TS1 {
+    let mut item = parse_macro_input!(tokens as ContractSubject);
+    let term = parse_macro_input!(attr as Term);
+    item.mark_unused();
 
-impl syn::parse::Parse for VariantAnnotation {
-    fn parse(input: parse::ParseStream) -> Result<Self> {
-        let fork = input.fork();
-        if let Ok(f) = fork.parse() {
-            input.advance_to(&fork);
-            Ok(VariantAnnotation::Fn(f))
-        } else if let Ok(w) = input.parse() {
-            Ok(VariantAnnotation::WhileLoop(w))
-        } else {
-            Err(Error::new(Span::call_site(), "TEST?"))
+    let panic_name = generate_unique_ident(&item.name());
+    let name_tag = format!("{}", quote! { #panic_name });
+
+    match item {
+        ContractSubject::FnOrMethod(fn_or_meth) if fn_or_meth.is_trait_signature() => {
+            let panics_tokens = sig_spec_item(panic_name, fn_or_meth.sig.clone(), term);
+            TS1::from(quote! {
+              #panics_tokens
+              #[creusot::clause::panics_if=#name_tag]
+              #fn_or_meth
+            })
+        }
+        ContractSubject::FnOrMethod(mut f) => {
+            let panics_tokens = fn_spec_item(panic_name, None, term);
+
+            if let Some(b) = f.body.as_mut() {
+                b.stmts.insert(0, Stmt::Item(Item::Verbatim(panics_tokens)))
+            }
+            TS1::from(quote! {
+              #[creusot::clause::panics_if=#name_tag]
+              #f
+            })
         }
+        ContractSubject::Closure(clos) => TS1::from(
+            Error::new(clos.span(), "`panics_if` cannot be used on closures").to_compile_error(),
+        ),
     }
 }
 
@@ -326,9 +342,12 @@ pub fn variant(attr: TS1, tokens: TS1) -> TS1 {
 }
 
 fn variant_inner(attr: TS1, tokens: TS1) -> Result<TS1> {
-    let p: pearlite_syn::Term = parse(attr)?;
+    let Ok(mut f) = parse::<ItemFn>(tokens.clone()) else {
+        let loop_ = invariant::parse_variant(attr.into(), tokens.into())?;
+        return Ok(invariant::lower(loop_).into());
+    };
 
-    let tgt: VariantAnnotation = parse(tokens)?;
+    let p: pearlite_syn::Term = parse(attr)?;
 
     let var_name = generate_unique_ident("variant");
 
@@ -337,33 +356,22 @@ fn variant_inner(attr: TS1, tokens: TS1) -> Result<TS1> {
     });
     let name_tag = format!("{}", quote! { #var_name });
 
-    let variant_attr = match tgt {
-        VariantAnnotation::Fn(_) => quote! { #[creusot::spec::variant] },
-        VariantAnnotation::WhileLoop(_) => quote! { #[creusot::spec::variant::loop_] },
-    };
     let variant_tokens = quote! {
         #[allow(unused_must_use)]
         let _ =
             #[creusot::no_translate]
             #[creusot::item=#name_tag]
-            #variant_attr
+            #[creusot::spec::variant]
             #[creusot::spec]
             ||{ ::creusot_contracts::__stubs::variant_check(#var_body) }
         ;
     };
 
-    match tgt {
-        VariantAnnotation::Fn(mut f) => {
-            f.block.stmts.insert(0, Stmt::Item(Item::Verbatim(variant_tokens)));
-            Ok(TS1::from(quote! {
-              #[creusot::clause::variant=#name_tag]
-              #f
-            }))
-        }
-        VariantAnnotation::WhileLoop(w) => Ok(TS1::from(quote! {
-          { #variant_tokens; #w }
-        })),
-    }
+    f.block.stmts.insert(0, Stmt::Item(Item::Verbatim(variant_tokens)));
+    Ok(TS1::from(quote! {
+      #[creusot::clause::variant=#name_tag]
+      #f
+    }))
 }
 
 struct Assertion(Vec<TermStmt>);
@@ -417,6 +425,13 @@ pub fn terminates(_: TS1, tokens: TS1) -> TS1 {
     result
 }
 
+#[proc_macro_attribute]
+pub fn may_panic(_: TS1, tokens: TS1) -> TS1 {
+    let mut result = TS1::from(quote! { #[creusot::clause::may_panic] });
+    result.extend(tokens);
+    result
+}
+
 #[proc_macro_attribute]
 pub fn pure(_: TS1, tokens: TS1) -> TS1 {
     let mut result =
@@ -603,6 +618,26 @@ fn predicate_item(log: LogicItem, prophetic: Option<TokenStream>) -> TS1 {
     })
 }
 
+#[proc_macro_attribute]
+pub fn inductive(_: TS1, tokens: TS1) -> TS1 {
+    let tokens = TokenStream::from(tokens);
+    TS1::from(quote! {
+        #[creusot::decl::inductive]
+        #[::creusot_contracts::predicate]
+        #tokens
+    })
+}
+
+#[proc_macro_attribute]
+pub fn coinductive(_: TS1, tokens: TS1) -> TS1 {
+    let tokens = TokenStream::from(tokens);
+    TS1::from(quote! {
+        #[creusot::decl::coinductive]
+        #[::creusot_contracts::predicate]
+        #tokens
+    })
+}
+
 #[proc_macro_attribute]
 pub fn trusted(_: TS1, tokens: TS1) -> TS1 {
     // let p: ItemFn = parse_macro_input!(tokens);
@@ -99,7 +99,10 @@ pub fn encode_term(term: &RT) -> Result<TokenStream, EncodeError> {
             let func = encode_term(func)?;
             Ok(quote_spanned! {sp=> #func (#(#args),*)})
         }
-        RT::Cast(_) => Err(EncodeError::Unsupported(term.span(), "Cast".into())),
+        RT::Cast(TermCast { expr, as_token, ty }) => {
+            let expr = encode_term(expr)?;
+            Ok(quote_spanned! {sp=> #expr #as_token #ty })
+        }
         RT::Field(TermField { base, member, .. }) => {
             let base = encode_term(base)?;
             Ok(quote!({ #base . #member }))
@@ -249,8 +252,8 @@ pub fn encode_term(term: &RT) -> Result<TokenStream, EncodeError> {
                 ::creusot_contracts::__stubs::implication(#hyp, #cons)
             })
         }
-        RT::Forall(TermForall { args, term, .. }) => {
-            let mut ts = encode_term(term)?;
+        RT::Forall(TermForall { args, triggers, term, .. }) => {
+            let mut ts = encode_triggers(triggers, encode_term(term)?)?;
             for arg in args {
                 ts = quote! {
                     ::creusot_contracts::__stubs::forall(
@@ -261,8 +264,8 @@ pub fn encode_term(term: &RT) -> Result<TokenStream, EncodeError> {
             }
             Ok(ts)
         }
-        RT::Exists(TermExists { args, term, .. }) => {
-            let mut ts = encode_term(term)?;
+        RT::Exists(TermExists { args, triggers, term, .. }) => {
+            let mut ts = encode_triggers(triggers, encode_term(term)?)?;
             for arg in args {
                 ts = quote! {
                     ::creusot_contracts::__stubs::exists(
@@ -288,6 +291,17 @@ pub fn encode_term(term: &RT) -> Result<TokenStream, EncodeError> {
     }
 }
 
+// Triggers are attached to the body of the innermost quantifier, which binds all the variables.
+fn encode_triggers(triggers: &[Trigger], mut ts: TokenStream) -> Result<TokenStream, EncodeError> {
+    for trigger in triggers.iter().rev() {
+        let terms: Vec<_> = trigger.terms.iter().map(encode_term).collect::<Result<_, _>>()?;
+        ts = quote! {
+            ::creusot_contracts::__stubs::trigger((#(#terms,)*), #ts)
+        }
+    }
+    Ok(ts)
+}
+
 pub fn encode_block(block: &Vec<TermStmt>) -> Result<TokenStream, EncodeError> {
     let stmts: Vec<_> = block.iter().map(encode_stmt).collect::<Result<_, _>>()?;
     Ok(quote! { { #(#stmts)* } })
@@ -320,9 +334,15 @@ fn encode_pattern(pat: &Pat) -> Result<TokenStream, EncodeError> {
 fn encode_arm(arm: &TermArm) -> Result<TokenStream, EncodeError> {
     let body = encode_term(&arm.body)?;
     let pat = &arm.pat;
-    // let (if_tok, guard) = arm.guard;
+    let guard = match &arm.guard {
+        Some((if_tok, guard)) => {
+            let guard = encode_term(guard)?;
+            quote! { #if_tok #guard }
+        }
+        None => TokenStream::new(),
+    };
     let comma = arm.comma;
-    Ok(quote! { #pat  => #body #comma })
+    Ok(quote! { #pat #guard => #body #comma })
 }
 
 #[cfg(test)]
@@ -381,6 +401,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn encode_trigger() {
+        let term: Term = syn::parse_str("forall<x:Int> #[trigger(f(x))] f(x)").unwrap();
+        assert_eq!(
+            format!("{}", encode_term(&term).unwrap()),
+            ":: creusot_contracts :: __stubs :: forall (# [creusot :: no_translate] | x : Int | { :: creusot_contracts :: __stubs :: trigger ((f (x) ,) , f (x)) })"
+        );
+    }
+
+    #[test]
+    fn encode_match_guard() {
+        let term: Term =
+            syn::parse_str("match x { Some(y) if y == z => true, _ => false }").unwrap();
+        assert_eq!(
+            format!("{}", encode_term(&term).unwrap()),
+            "match x { Some (y) if :: creusot_contracts :: __stubs :: equal (y , z) => true , _ => false }"
+        );
+    }
+
     #[test]
     fn encode_exists() {
         let term: Term = syn::parse_str("exists<x:Int> x == x").unwrap();
@@ -1,5 +1,5 @@
 // Inspired by https://plv.mpi-sws.org/rustbelt/ghostcell/ https://rust-unofficial.github.io/too-many-lists/fifth.html
-use crate::{logic::FMap, Clone, *};
+use crate::{logic::FMap, ptr_own::PtrOwn, Clone, *};
 use ::std::{
     marker::PhantomData,
     ops::{Deref, DerefMut},
@@ -100,6 +100,33 @@ impl<T: ?Sized> GhostPtrToken<T> {
         unsafe { Box::from_raw(ptr as *mut _) }
     }
 
+    /// Takes the permission to `ptr` out of `self`
+    #[trusted]
+    #[requires((*self)@.contains(ptr))]
+    #[ensures(result.ptr() == ptr)]
+    #[ensures(result.val() == Some((*self)@.lookup(ptr)))]
+    #[ensures((^self)@ == (*self)@.remove(ptr))]
+    #[allow(unused_variables)]
+    pub fn take_own(&mut self, ptr: *const T) -> PtrOwn<T>
+    where
+        T: Sized,
+    {
+        PtrOwn(PhantomData)
+    }
+
+    /// Gives the permission `own` to `self`
+    #[trusted]
+    #[requires(own.val() != None)]
+    #[ensures(!(*self)@.contains(own.ptr()))]
+    #[ensures(Some((^self)@.lookup(own.ptr())) == own.val())]
+    #[ensures((^self)@ == (*self)@.insert(own.ptr(), (^self)@.lookup(own.ptr())))]
+    #[allow(unused_variables)]
+    pub fn give_own(&mut self, own: PtrOwn<T>)
+    where
+        T: Sized,
+    {
+    }
+
     #[trusted]
     #[ensures((*self)@.disjoint(other@))]
     // Since we had full permission to and all of the entries in `self` and `other` simultaneously,
@@ -145,6 +172,23 @@ impl<T: ?Sized> GhostPtrExt<T> for GhostPtr<T> {
     fn addr_logic(self) -> Int {
         absurd
     }
+
+    #[logic]
+    #[open]
+    fn offset_logic(self, count: Int) -> Self
+    where
+        T: Sized,
+    {
+        pearlite! { self.byte_offset_logic(count * crate::std::mem::size_of_logic::<T>()) }
+    }
+
+    #[trusted]
+    #[logic]
+    #[open(self)]
+    #[creusot::builtins = "prelude.prelude.Opaque.offset_logic"]
+    fn byte_offset_logic(self, _: Int) -> Self {
+        absurd
+    }
 }
 
 impl<'a, T: ?Sized> ShallowModel for GhostPtrTokenRef<'a, T> {
@@ -283,6 +327,16 @@ pub trait GhostPtrExt<T: ?Sized>: Sized {
     fn null_logic() -> Self;
     #[logic]
     fn addr_logic(self) -> Int;
+    /// The pointer `count` elements of type `T` after `self`
+    #[logic]
+    fn offset_logic(self, count: Int) -> Self
+    where
+        T: Sized;
+    /// The pointer `count` bytes after `self`
+    ///
+    /// Casts between raw pointers do not change their address, so offsets are counted in bytes.
+    #[logic]
+    fn byte_offset_logic(self, count: Int) -> Self;
 }
 
 extern_spec! {
@@ -296,6 +350,52 @@ extern_spec! {
         #[trusted]
         #[ensures(result == (self == GhostPtr::<T>::null_logic()))]
         fn is_null(self) -> bool;
+
+        // Offsetting a pointer out of its allocation is undefined behavior, and the allocation
+        // is only known from a permission: other offsets must go through `PtrOwn::offset`.
+        #[requires(count@ == 0)]
+        #[ensures(result == self)]
+        unsafe fn offset(self, count: isize) -> *const T;
+
+        #[requires(count@ == 0)]
+        #[ensures(result == self)]
+        unsafe fn add(self, count: usize) -> *const T;
+
+        #[requires(count@ == 0)]
+        #[ensures(result == self)]
+        unsafe fn sub(self, count: usize) -> *const T;
+
+        #[ensures(result == self.offset_logic(count@))]
+        fn wrapping_add(self, count: usize) -> *const T;
+
+        #[ensures(result == self.offset_logic(-count@))]
+        fn wrapping_sub(self, count: usize) -> *const T;
+    }
+
+    impl<T> *mut T {
+        #[ensures(result == (self as *const T == GhostPtr::<T>::null_logic()))]
+        fn is_null(self) -> bool;
+
+        #[ensures(result == self as *const T)]
+        fn cast_const(self) -> *const T;
+
+        #[requires(count@ == 0)]
+        #[ensures(result == self)]
+        unsafe fn offset(self, count: isize) -> *mut T;
+
+        #[requires(count@ == 0)]
+        #[ensures(result == self)]
+        unsafe fn add(self, count: usize) -> *mut T;
+
+        #[requires(count@ == 0)]
+        #[ensures(result == self)]
+        unsafe fn sub(self, count: usize) -> *mut T;
+
+        #[ensures(result as *const T == (self as *const T).offset_logic(count@))]
+        fn wrapping_add(self, count: usize) -> *mut T;
+
+        #[ensures(result as *const T == (self as *const T).offset_logic(-count@))]
+        fn wrapping_sub(self, count: usize) -> *mut T;
     }
 
     mod std {
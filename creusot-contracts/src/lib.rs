@@ -6,7 +6,15 @@
     feature(print_internals, fmt_internals, fmt_helpers_for_derive)
 )]
 #![cfg_attr(feature = "typechecker", feature(rustc_private), feature(box_patterns))]
-#![feature(step_trait, allocator_api, unboxed_closures, tuple_trait, strict_provenance)]
+#![feature(
+    step_trait,
+    allocator_api,
+    unboxed_closures,
+    tuple_trait,
+    strict_provenance,
+    pattern,
+    slice_concat_trait
+)]
 #![cfg_attr(not(creusot), feature(rustc_attrs))]
 #![cfg_attr(not(creusot), allow(internal_features))]
 
@@ -27,6 +35,21 @@ mod macros {
 
     pub use base_macros::snapshot;
 
+    /// Indicate that the function panics exactly when the given condition holds on
+    /// its arguments.
+    ///
+    /// A panic is then a proper exit of the function rather than an error: the body
+    /// may reach `panic!`, `assert!` or `unreachable!` when the condition holds, and
+    /// must return normally when it does not. Callers learn the condition when the
+    /// call panics. If several `panics_if` clauses are given, the function panics
+    /// when any of them holds.
+    pub use base_macros::panics_if;
+
+    /// Indicate that the function may panic, without saying when.
+    ///
+    /// Callers of such a function must themselves be allowed to panic.
+    pub use base_macros::may_panic;
+
     /// Indicate that the function terminates: fullfilling the `requires` clauses
     /// ensures that this function will not loop indefinitively.
     pub use base_macros::terminates;
@@ -107,6 +130,25 @@ mod macros {
     /// called from a regular [`logic`] or [`predicate`] function.
     pub use base_macros::predicate;
 
+    /// Declare a predicate as being _inductive_: it holds exactly for the arguments that can be
+    /// derived by finitely many applications of its introduction rules.
+    ///
+    /// Each disjunct of the body (separated by a top-level `||`) is an introduction rule, and the
+    /// predicate can only occur in strictly positive positions of the body. The body needs no
+    /// `#[variant]`, but the predicate cannot have a contract.
+    /// ```ignore
+    /// #[inductive]
+    /// fn even(n: Int) -> bool {
+    ///     pearlite! { n == 0 || (n >= 2 && even(n - 2)) }
+    /// }
+    /// ```
+    pub use base_macros::inductive;
+
+    /// Declare a predicate as being _coinductive_: like [`inductive`], but the predicate holds
+    /// for all the arguments that are consistent with its rules, including through infinitely many
+    /// applications of them.
+    pub use base_macros::coinductive;
+
     /// Inserts a *logical* assertion into the code. This assertion will not be checked at runtime
     /// but only during proofs. However, it has access to the ghost context and can use logical operations
     /// and syntax.
@@ -116,8 +158,9 @@ mod macros {
     /// valid.
     pub use base_macros::trusted;
 
-    /// Declares a variant for a function, this is primarily used in combination with logical functions
-    /// The variant must be an expression which returns a type implementing [WellFounded]
+    /// Declares a variant for a function or a loop, which must decrease at each recursive call
+    /// or iteration. The variant must be an expression which returns a type implementing
+    /// [WellFounded]
     pub use base_macros::variant;
 
     /// Enables Pearlite syntax, granting access to Pearlite specific operators and syntax
@@ -183,6 +226,7 @@ pub mod snapshot {
 pub mod ghost_ptr;
 pub mod invariant;
 pub mod model;
+pub mod ptr_own;
 pub mod resolve;
 pub mod util;
 pub mod well_founded;
@@ -190,7 +234,7 @@ pub mod well_founded;
 // We add some common things at the root of the creusot-contracts library
 mod base_prelude {
     pub use crate::{
-        logic::{IndexLogic as _, Int, OrdLogic, Seq},
+        logic::{FloatLogic as _, IndexLogic as _, Int, OrdLogic, Seq, ToBv as _},
         model::{DeepModel, ShallowModel},
         resolve::Resolve,
         snapshot::Snapshot,
@@ -205,16 +249,21 @@ mod base_prelude {
             clone::Clone,
             cmp::PartialEq,
             default::Default,
-            iter::{FromIterator, IntoIterator, Iterator},
+            iter::{DoubleEndedIterator, FromIterator, IntoIterator, Iterator},
         },
         well_founded::WellFounded,
     };
 
     // Export extension traits anonymously
-    pub use crate::std::{
-        iter::{SkipExt as _, TakeExt as _},
-        ops::{FnExt as _, FnMutExt as _, FnOnceExt as _, RangeInclusiveExt as _},
-        slice::SliceExt as _,
+    pub use crate::{
+        ghost_ptr::GhostPtrExt as _,
+        std::{
+            char::CharExt as _,
+            iter::{SkipExt as _, TakeExt as _},
+            ops::{FnExt as _, FnMutExt as _, FnOnceExt as _, RangeInclusiveExt as _},
+            slice::SliceExt as _,
+            string::{FromStrSpec as _, PatternExt as _},
+        },
     };
 }
 pub mod prelude {
@@ -1,14 +1,19 @@
 #![cfg_attr(not(creusot), allow(unused_imports))]
 
+mod bv;
+mod float;
 mod fmap;
 mod fset;
 mod int;
+pub mod lemmas;
 mod mapping;
 mod ops;
 pub mod ord;
 mod seq;
 mod set;
 
+pub use bv::{ToBv, BV128, BV16, BV32, BV64, BV8};
+pub use float::FloatLogic;
 pub use fmap::FMap;
 pub use fset::FSet;
 pub use int::Int;
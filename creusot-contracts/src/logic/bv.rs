@@ -0,0 +1,298 @@
+use crate::*;
+
+macro_rules! bv {
+    ($name:ident, $th:expr, $bits:literal) => {
+        #[doc = concat!("Bit vectors of ", $bits, " bits, with wrap-around arithmetic.")]
+        #[cfg_attr(creusot, creusot::builtins = concat!($th, ".t"))]
+        #[allow(dead_code)]
+        pub struct $name(*mut ());
+
+        impl $name {
+            /// The bit vector whose unsigned value is `i` modulo 2 to the number of bits.
+            #[trusted]
+            #[logic]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".of_int")]
+            pub fn of_int(_: Int) -> Self {
+                absurd
+            }
+
+            /// The value of `self` as an unsigned integer.
+            #[trusted]
+            #[logic]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".to_uint")]
+            pub fn to_uint(self) -> Int {
+                absurd
+            }
+
+            /// The value of `self` as a signed integer, in two's complement.
+            #[trusted]
+            #[logic]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".to_int")]
+            pub fn to_int(self) -> Int {
+                absurd
+            }
+
+            /// The bit vector with all bits unset.
+            #[trusted]
+            #[logic]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".zeros")]
+            pub fn zeros() -> Self {
+                absurd
+            }
+
+            /// The bit vector with all bits set.
+            #[trusted]
+            #[logic]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".ones")]
+            pub fn ones() -> Self {
+                absurd
+            }
+
+            /// Whether the `i`-th bit of `self` is set, the least significant bit being the 0-th.
+            #[trusted]
+            #[predicate]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".nth")]
+            pub fn nth(self, _: Int) -> bool {
+                absurd
+            }
+
+            #[trusted]
+            #[logic]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".add")]
+            pub fn add(self, _: Self) -> Self {
+                absurd
+            }
+
+            #[trusted]
+            #[logic]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".sub")]
+            pub fn sub(self, _: Self) -> Self {
+                absurd
+            }
+
+            #[trusted]
+            #[logic]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".mul")]
+            pub fn mul(self, _: Self) -> Self {
+                absurd
+            }
+
+            #[trusted]
+            #[logic]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".neg")]
+            pub fn neg(self) -> Self {
+                absurd
+            }
+
+            /// Unsigned division. The result of a division by zero is unspecified.
+            #[trusted]
+            #[logic]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".udiv")]
+            pub fn udiv(self, _: Self) -> Self {
+                absurd
+            }
+
+            /// Unsigned remainder. The result of a division by zero is unspecified.
+            #[trusted]
+            #[logic]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".urem")]
+            pub fn urem(self, _: Self) -> Self {
+                absurd
+            }
+
+            /// Signed division, rounding towards zero. The result of a division by zero is
+            /// unspecified.
+            #[trusted]
+            #[logic]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".sdiv")]
+            pub fn sdiv(self, _: Self) -> Self {
+                absurd
+            }
+
+            /// Signed remainder, of the sign of `self`. The result of a division by zero is
+            /// unspecified.
+            #[trusted]
+            #[logic]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".srem")]
+            pub fn srem(self, _: Self) -> Self {
+                absurd
+            }
+
+            #[trusted]
+            #[logic]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".bw_and")]
+            pub fn and(self, _: Self) -> Self {
+                absurd
+            }
+
+            #[trusted]
+            #[logic]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".bw_or")]
+            pub fn or(self, _: Self) -> Self {
+                absurd
+            }
+
+            #[trusted]
+            #[logic]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".bw_xor")]
+            pub fn xor(self, _: Self) -> Self {
+                absurd
+            }
+
+            #[trusted]
+            #[logic]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".bw_not")]
+            pub fn not(self) -> Self {
+                absurd
+            }
+
+            /// Shift to the left, the result is zero if the shift is larger than the size.
+            #[trusted]
+            #[logic]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".lsl")]
+            pub fn shl(self, _: Int) -> Self {
+                absurd
+            }
+
+            /// Logical shift to the right, filling with zeros.
+            #[trusted]
+            #[logic]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".lsr")]
+            pub fn lshr(self, _: Int) -> Self {
+                absurd
+            }
+
+            /// Arithmetic shift to the right, filling with the sign bit.
+            #[trusted]
+            #[logic]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".asr")]
+            pub fn ashr(self, _: Int) -> Self {
+                absurd
+            }
+
+            #[trusted]
+            #[logic]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".rotate_left")]
+            pub fn rotate_left(self, _: Int) -> Self {
+                absurd
+            }
+
+            #[trusted]
+            #[logic]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".rotate_right")]
+            pub fn rotate_right(self, _: Int) -> Self {
+                absurd
+            }
+
+            #[trusted]
+            #[predicate]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".ult")]
+            pub fn ult(self, _: Self) -> bool {
+                absurd
+            }
+
+            #[trusted]
+            #[predicate]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".ule")]
+            pub fn ule(self, _: Self) -> bool {
+                absurd
+            }
+
+            #[trusted]
+            #[predicate]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".slt")]
+            pub fn slt(self, _: Self) -> bool {
+                absurd
+            }
+
+            #[trusted]
+            #[predicate]
+            #[open(self)]
+            #[creusot::builtins = concat!($th, ".sle")]
+            pub fn sle(self, _: Self) -> bool {
+                absurd
+            }
+        }
+    };
+}
+
+bv!(BV8, "bv.BV8", 8);
+bv!(BV16, "bv.BV16", 16);
+bv!(BV32, "bv.BV32", 32);
+bv!(BV64, "bv.BV64", 64);
+bv!(BV128, "bv.BV128", 128);
+
+/// Machine integers seen as bit vectors of the same width.
+pub trait ToBv {
+    type Bv;
+
+    #[logic]
+    fn to_bv(self) -> Self::Bv;
+}
+
+macro_rules! to_bv {
+    ($t:ty, $bv:ident, unsigned) => {
+        impl ToBv for $t {
+            type Bv = $bv;
+
+            #[logic]
+            #[open]
+            #[ensures(result.to_uint() == self@)]
+            fn to_bv(self) -> $bv {
+                pearlite! { $bv::of_int(self@) }
+            }
+        }
+    };
+    ($t:ty, $bv:ident, signed) => {
+        impl ToBv for $t {
+            type Bv = $bv;
+
+            #[logic]
+            #[open]
+            #[ensures(result.to_int() == self@)]
+            fn to_bv(self) -> $bv {
+                pearlite! { $bv::of_int(self@) }
+            }
+        }
+    };
+}
+
+to_bv!(u8, BV8, unsigned);
+to_bv!(u16, BV16, unsigned);
+to_bv!(u32, BV32, unsigned);
+to_bv!(u64, BV64, unsigned);
+to_bv!(u128, BV128, unsigned);
+to_bv!(usize, BV64, unsigned);
+
+to_bv!(i8, BV8, signed);
+to_bv!(i16, BV16, signed);
+to_bv!(i32, BV32, signed);
+to_bv!(i64, BV64, signed);
+to_bv!(i128, BV128, signed);
+to_bv!(isize, BV64, signed);
@@ -0,0 +1,52 @@
+use crate::*;
+
+/// Logical operations on IEEE-754 floating-point numbers.
+///
+/// The comparisons follow IEEE-754: every comparison involving NaN is false, and `-0.0` is equal
+/// to `+0.0`. This differs from the logical equality `==`, which is structural.
+pub trait FloatLogic {
+    #[predicate]
+    fn is_nan_log(self) -> bool;
+
+    #[predicate]
+    fn is_infinite_log(self) -> bool;
+
+    #[predicate]
+    fn is_finite_log(self) -> bool;
+
+    #[predicate]
+    fn is_zero_log(self) -> bool;
+
+    /// The number is positive, `+0.0` or `+inf`
+    #[predicate]
+    fn is_positive_log(self) -> bool;
+
+    /// The number is negative, `-0.0` or `-inf`
+    #[predicate]
+    fn is_negative_log(self) -> bool;
+
+    #[logic]
+    fn abs_log(self) -> Self;
+
+    #[logic]
+    fn sqrt_log(self) -> Self;
+
+    /// Fused multiply-add: `self * a + b` with a single rounding
+    #[logic]
+    fn mul_add_log(self, a: Self, b: Self) -> Self;
+
+    #[predicate]
+    fn eq_log(self, o: Self) -> bool;
+
+    #[predicate]
+    fn lt_log(self, o: Self) -> bool;
+
+    #[predicate]
+    fn le_log(self, o: Self) -> bool;
+
+    #[predicate]
+    fn gt_log(self, o: Self) -> bool;
+
+    #[predicate]
+    fn ge_log(self, o: Self) -> bool;
+}
@@ -0,0 +1,466 @@
+//! Lemmas about [`Seq`], [`FSet`] and [`FMap`], and higher-order operations on sequences.
+//!
+//! A lemma is a logic function returning `()`: calling it in a proof (for example in a
+//! `proof_assert!`) brings its postcondition into the context.
+//!
+//! This file is also included in the test suite so that the proofs of the lemmas are checked,
+//! hence it only refers to this crate through `creusot_contracts`.
+
+use creusot_contracts::{
+    logic::{FMap, FSet, Mapping},
+    *,
+};
+
+// Sequences
+
+/// Concatenation is associative.
+#[logic]
+#[open]
+#[ensures(a.concat(b).concat(c) == a.concat(b.concat(c)))]
+pub fn concat_assoc<T>(a: Seq<T>, b: Seq<T>, c: Seq<T>) {}
+
+/// The empty sequence is neutral for concatenation.
+#[logic]
+#[open]
+#[ensures(Seq::EMPTY.concat(s) == s)]
+#[ensures(s.concat(Seq::EMPTY) == s)]
+pub fn concat_empty<T>(s: Seq<T>) {}
+
+/// The tail of a concatenation, when the first sequence is not empty.
+#[logic]
+#[open]
+#[requires(a.len() > 0)]
+#[ensures(a.concat(b)[0] == a[0])]
+#[ensures(a.concat(b).tail() == a.tail().concat(b))]
+pub fn concat_tail<T>(a: Seq<T>, b: Seq<T>) {}
+
+/// Both operands can be recovered from a concatenation.
+#[logic]
+#[open]
+#[ensures(a.concat(b).subsequence(0, a.len()) == a)]
+#[ensures(a.concat(b).subsequence(a.len(), a.len() + b.len()) == b)]
+pub fn concat_subsequence<T>(a: Seq<T>, b: Seq<T>) {}
+
+/// Adding an element at the end is concatenating a singleton.
+#[logic]
+#[open]
+#[ensures(s.push(x) == s.concat(Seq::singleton(x)))]
+pub fn push_concat<T>(s: Seq<T>, x: T) {}
+
+/// The whole subsequence of a sequence is the sequence itself.
+#[logic]
+#[open]
+#[ensures(s.subsequence(0, s.len()) == s)]
+pub fn subsequence_full<T>(s: Seq<T>) {}
+
+/// Two adjacent subsequences can be merged.
+#[logic]
+#[open]
+#[requires(0 <= i && i <= j && j <= k && k <= s.len())]
+#[ensures(s.subsequence(i, j).concat(s.subsequence(j, k)) == s.subsequence(i, k))]
+pub fn subsequence_split<T>(s: Seq<T>, i: Int, j: Int, k: Int) {}
+
+/// A subsequence of a subsequence is a subsequence.
+#[logic]
+#[open]
+#[requires(0 <= i && i <= j && j <= s.len())]
+#[requires(0 <= k && k <= l && l <= j - i)]
+#[ensures(s.subsequence(i, j).subsequence(k, l) == s.subsequence(i + k, i + l))]
+pub fn subsequence_subsequence<T>(s: Seq<T>, i: Int, j: Int, k: Int, l: Int) {}
+
+/// An element of a non-empty sequence is either its head or an element of its tail.
+#[logic]
+#[open]
+#[requires(s.len() > 0)]
+#[ensures(s.contains(x) == (s[0] == x || s.tail().contains(x)))]
+pub fn contains_tail<T>(s: Seq<T>, x: T) {}
+
+/// An element of a concatenation is an element of one of the operands.
+#[logic]
+#[open]
+#[ensures(a.concat(b).contains(x) == (a.contains(x) || b.contains(x)))]
+pub fn contains_concat<T>(a: Seq<T>, b: Seq<T>, x: T) {}
+
+/// Reversing twice gives back the sequence.
+#[logic]
+#[open]
+#[ensures(s.reverse().len() == s.len())]
+#[ensures(s.reverse().reverse() == s)]
+#[ensures(s.reverse().reverse().ext_eq(s))]
+pub fn reverse_reverse<T>(s: Seq<T>) {}
+
+/// Reversing a concatenation reverses the order of the operands.
+#[logic]
+#[open]
+#[ensures(a.concat(b).reverse() == b.reverse().concat(a.reverse()))]
+#[ensures(a.concat(b).reverse().ext_eq(b.reverse().concat(a.reverse())))]
+pub fn reverse_concat<T>(a: Seq<T>, b: Seq<T>) {}
+
+/// Every sequence is a permutation of itself.
+#[logic]
+#[open]
+#[ensures(s.permutation_of(s))]
+pub fn permutation_refl<T>(s: Seq<T>) {}
+
+/// Being a permutation is symmetric.
+#[logic]
+#[open]
+#[requires(a.permutation_of(b))]
+#[ensures(b.permutation_of(a))]
+pub fn permutation_symm<T>(a: Seq<T>, b: Seq<T>) {}
+
+/// Being a permutation is transitive.
+#[logic]
+#[open]
+#[requires(a.permutation_of(b))]
+#[requires(b.permutation_of(c))]
+#[ensures(a.permutation_of(c))]
+pub fn permutation_trans<T>(a: Seq<T>, b: Seq<T>, c: Seq<T>) {}
+
+/// Permutations have the same length.
+#[logic]
+#[open]
+#[requires(a.permutation_of(b))]
+#[ensures(a.len() == b.len())]
+pub fn permutation_len<T>(a: Seq<T>, b: Seq<T>) {}
+
+/// Exchanging two elements gives a permutation.
+#[logic]
+#[open]
+#[requires(a.exchange(b, i, j))]
+#[ensures(a.permutation_of(b))]
+pub fn exchange_permutation<T>(a: Seq<T>, b: Seq<T>, i: Int, j: Int) {}
+
+/// A subsequence of a sorted sequence is sorted.
+#[logic]
+#[open]
+#[requires(s.sorted())]
+#[requires(0 <= i && i <= j && j <= s.len())]
+#[ensures(s.subsequence(i, j).sorted())]
+pub fn sorted_subsequence<T: OrdLogic>(s: Seq<T>, i: Int, j: Int) {}
+
+/// Adding an element larger than the last one to a sorted sequence keeps it sorted.
+#[logic]
+#[open]
+#[requires(s.sorted())]
+#[requires(s.len() > 0 ==> s[s.len() - 1] <= x)]
+#[ensures(s.push(x).sorted())]
+pub fn sorted_push<T: OrdLogic>(s: Seq<T>, x: T) {}
+
+/// The concatenation of two sorted sequences, the first one being smaller than the second one, is
+/// sorted.
+#[logic]
+#[open]
+#[requires(a.sorted())]
+#[requires(b.sorted())]
+#[requires(forall<i: Int, j: Int> 0 <= i && i < a.len() && 0 <= j && j < b.len() ==> a[i] <= b[j])]
+#[ensures(a.concat(b).sorted())]
+pub fn sorted_concat<T: OrdLogic>(a: Seq<T>, b: Seq<T>) {}
+
+// Finite sets
+
+/// The length of a set after an insertion.
+#[logic]
+#[open]
+#[ensures(!s.contains(x) ==> s.insert(x).len() == s.len() + 1)]
+#[ensures(s.contains(x) ==> s.insert(x) == s)]
+pub fn fset_insert_len<T>(s: FSet<T>, x: T) {}
+
+/// The length of a set after a removal.
+#[logic]
+#[open]
+#[ensures(s.contains(x) ==> s.remove(x).len() == s.len() - 1)]
+#[ensures(!s.contains(x) ==> s.remove(x) == s)]
+pub fn fset_remove_len<T>(s: FSet<T>, x: T) {}
+
+/// A subset is smaller than its superset.
+#[logic]
+#[open]
+#[requires(a.is_subset(b))]
+#[ensures(a.len() <= b.len())]
+pub fn fset_subset_len<T>(a: FSet<T>, b: FSet<T>) {}
+
+/// Being a subset is transitive.
+#[logic]
+#[open]
+#[requires(a.is_subset(b))]
+#[requires(b.is_subset(c))]
+#[ensures(a.is_subset(c))]
+pub fn fset_subset_trans<T>(a: FSet<T>, b: FSet<T>, c: FSet<T>) {}
+
+/// The union of two sets contains both of them.
+#[logic]
+#[open]
+#[ensures(forall<x: T> a.union(b).contains(x) == (a.contains(x) || b.contains(x)))]
+#[ensures(a.is_subset(a.union(b)) && b.is_subset(a.union(b)))]
+#[ensures(a.len() <= a.union(b).len() && b.len() <= a.union(b).len())]
+pub fn fset_union<T>(a: FSet<T>, b: FSet<T>) {
+    pearlite! {
+        fset_subset_len(a, a.union(b));
+        fset_subset_len(b, a.union(b))
+    }
+}
+
+// Finite maps
+
+/// The value of a key after an insertion.
+#[logic]
+#[open]
+#[ensures(m.insert(k, v).contains(k))]
+#[ensures(m.insert(k, v).lookup(k) == v)]
+#[ensures(forall<k2: K> k2 != k ==> m.insert(k, v).get(k2) == m.get(k2))]
+pub fn fmap_insert_get<K, V>(m: FMap<K, V>, k: K, v: V) {}
+
+/// The value of a key after a removal.
+#[logic]
+#[open]
+#[ensures(!m.remove(k).contains(k))]
+#[ensures(forall<k2: K> k2 != k ==> m.remove(k).get(k2) == m.get(k2))]
+pub fn fmap_remove_get<K, V>(m: FMap<K, V>, k: K) {}
+
+/// Removing a key that was just inserted gives back the map without this key.
+#[logic]
+#[open]
+#[ensures(m.insert(k, v).remove(k).ext_eq(m.remove(k)))]
+pub fn fmap_insert_remove<K, V>(m: FMap<K, V>, k: K, v: V) {}
+
+// Higher-order operations on sequences
+
+/// Applies `f` to every element of `s`.
+#[logic]
+#[open]
+#[variant(s.len())]
+#[ensures(result.len() == s.len())]
+#[ensures(forall<i: Int> 0 <= i && i < s.len() ==> result[i] == f.get(s[i]))]
+pub fn map<T, U>(s: Seq<T>, f: Mapping<T, U>) -> Seq<U> {
+    pearlite! {
+        if s.len() == 0 { Seq::EMPTY } else { Seq::singleton(f.get(s[0])).concat(map(s.tail(), f)) }
+    }
+}
+
+/// `map` distributes over concatenation.
+#[logic]
+#[open]
+#[variant(a.len())]
+#[ensures(map(a.concat(b), f) == map(a, f).concat(map(b, f)))]
+pub fn map_concat<T, U>(a: Seq<T>, b: Seq<T>, f: Mapping<T, U>) {
+    pearlite! {
+        if a.len() > 0 {
+            concat_tail(a, b);
+            map_concat(a.tail(), b, f);
+            concat_assoc(Seq::singleton(f.get(a[0])), map(a.tail(), f), map(b, f))
+        } else {
+            concat_empty(b);
+            concat_empty(map(b, f))
+        }
+    }
+}
+
+/// The elements of `s` which satisfy `p`, in the same order.
+#[logic]
+#[open]
+#[variant(s.len())]
+#[ensures(result.len() <= s.len())]
+#[ensures(forall<i: Int> 0 <= i && i < result.len() ==> p.get(result[i]))]
+pub fn filter<T>(s: Seq<T>, p: Mapping<T, bool>) -> Seq<T> {
+    pearlite! {
+        if s.len() == 0 {
+            Seq::EMPTY
+        } else if p.get(s[0]) {
+            Seq::singleton(s[0]).concat(filter(s.tail(), p))
+        } else {
+            filter(s.tail(), p)
+        }
+    }
+}
+
+/// The elements of `filter(s, p)` are the elements of `s` which satisfy `p`.
+#[logic]
+#[open]
+#[variant(s.len())]
+#[ensures(filter(s, p).contains(x) == (s.contains(x) && p.get(x)))]
+pub fn filter_contains<T>(s: Seq<T>, p: Mapping<T, bool>, x: T) {
+    pearlite! {
+        if s.len() > 0 {
+            contains_tail(s, x);
+            contains_concat(Seq::singleton(s[0]), filter(s.tail(), p), x);
+            filter_contains(s.tail(), p, x)
+        } else {
+            ()
+        }
+    }
+}
+
+/// `filter` distributes over concatenation.
+#[logic]
+#[open]
+#[variant(a.len())]
+#[ensures(filter(a.concat(b), p) == filter(a, p).concat(filter(b, p)))]
+pub fn filter_concat<T>(a: Seq<T>, b: Seq<T>, p: Mapping<T, bool>) {
+    pearlite! {
+        if a.len() > 0 {
+            concat_tail(a, b);
+            filter_concat(a.tail(), b, p);
+            concat_assoc(Seq::singleton(a[0]), filter(a.tail(), p), filter(b, p))
+        } else {
+            concat_empty(b);
+            concat_empty(filter(b, p))
+        }
+    }
+}
+
+/// Combines the elements of `s` from left to right with `f`, starting from `init`.
+#[logic]
+#[open]
+#[variant(s.len())]
+pub fn fold<T, A>(s: Seq<T>, init: A, f: Mapping<(A, T), A>) -> A {
+    pearlite! {
+        if s.len() == 0 { init } else { fold(s.tail(), f.get((init, s[0])), f) }
+    }
+}
+
+/// Folding a concatenation folds the second operand starting from the fold of the first one.
+#[logic]
+#[open]
+#[variant(a.len())]
+#[ensures(fold(a.concat(b), init, f) == fold(b, fold(a, init, f), f))]
+pub fn fold_concat<T, A>(a: Seq<T>, b: Seq<T>, init: A, f: Mapping<(A, T), A>) {
+    pearlite! {
+        if a.len() > 0 {
+            concat_tail(a, b);
+            fold_concat(a.tail(), b, f.get((init, a[0])), f)
+        } else {
+            concat_empty(b)
+        }
+    }
+}
+
+/// Folding a sequence with an element added at the end.
+#[logic]
+#[open]
+#[ensures(fold(s.push(x), init, f) == f.get((fold(s, init, f), x)))]
+pub fn fold_push<T, A>(s: Seq<T>, x: T, init: A, f: Mapping<(A, T), A>) {
+    pearlite! {
+        push_concat(s, x);
+        fold_concat(s, Seq::singleton(x), init, f)
+    }
+}
+
+/// The number of occurrences of `x` in `s`.
+#[logic]
+#[open]
+#[variant(s.len())]
+#[ensures(0 <= result && result <= s.len())]
+pub fn count_occ<T>(s: Seq<T>, x: T) -> Int {
+    pearlite! {
+        if s.len() == 0 {
+            0
+        } else {
+            (if s[0] == x { 1 } else { 0 }) + count_occ(s.tail(), x)
+        }
+    }
+}
+
+/// `count_occ` is additive with respect to concatenation.
+#[logic]
+#[open]
+#[variant(a.len())]
+#[ensures(count_occ(a.concat(b), x) == count_occ(a, x) + count_occ(b, x))]
+pub fn count_occ_concat<T>(a: Seq<T>, b: Seq<T>, x: T) {
+    pearlite! {
+        if a.len() > 0 {
+            concat_tail(a, b);
+            count_occ_concat(a.tail(), b, x)
+        } else {
+            concat_empty(b)
+        }
+    }
+}
+
+/// An element occurs in a sequence exactly when it is contained in it.
+#[logic]
+#[open]
+#[variant(s.len())]
+#[ensures((count_occ(s, x) > 0) == s.contains(x))]
+pub fn count_occ_contains<T>(s: Seq<T>, x: T) {
+    pearlite! {
+        if s.len() > 0 {
+            contains_tail(s, x);
+            count_occ_contains(s.tail(), x)
+        } else {
+            ()
+        }
+    }
+}
+
+/// The concatenation of the images of the elements of `s` by `f`.
+#[logic]
+#[open]
+#[variant(s.len())]
+pub fn flat_map<T, U>(s: Seq<T>, f: Mapping<T, Seq<U>>) -> Seq<U> {
+    pearlite! {
+        if s.len() == 0 { Seq::EMPTY } else { f.get(s[0]).concat(flat_map(s.tail(), f)) }
+    }
+}
+
+/// `flat_map` distributes over concatenation.
+#[logic]
+#[open]
+#[variant(a.len())]
+#[ensures(flat_map(a.concat(b), f) == flat_map(a, f).concat(flat_map(b, f)))]
+pub fn flat_map_concat<T, U>(a: Seq<T>, b: Seq<T>, f: Mapping<T, Seq<U>>) {
+    pearlite! {
+        if a.len() > 0 {
+            concat_tail(a, b);
+            flat_map_concat(a.tail(), b, f);
+            concat_assoc(f.get(a[0]), flat_map(a.tail(), f), flat_map(b, f))
+        } else {
+            concat_empty(b);
+            concat_empty(flat_map(b, f))
+        }
+    }
+}
+
+/// The index of the first occurrence of `x` in `s`, if any.
+#[logic]
+#[open]
+#[variant(s.len())]
+#[ensures(match result {
+    Some(i) => 0 <= i && i < s.len() && s[i] == x && forall<j: Int> 0 <= j && j < i ==> s[j] != x,
+    None => forall<j: Int> 0 <= j && j < s.len() ==> s[j] != x,
+})]
+pub fn index_of<T>(s: Seq<T>, x: T) -> Option<Int> {
+    pearlite! {
+        if s.len() == 0 {
+            None
+        } else if s[0] == x {
+            Some(0)
+        } else {
+            match index_of(s.tail(), x) {
+                Some(i) => Some(i + 1),
+                None => None,
+            }
+        }
+    }
+}
+
+/// All the elements of `s` satisfy `p`.
+#[predicate]
+#[open]
+pub fn all<T>(s: Seq<T>, p: Mapping<T, bool>) -> bool {
+    pearlite! { forall<i: Int> 0 <= i && i < s.len() ==> p.get(s[i]) }
+}
+
+/// Some element of `s` satisfies `p`.
+#[predicate]
+#[open]
+pub fn any<T>(s: Seq<T>, p: Mapping<T, bool>) -> bool {
+    pearlite! { exists<i: Int> 0 <= i && i < s.len() && p.get(s[i]) }
+}
+
+/// `all` and `any` distribute over concatenation.
+#[logic]
+#[open]
+#[ensures(all(a.concat(b), p) == (all(a, p) && all(b, p)))]
+#[ensures(any(a.concat(b), p) == (any(a, p) || any(b, p)))]
+pub fn all_any_concat<T>(a: Seq<T>, b: Seq<T>, p: Mapping<T, bool>) {}
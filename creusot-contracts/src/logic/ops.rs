@@ -61,6 +61,7 @@ impl<T, const N: usize> IndexLogic<Int> for [T; N] {
     #[logic]
     #[open]
     #[why3::attr = "inline:trivial"]
+    #[rustc_diagnostic_item = "array_index_logic"]
     fn index_logic(self, ix: Int) -> Self::Item {
         pearlite! { self@[ix] }
     }
@@ -214,6 +214,16 @@ impl OrdLogic for bool {
     ord_laws_impl! {}
 }
 
+impl OrdLogic for char {
+    #[open]
+    #[logic]
+    fn cmp_log(self, o: Self) -> Ordering {
+        pearlite! { self@.cmp_log(o@) }
+    }
+
+    ord_laws_impl! {}
+}
+
 impl<A: OrdLogic, B: OrdLogic> OrdLogic for (A, B) {
     #[logic]
     #[open]
@@ -10,6 +10,7 @@ impl<T> Seq<T> {
     #[cfg(creusot)]
     #[trusted]
     #[creusot::builtins = "seq.Seq.empty"]
+    #[rustc_diagnostic_item = "seq_empty"]
     pub const EMPTY: Self = { Seq(std::marker::PhantomData) };
 
     #[trusted]
@@ -81,6 +82,7 @@ impl<T> Seq<T> {
     #[logic]
     #[open(self)]
     #[creusot::builtins = "seq.Seq.snoc"]
+    #[rustc_diagnostic_item = "seq_push"]
     pub fn push(self, _: T) -> Self {
         absurd
     }
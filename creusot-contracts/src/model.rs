@@ -1,4 +1,4 @@
-use ::std::{rc::Rc, sync::Arc};
+use ::std::{alloc::Allocator, rc::Rc, sync::Arc};
 
 use crate::*;
 
@@ -33,7 +33,7 @@ impl<T: DeepModel> DeepModel for Rc<T> {
     }
 }
 
-impl<T> ShallowModel for Rc<T> {
+impl<T, A: Allocator> ShallowModel for Rc<T, A> {
     type ShallowModelTy = T;
     #[logic]
     #[open]
@@ -49,6 +49,7 @@ impl ShallowModel for str {
     #[logic]
     #[open]
     #[trusted]
+    #[creusot::builtins = "prelude.prelude.Str.id"]
     fn shallow_model(self) -> Self::ShallowModelTy {
         pearlite! { absurd }
     }
@@ -63,7 +64,7 @@ impl<T: DeepModel> DeepModel for Arc<T> {
     }
 }
 
-impl<T> ShallowModel for Arc<T> {
+impl<T, A: Allocator> ShallowModel for Arc<T, A> {
     type ShallowModelTy = T;
     #[logic]
     #[open]
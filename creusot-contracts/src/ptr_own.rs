@@ -0,0 +1,218 @@
+//! Permissions to access the memory pointed to by raw pointers
+//!
+//! Raw pointers cannot be dereferenced in verified code. Instead, the memory they point to is
+//! accessed through a [`PtrOwn`], a token standing for the ownership of this memory, in the style
+//! of the points-to assertions of separation logic.
+//!
+//! ```
+//! use creusot_contracts::ptr_own::PtrOwn;
+//!
+//! let (ptr, mut own) = PtrOwn::new(1);
+//! *PtrOwn::as_mut(ptr, &mut own) += 1;
+//! assert_eq!(*PtrOwn::as_ref(ptr, &own), 2);
+//! let b = PtrOwn::to_box(ptr, own);
+//! ```
+#[cfg(creusot)]
+use crate::ghost_ptr::GhostPtr;
+use crate::*;
+use ::std::{
+    alloc::{self, Layout},
+    marker::PhantomData,
+    ptr::NonNull,
+};
+
+/// The permission to access the memory at [`ptr`](Self::ptr), which holds [`val`](Self::val)
+///
+/// At most one [`PtrOwn`] has permission to each pointer.
+/// No [`PtrOwn`] has permission to a dangling pointer.
+#[trusted]
+pub struct PtrOwn<T>(pub(crate) PhantomData<T>);
+
+// The permission guarantees that the pointers are valid
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+impl<T> PtrOwn<T> {
+    /// The pointer this permission is for
+    #[trusted]
+    #[logic]
+    #[open(self)]
+    #[ensures(result != GhostPtr::<T>::null_logic())]
+    pub fn ptr(self) -> *const T {
+        absurd
+    }
+
+    /// The value stored at [`ptr`](Self::ptr), or `None` if the memory is not initialized
+    #[trusted]
+    #[logic]
+    #[open(self)]
+    pub fn val(self) -> Option<T> {
+        absurd
+    }
+
+    /// Moves `val` to a new allocation, and creates the permission to it
+    #[panics_if(crate::std::mem::size_of_logic::<T>() == 0)]
+    #[ensures(result.1.ptr() == result.0)]
+    #[ensures(result.1.val() == Some(val))]
+    pub fn new(val: T) -> (*const T, PtrOwn<T>) {
+        Self::from_box(Box::new(val))
+    }
+
+    /// Casts `val` into a raw pointer and creates the permission to it
+    ///
+    /// Zero-sized types are not supported, as their boxes do not own an allocation.
+    // Safety the box had full ownership of its allocation
+    #[trusted]
+    #[panics_if(crate::std::mem::size_of_logic::<T>() == 0)]
+    #[ensures(result.1.ptr() == result.0)]
+    #[ensures(result.1.val() == Some(*val))]
+    pub fn from_box(val: Box<T>) -> (*const T, PtrOwn<T>) {
+        assert!(core::mem::size_of::<T>() > 0, "PtrOwn doesn't support ZSTs");
+        (Box::into_raw(val), PtrOwn(PhantomData))
+    }
+
+    /// Transfers the ownership of `ptr` back into a `Box`
+    #[trusted]
+    #[requires(ptr == _own.ptr())]
+    #[requires(_own.val() != None)]
+    #[ensures(Some(*result) == _own.val())]
+    pub fn to_box(ptr: *const T, _own: PtrOwn<T>) -> Box<T> {
+        unsafe { Box::from_raw(ptr as *mut T) }
+    }
+
+    /// Allocates uninitialized memory for a `T`, with the layout used by `Box<T>`
+    ///
+    /// Zero-sized types are not supported.
+    #[trusted]
+    #[panics_if(crate::std::mem::size_of_logic::<T>() == 0)]
+    #[ensures(result.1.ptr() == result.0)]
+    #[ensures(result.1.val() == None)]
+    pub fn alloc() -> (*const T, PtrOwn<T>) {
+        let layout = Layout::new::<T>();
+        assert!(layout.size() > 0, "PtrOwn doesn't support ZSTs");
+        let ptr = unsafe { alloc::alloc(layout) };
+        if ptr.is_null() {
+            alloc::handle_alloc_error(layout)
+        }
+        (ptr as *const T, PtrOwn(PhantomData))
+    }
+
+    /// Frees the memory at `ptr`, without dropping the value it holds
+    #[trusted]
+    #[requires(ptr == _own.ptr())]
+    pub fn dealloc(ptr: *const T, _own: PtrOwn<T>) {
+        unsafe { alloc::dealloc(ptr as *mut u8, Layout::new::<T>()) }
+    }
+
+    /// Immutably borrows the value at `ptr`
+    // Safety `_own` cannot be used to mutate the value as long as the borrow lasts
+    #[trusted]
+    #[requires(ptr == _own.ptr())]
+    #[requires(_own.val() != None)]
+    #[ensures(Some(*result) == _own.val())]
+    pub fn as_ref(ptr: *const T, _own: &PtrOwn<T>) -> &T {
+        unsafe { &*ptr }
+    }
+
+    /// Mutably borrows the value at `ptr`
+    // Safety `_own` cannot be used as long as the borrow lasts
+    #[trusted]
+    #[requires(ptr == (*_own).ptr())]
+    #[requires((*_own).val() != None)]
+    #[ensures(Some(*result) == (*_own).val())]
+    #[ensures((^_own).ptr() == (*_own).ptr())]
+    #[ensures((^_own).val() == Some(^result))]
+    pub fn as_mut(ptr: *const T, _own: &mut PtrOwn<T>) -> &mut T {
+        unsafe { &mut *(ptr as *mut T) }
+    }
+
+    /// Copies the value at `ptr`
+    #[requires(ptr == own.ptr())]
+    #[requires(own.val() != None)]
+    #[ensures(Some(result) == own.val())]
+    pub fn read(ptr: *const T, own: &PtrOwn<T>) -> T
+    where
+        T: Copy,
+    {
+        *Self::as_ref(ptr, own)
+    }
+
+    /// Stores `val` at `ptr`, without dropping the previous value
+    #[trusted]
+    #[requires(ptr == (*_own).ptr())]
+    #[ensures((^_own).ptr() == (*_own).ptr())]
+    #[ensures((^_own).val() == Some(val))]
+    pub fn write(ptr: *const T, _own: &mut PtrOwn<T>, val: T) {
+        unsafe { (ptr as *mut T).write(val) }
+    }
+
+    /// Moves the value out of `ptr`, leaving the memory uninitialized
+    #[trusted]
+    #[requires(ptr == (*_own).ptr())]
+    #[requires((*_own).val() != None)]
+    #[ensures(Some(result) == (*_own).val())]
+    #[ensures((^_own).ptr() == (*_own).ptr())]
+    #[ensures((^_own).val() == None)]
+    pub fn take(ptr: *const T, _own: &mut PtrOwn<T>) -> T {
+        unsafe { ptr.read() }
+    }
+
+    /// Moves `ptr` by `count` elements, staying in the allocation `_own` is for
+    ///
+    /// The allocation holds a single `T`, so the pointers in it are [`ptr`](Self::ptr) and the
+    /// pointer one element past its end.
+    // Safety the permission guarantees that the allocation is live
+    #[trusted]
+    #[requires(ptr == _own.ptr() && (count@ == 0 || count@ == 1)
+        || ptr == _own.ptr().offset_logic(1) && (count@ == 0 || count@ == -1))]
+    #[ensures(result == ptr.offset_logic(count@))]
+    pub fn offset(ptr: *const T, _own: &PtrOwn<T>, count: isize) -> *const T {
+        unsafe { ptr.offset(count) }
+    }
+
+    /// Two permissions are for different pointers
+    // Safety we have full ownership of both permissions simultaneously
+    #[trusted]
+    #[ensures((*_own1).ptr() != _own2.ptr())]
+    #[ensures(*_own1 == ^_own1)]
+    pub fn disjoint_lemma(_own1: &mut PtrOwn<T>, _own2: &PtrOwn<T>) {}
+}
+
+impl<T: ?Sized> ShallowModel for NonNull<T> {
+    type ShallowModelTy = *const T;
+
+    /// The pointer wrapped by `self`
+    #[trusted]
+    #[logic]
+    #[open(self)]
+    #[ensures(result != GhostPtr::<T>::null_logic())]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        absurd
+    }
+}
+
+extern_spec! {
+    mod std {
+        mod ptr {
+            impl<T: ?Sized> NonNull<T> {
+                #[pure]
+                #[ensures(match result {
+                    None => ptr as *const T == GhostPtr::<T>::null_logic(),
+                    Some(p) => p@ == ptr as *const T,
+                })]
+                fn new(ptr: *mut T) -> Option<NonNull<T>>;
+
+                #[pure]
+                #[requires(ptr as *const T != GhostPtr::<T>::null_logic())]
+                #[ensures(result@ == ptr as *const T)]
+                unsafe fn new_unchecked(ptr: *mut T) -> NonNull<T>;
+
+                #[pure]
+                #[ensures(result as *const T == self@)]
+                fn as_ptr(self) -> *mut T;
+
+                #[pure]
+                #[ensures(result@ == self@ as *const U)]
+                fn cast<U>(self) -> NonNull<U>;
+            }
+        }
+    }
+}
@@ -2,19 +2,26 @@ pub use ::std::*;
 
 pub mod array;
 pub mod boxed;
+pub mod cell;
+pub mod char;
 pub mod clone;
 pub mod cmp;
+pub mod collections;
 pub mod default;
 pub mod deque;
 pub mod fmt;
+pub mod hash;
 pub mod iter;
 pub mod mem;
 pub mod num;
 pub mod ops;
 pub mod option;
+pub mod rc;
 pub mod result;
 pub mod slice;
 pub mod string;
+pub mod sync;
+pub mod task;
 pub mod time;
 mod tuples;
 pub mod vec;
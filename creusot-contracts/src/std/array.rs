@@ -7,9 +7,8 @@ impl<T, const N: usize> ShallowModel for [T; N] {
     #[trusted]
     #[open]
     #[creusot::builtins = "prelude.prelude.Slice.id"]
-    // TODO:
-    // #[ensures(result.len() == N@)]
-    // Warning: #[ensures] and #[trusted] are incompatible, so this might require
+    // The length of the model is `N`: this is part of the type invariant of arrays, see
+    // `array_len_inv`.
     fn shallow_model(self) -> Self::ShallowModelTy {
         pearlite! { absurd }
     }
@@ -21,11 +20,37 @@ impl<T: DeepModel, const N: usize> DeepModel for [T; N] {
     #[logic]
     #[trusted]
     #[open(self)]
-    // TODO
-    // #[ensures(result.len() == N@)]
     #[ensures(self.shallow_model().len() == result.len())]
     #[ensures(forall<i: _> 0 <= i && i < result.len() ==> result[i] == self[i].deep_model())]
     fn deep_model(self) -> Self::DeepModelTy {
         pearlite! { absurd }
     }
 }
+
+/// The length of an array is given by its type.
+///
+/// Arrays are translated to sequences, so this fact is part of the type invariant of arrays.
+#[predicate]
+#[open]
+#[rustc_diagnostic_item = "array_len_inv"]
+pub fn array_len_inv<T, const N: usize>(x: [T; N]) -> bool {
+    pearlite! { x@.len() == N@ }
+}
+
+/// The array whose elements are `s`, used to translate constant arrays.
+#[logic]
+#[trusted]
+#[open(self)]
+#[requires(s.len() == N@)]
+#[ensures(result@ == s)]
+#[rustc_diagnostic_item = "array_from_seq"]
+pub fn array_from_seq<T, const N: usize>(s: Seq<T>) -> [T; N] {
+    pearlite! { absurd }
+}
+
+#[logic]
+#[open]
+#[rustc_diagnostic_item = "array_len_logic"]
+pub fn array_len<T, const N: usize>(x: [T; N]) -> Int {
+    pearlite! { x@.len() }
+}
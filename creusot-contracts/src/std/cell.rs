@@ -0,0 +1,149 @@
+use crate::*;
+pub use ::std::cell::*;
+use ::std::{marker::PhantomData, ops::Deref};
+
+/// An invariant on the values stored in a [`PredCell`], a [`PredRefCell`] or a
+/// [`PredMutex`](crate::std::sync::PredMutex)
+pub trait Inv<T> {
+    #[predicate]
+    fn inv(x: T) -> bool;
+}
+
+/// A [`Cell`] whose content always satisfies the invariant `I`
+#[trusted]
+pub struct PredCell<T, I>(Cell<T>, PhantomData<I>);
+
+impl<T, I: Inv<T>> PredCell<T, I> {
+    #[trusted]
+    #[requires(I::inv(val))]
+    pub fn new(val: T) -> Self {
+        PredCell(Cell::new(val), PhantomData)
+    }
+
+    #[trusted]
+    #[ensures(I::inv(result))]
+    pub fn get(&self) -> T
+    where
+        T: Copy,
+    {
+        self.0.get()
+    }
+
+    #[trusted]
+    #[requires(I::inv(val))]
+    pub fn set(&self, val: T) {
+        self.0.set(val)
+    }
+
+    #[trusted]
+    #[requires(I::inv(val))]
+    #[ensures(I::inv(result))]
+    pub fn replace(&self, val: T) -> T {
+        self.0.replace(val)
+    }
+
+    #[trusted]
+    #[ensures(I::inv(result))]
+    pub fn into_inner(self) -> T {
+        self.0.into_inner()
+    }
+}
+
+/// A [`RefCell`] whose content always satisfies the invariant `I`
+#[trusted]
+pub struct PredRefCell<T, I>(RefCell<T>, PhantomData<I>);
+
+impl<T, I: Inv<T>> PredRefCell<T, I> {
+    #[trusted]
+    #[requires(I::inv(val))]
+    pub fn new(val: T) -> Self {
+        PredRefCell(RefCell::new(val), PhantomData)
+    }
+
+    /// Immutably borrows the content, panicking if it is mutably borrowed
+    #[trusted]
+    #[may_panic]
+    #[ensures(I::inv(result@))]
+    pub fn borrow(&self) -> PredRef<'_, T, I> {
+        PredRef(self.0.borrow(), PhantomData)
+    }
+
+    /// Mutably borrows the content, panicking if it is already borrowed
+    #[trusted]
+    #[may_panic]
+    #[ensures(I::inv(result@))]
+    pub fn borrow_mut(&self) -> PredRefMut<'_, T, I> {
+        PredRefMut(self.0.borrow_mut(), PhantomData)
+    }
+
+    #[trusted]
+    #[ensures(I::inv(result))]
+    pub fn into_inner(self) -> T {
+        self.0.into_inner()
+    }
+}
+
+/// An immutable borrow of the content of a [`PredRefCell`]
+#[trusted]
+pub struct PredRef<'a, T, I>(Ref<'a, T>, PhantomData<I>);
+
+impl<'a, T, I> ShallowModel for PredRef<'a, T, I> {
+    type ShallowModelTy = T;
+
+    /// The borrowed value
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        pearlite! { absurd }
+    }
+}
+
+impl<'a, T, I: Inv<T>> Deref for PredRef<'a, T, I> {
+    type Target = T;
+
+    #[trusted]
+    #[ensures(*result == self@)]
+    #[ensures(I::inv(*result))]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+/// A mutable borrow of the content of a [`PredRefCell`]
+///
+/// The content can only be replaced by values satisfying the invariant, using [`set`](Self::set).
+#[trusted]
+pub struct PredRefMut<'a, T, I>(RefMut<'a, T>, PhantomData<I>);
+
+impl<'a, T, I> ShallowModel for PredRefMut<'a, T, I> {
+    type ShallowModelTy = T;
+
+    /// The current value of the borrowed content
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        pearlite! { absurd }
+    }
+}
+
+impl<'a, T, I: Inv<T>> PredRefMut<'a, T, I> {
+    #[trusted]
+    #[requires(I::inv(val))]
+    #[ensures((^self)@ == val)]
+    pub fn set(&mut self, val: T) {
+        *self.0 = val
+    }
+}
+
+impl<'a, T, I: Inv<T>> Deref for PredRefMut<'a, T, I> {
+    type Target = T;
+
+    #[trusted]
+    #[ensures(*result == self@)]
+    #[ensures(I::inv(*result))]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
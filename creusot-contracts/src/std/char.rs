@@ -0,0 +1,81 @@
+use crate::*;
+pub use ::std::char::*;
+
+impl ShallowModel for char {
+    type ShallowModelTy = Int;
+
+    /// The code point of the character
+    #[logic]
+    #[open]
+    #[trusted]
+    #[creusot::builtins = "prelude.prelude.Char.code"]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        pearlite! { absurd }
+    }
+}
+
+impl DeepModel for char {
+    type DeepModelTy = Int;
+
+    #[logic]
+    #[open]
+    fn deep_model(self) -> Self::DeepModelTy {
+        pearlite! { self@ }
+    }
+}
+
+pub trait CharExt {
+    #[logic]
+    fn len_utf8_log(self) -> Int;
+
+    #[predicate]
+    fn is_whitespace_log(self) -> bool;
+}
+
+impl CharExt for char {
+    #[logic]
+    #[open]
+    fn len_utf8_log(self) -> Int {
+        pearlite! { Seq::singleton(self).to_utf8().len() }
+    }
+
+    // Only the ASCII whitespace characters are specified
+    #[predicate]
+    #[open(self)]
+    #[trusted]
+    #[ensures(self@ < 128 ==> result == (
+        self == ' ' || self == '\t' || self == '\n' || self == '\r' || self == '\u{b}' || self == '\u{c}'
+    ))]
+    fn is_whitespace_log(self) -> bool {
+        pearlite! { absurd }
+    }
+}
+
+extern_spec! {
+    impl char {
+        #[pure]
+        #[ensures(result@ == self.len_utf8_log())]
+        #[ensures(1 <= result@ && result@ <= 4)]
+        fn len_utf8(self) -> usize;
+
+        #[pure]
+        #[ensures(result == self.is_whitespace_log())]
+        fn is_whitespace(self) -> bool;
+
+        #[pure]
+        #[ensures(result == ('0' <= *self && *self <= '9'))]
+        fn is_ascii_digit(&self) -> bool;
+
+        #[pure]
+        #[ensures(result == ('a' <= *self && *self <= 'z' || 'A' <= *self && *self <= 'Z'))]
+        fn is_ascii_alphabetic(&self) -> bool;
+
+        #[pure]
+        #[requires(radix@ <= 36)]
+        #[ensures(radix@ <= 10 ==> match result {
+            Some(d) => '0' <= self && self@ < 48 + radix@ && d@ == self@ - 48,
+            None => !('0' <= self && self@ < 48 + radix@),
+        })]
+        fn to_digit(self, radix: u32) -> Option<u32>;
+    }
+}
@@ -0,0 +1,18 @@
+//! Models and specifications for the map and set collections of the standard library.
+//!
+//! Maps are modelled as [`FMap`](crate::logic::FMap)s and sets as [`FSet`](crate::logic::FSet)s
+//! indexed by the *deep model* of their keys. This is sound as long as the key type's `Eq`,
+//! `Hash` and `Ord` implementations agree with its deep model: two keys compare equal (and hash
+//! to the same value) exactly when their deep models are equal, and keys are ordered like their
+//! deep models. The first and last of these are the contracts of `PartialEq` and `Ord` in
+//! [`cmp`](crate::std::cmp), which every implementation must satisfy. The second cannot be
+//! proved, since hashing is not modelled: hashed collections require their keys to implement
+//! [`DeepModelHash`](crate::std::hash::DeepModelHash), whose implementations are `#[trusted]`.
+//! Lookups through a borrowed form `Q` of the key require `Q` to have the same deep model as
+//! `K`, matching the requirements of `Borrow`.
+pub use ::std::collections::*;
+
+pub mod btree_map;
+pub mod btree_set;
+pub mod hash_map;
+pub mod hash_set;
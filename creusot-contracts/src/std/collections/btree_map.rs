@@ -0,0 +1,215 @@
+use crate::{
+    invariant::Invariant,
+    logic::{FMap, OrdLogic},
+    std::alloc::Allocator,
+    *,
+};
+use ::std::borrow::Borrow;
+pub use ::std::collections::btree_map::*;
+
+impl<K: DeepModel, V, A: Allocator + Clone> ShallowModel for BTreeMap<K, V, A> {
+    type ShallowModelTy = FMap<K::DeepModelTy, V>;
+
+    #[logic]
+    #[trusted]
+    #[open(self)]
+    #[ensures(result.len() <= usize::MAX@)]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        pearlite! { absurd }
+    }
+}
+
+extern_spec! {
+    mod std {
+        mod collections {
+            impl<K, V> BTreeMap<K, V>
+                where K: DeepModel
+            {
+                #[pure]
+                #[ensures(result@.is_empty())]
+                fn new() -> Self;
+            }
+
+            impl<K, V, A: Allocator + Clone> BTreeMap<K, V, A>
+                where K: DeepModel
+            {
+                #[pure]
+                #[ensures(result@ == self@.len())]
+                fn len(&self) -> usize;
+
+                #[pure]
+                #[ensures(result == self@.is_empty())]
+                fn is_empty(&self) -> bool;
+
+                #[pure]
+                #[ensures((^self)@.is_empty())]
+                fn clear(&mut self);
+
+                #[pure]
+                #[ensures(result@ == self@)]
+                fn iter(&self) -> Iter<'_, K, V>;
+            }
+
+            impl<K, V, A: Allocator + Clone> BTreeMap<K, V, A>
+                where K: Ord + DeepModel, K::DeepModelTy: OrdLogic
+            {
+                #[terminates] // can OOM
+                #[ensures((^self)@ == self@.insert(key.deep_model(), value))]
+                #[ensures(match result {
+                    Some(old) => self@.contains(key.deep_model()) && self@.lookup(key.deep_model()) == old,
+                    None => !self@.contains(key.deep_model()),
+                })]
+                fn insert(&mut self, key: K, value: V) -> Option<V>;
+
+                #[pure]
+                #[ensures(match result {
+                    Some(v) => self@.contains(key.deep_model()) && self@.lookup(key.deep_model()) == *v,
+                    None => !self@.contains(key.deep_model()),
+                })]
+                fn get<'a, Q: ?Sized>(&'a self, key: &Q) -> Option<&'a V>
+                    where K: Borrow<Q>, Q: Ord + DeepModel<DeepModelTy = K::DeepModelTy>;
+
+                #[pure]
+                #[ensures(match result {
+                    Some(v) => self@.contains(key.deep_model()) && self@.lookup(key.deep_model()) == *v
+                        && (^self)@ == self@.insert(key.deep_model(), ^v),
+                    None => !self@.contains(key.deep_model()) && (^self)@ == self@,
+                })]
+                fn get_mut<'a, Q: ?Sized>(&'a mut self, key: &Q) -> Option<&'a mut V>
+                    where K: Borrow<Q>, Q: Ord + DeepModel<DeepModelTy = K::DeepModelTy>;
+
+                #[pure]
+                #[ensures(result == self@.contains(key.deep_model()))]
+                fn contains_key<Q: ?Sized>(&self, key: &Q) -> bool
+                    where K: Borrow<Q>, Q: Ord + DeepModel<DeepModelTy = K::DeepModelTy>;
+
+                #[pure]
+                #[ensures((^self)@ == self@.remove(key.deep_model()))]
+                #[ensures(match result {
+                    Some(v) => self@.contains(key.deep_model()) && self@.lookup(key.deep_model()) == v,
+                    None => !self@.contains(key.deep_model()),
+                })]
+                fn remove<Q: ?Sized>(&mut self, key: &Q) -> Option<V>
+                    where K: Borrow<Q>, Q: Ord + DeepModel<DeepModelTy = K::DeepModelTy>;
+
+                #[terminates] // can OOM
+                #[ensures(result@.0 == key.deep_model())]
+                #[ensures(*result@.1 == self@ && ^result@.1 == (^self)@)]
+                fn entry(&mut self, key: K) -> Entry<'_, K, V, A>;
+            }
+        }
+    }
+}
+
+/// An entry is modelled by the deep model of its key, and a borrow of the model of the map it
+/// was taken from. The final value of that borrow is only known once the entry is consumed by
+/// `or_insert`.
+impl<'a, K: DeepModel, V, A: Allocator + Clone> ShallowModel for Entry<'a, K, V, A> {
+    type ShallowModelTy = (K::DeepModelTy, &'a mut FMap<K::DeepModelTy, V>);
+
+    #[logic]
+    #[trusted]
+    #[open(self)]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        pearlite! { absurd }
+    }
+}
+
+extern_spec! {
+    mod std {
+        mod collections {
+            mod btree_map {
+                impl<'a, K, V, A: Allocator + Clone> Entry<'a, K, V, A>
+                    where K: Ord + DeepModel
+                {
+                    #[pure]
+                    #[ensures(result.deep_model() == self@.0)]
+                    fn key<'b>(&'b self) -> &'b K;
+
+                    #[terminates] // can OOM
+                    #[ensures(if (*self@.1).contains(self@.0) {
+                        *result == (*self@.1).lookup(self@.0)
+                    } else {
+                        *result == default
+                    })]
+                    #[ensures(^self@.1 == (*self@.1).insert(self@.0, ^result))]
+                    fn or_insert(self, default: V) -> &'a mut V;
+                }
+            }
+        }
+    }
+}
+
+impl<'a, K: DeepModel, V, A: Allocator + Clone> IntoIterator for &'a BTreeMap<K, V, A>
+where
+    K::DeepModelTy: OrdLogic,
+{
+    #[predicate]
+    #[open]
+    fn into_iter_pre(self) -> bool {
+        pearlite! { true }
+    }
+
+    #[predicate]
+    #[open]
+    fn into_iter_post(self, res: Self::IntoIter) -> bool {
+        pearlite! { self@ == res@ }
+    }
+}
+
+/// An iterator is modelled by the entries it has not yet produced.
+impl<'a, K: DeepModel, V> ShallowModel for Iter<'a, K, V> {
+    type ShallowModelTy = FMap<K::DeepModelTy, V>;
+
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        absurd
+    }
+}
+
+impl<'a, K, V> Invariant for Iter<'a, K, V> {}
+
+/// Entries are produced in increasing order of keys.
+impl<'a, K: DeepModel, V> Iterator for Iter<'a, K, V>
+where
+    K::DeepModelTy: OrdLogic,
+{
+    #[predicate(prophetic)]
+    #[open]
+    fn completed(&mut self) -> bool {
+        pearlite! { self.resolve() && self@.is_empty() }
+    }
+
+    #[predicate]
+    #[open]
+    fn produces(self, visited: Seq<Self::Item>, tl: Self) -> bool {
+        pearlite! {
+            tl@.subset(self@)
+            && visited.len() == self@.len() - tl@.len()
+            && (forall<i: Int> 0 <= i && i < visited.len() ==>
+                self@.contains(visited[i].0.deep_model())
+                && !tl@.contains(visited[i].0.deep_model())
+                && self@.lookup(visited[i].0.deep_model()) == *visited[i].1)
+            && (forall<i: Int, j: Int> 0 <= i && i < j && j < visited.len() ==>
+                visited[i].0.deep_model() < visited[j].0.deep_model())
+            && (forall<i: Int, k: K::DeepModelTy> 0 <= i && i < visited.len() && tl@.contains(k) ==>
+                visited[i].0.deep_model() < k)
+            && (forall<k: K::DeepModelTy> self@.contains(k) && !tl@.contains(k) ==>
+                exists<i: Int> 0 <= i && i < visited.len() && visited[i].0.deep_model() == k)
+        }
+    }
+
+    #[law]
+    #[open]
+    #[ensures(self.produces(Seq::EMPTY, self))]
+    fn produces_refl(self) {}
+
+    #[law]
+    #[open]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
@@ -0,0 +1,154 @@
+use crate::{
+    invariant::Invariant,
+    logic::{FSet, OrdLogic},
+    std::alloc::Allocator,
+    *,
+};
+use ::std::borrow::Borrow;
+pub use ::std::collections::btree_set::*;
+
+impl<T: DeepModel, A: Allocator + Clone> ShallowModel for BTreeSet<T, A> {
+    type ShallowModelTy = FSet<T::DeepModelTy>;
+
+    #[logic]
+    #[trusted]
+    #[open(self)]
+    #[ensures(result.len() <= usize::MAX@)]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        pearlite! { absurd }
+    }
+}
+
+extern_spec! {
+    mod std {
+        mod collections {
+            impl<T> BTreeSet<T>
+                where T: DeepModel
+            {
+                #[pure]
+                #[ensures(result@ == FSet::EMPTY)]
+                fn new() -> Self;
+            }
+
+            impl<T, A: Allocator + Clone> BTreeSet<T, A>
+                where T: DeepModel
+            {
+                #[pure]
+                #[ensures(result@ == self@.len())]
+                fn len(&self) -> usize;
+
+                #[pure]
+                #[ensures(result == self@.is_empty())]
+                fn is_empty(&self) -> bool;
+
+                #[pure]
+                #[ensures((^self)@ == FSet::EMPTY)]
+                fn clear(&mut self);
+
+                #[pure]
+                #[ensures(result@ == self@)]
+                fn iter(&self) -> Iter<'_, T>;
+            }
+
+            impl<T, A: Allocator + Clone> BTreeSet<T, A>
+                where T: Ord + DeepModel, T::DeepModelTy: OrdLogic
+            {
+                #[terminates] // can OOM
+                #[ensures((^self)@ == self@.insert(value.deep_model()))]
+                #[ensures(result == !self@.contains(value.deep_model()))]
+                fn insert(&mut self, value: T) -> bool;
+
+                #[pure]
+                #[ensures(result == self@.contains(value.deep_model()))]
+                fn contains<Q: ?Sized>(&self, value: &Q) -> bool
+                    where T: Borrow<Q>, Q: Ord + DeepModel<DeepModelTy = T::DeepModelTy>;
+
+                #[pure]
+                #[ensures(match result {
+                    Some(v) => self@.contains(value.deep_model()) && v.deep_model() == value.deep_model(),
+                    None => !self@.contains(value.deep_model()),
+                })]
+                fn get<'a, Q: ?Sized>(&'a self, value: &Q) -> Option<&'a T>
+                    where T: Borrow<Q>, Q: Ord + DeepModel<DeepModelTy = T::DeepModelTy>;
+
+                #[pure]
+                #[ensures((^self)@ == self@.remove(value.deep_model()))]
+                #[ensures(result == self@.contains(value.deep_model()))]
+                fn remove<Q: ?Sized>(&mut self, value: &Q) -> bool
+                    where T: Borrow<Q>, Q: Ord + DeepModel<DeepModelTy = T::DeepModelTy>;
+            }
+        }
+    }
+}
+
+impl<'a, T: DeepModel, A: Allocator + Clone> IntoIterator for &'a BTreeSet<T, A>
+where
+    T::DeepModelTy: OrdLogic,
+{
+    #[predicate]
+    #[open]
+    fn into_iter_pre(self) -> bool {
+        pearlite! { true }
+    }
+
+    #[predicate]
+    #[open]
+    fn into_iter_post(self, res: Self::IntoIter) -> bool {
+        pearlite! { self@ == res@ }
+    }
+}
+
+/// An iterator is modelled by the elements it has not yet produced.
+impl<'a, T: DeepModel> ShallowModel for Iter<'a, T> {
+    type ShallowModelTy = FSet<T::DeepModelTy>;
+
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        absurd
+    }
+}
+
+impl<'a, T> Invariant for Iter<'a, T> {}
+
+/// Elements are produced in increasing order.
+impl<'a, T: DeepModel> Iterator for Iter<'a, T>
+where
+    T::DeepModelTy: OrdLogic,
+{
+    #[predicate(prophetic)]
+    #[open]
+    fn completed(&mut self) -> bool {
+        pearlite! { self.resolve() && self@.is_empty() }
+    }
+
+    #[predicate]
+    #[open]
+    fn produces(self, visited: Seq<Self::Item>, tl: Self) -> bool {
+        pearlite! {
+            tl@.is_subset(self@)
+            && visited.len() == self@.len() - tl@.len()
+            && (forall<i: Int> 0 <= i && i < visited.len() ==>
+                self@.contains(visited[i].deep_model()) && !tl@.contains(visited[i].deep_model()))
+            && (forall<i: Int, j: Int> 0 <= i && i < j && j < visited.len() ==>
+                visited[i].deep_model() < visited[j].deep_model())
+            && (forall<i: Int, e: T::DeepModelTy> 0 <= i && i < visited.len() && tl@.contains(e) ==>
+                visited[i].deep_model() < e)
+            && (forall<e: T::DeepModelTy> self@.contains(e) && !tl@.contains(e) ==>
+                exists<i: Int> 0 <= i && i < visited.len() && visited[i].deep_model() == e)
+        }
+    }
+
+    #[law]
+    #[open]
+    #[ensures(self.produces(Seq::EMPTY, self))]
+    fn produces_refl(self) {}
+
+    #[law]
+    #[open]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
@@ -0,0 +1,205 @@
+use crate::{invariant::Invariant, logic::FMap, std::hash::DeepModelHash, *};
+pub use ::std::collections::hash_map::*;
+use ::std::{borrow::Borrow, hash::BuildHasher};
+
+impl<K: DeepModel, V, S> ShallowModel for HashMap<K, V, S> {
+    type ShallowModelTy = FMap<K::DeepModelTy, V>;
+
+    #[logic]
+    #[trusted]
+    #[open(self)]
+    #[ensures(result.len() <= usize::MAX@)]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        pearlite! { absurd }
+    }
+}
+
+extern_spec! {
+    mod std {
+        mod collections {
+            impl<K, V> HashMap<K, V>
+                where K: DeepModel
+            {
+                #[pure]
+                #[ensures(result@.is_empty())]
+                fn new() -> Self;
+
+                #[terminates] // can OOM
+                #[ensures(result@.is_empty())]
+                fn with_capacity(capacity: usize) -> Self;
+            }
+
+            impl<K, V, S> HashMap<K, V, S>
+                where K: DeepModel
+            {
+                #[pure]
+                #[ensures(result@ == self@.len())]
+                fn len(&self) -> usize;
+
+                #[pure]
+                #[ensures(result == self@.is_empty())]
+                fn is_empty(&self) -> bool;
+
+                #[pure]
+                #[ensures((^self)@.is_empty())]
+                fn clear(&mut self);
+
+                #[pure]
+                #[ensures(result@ == self@)]
+                fn iter(&self) -> Iter<'_, K, V>;
+            }
+
+            impl<K, V, S> HashMap<K, V, S>
+                where K: Eq + DeepModel + DeepModelHash, S: BuildHasher
+            {
+                #[terminates] // can OOM
+                #[ensures((^self)@ == self@.insert(k.deep_model(), v))]
+                #[ensures(match result {
+                    Some(old) => self@.contains(k.deep_model()) && self@.lookup(k.deep_model()) == old,
+                    None => !self@.contains(k.deep_model()),
+                })]
+                fn insert(&mut self, k: K, v: V) -> Option<V>;
+
+                #[pure]
+                #[ensures(match result {
+                    Some(v) => self@.contains(k.deep_model()) && self@.lookup(k.deep_model()) == *v,
+                    None => !self@.contains(k.deep_model()),
+                })]
+                fn get<'a, Q: ?Sized>(&'a self, k: &Q) -> Option<&'a V>
+                    where K: Borrow<Q>, Q: Eq + DeepModelHash + DeepModel<DeepModelTy = K::DeepModelTy>;
+
+                #[pure]
+                #[ensures(match result {
+                    Some(v) => self@.contains(k.deep_model()) && self@.lookup(k.deep_model()) == *v
+                        && (^self)@ == self@.insert(k.deep_model(), ^v),
+                    None => !self@.contains(k.deep_model()) && (^self)@ == self@,
+                })]
+                fn get_mut<'a, Q: ?Sized>(&'a mut self, k: &Q) -> Option<&'a mut V>
+                    where K: Borrow<Q>, Q: Eq + DeepModelHash + DeepModel<DeepModelTy = K::DeepModelTy>;
+
+                #[pure]
+                #[ensures(result == self@.contains(k.deep_model()))]
+                fn contains_key<Q: ?Sized>(&self, k: &Q) -> bool
+                    where K: Borrow<Q>, Q: Eq + DeepModelHash + DeepModel<DeepModelTy = K::DeepModelTy>;
+
+                #[pure]
+                #[ensures((^self)@ == self@.remove(k.deep_model()))]
+                #[ensures(match result {
+                    Some(v) => self@.contains(k.deep_model()) && self@.lookup(k.deep_model()) == v,
+                    None => !self@.contains(k.deep_model()),
+                })]
+                fn remove<Q: ?Sized>(&mut self, k: &Q) -> Option<V>
+                    where K: Borrow<Q>, Q: Eq + DeepModelHash + DeepModel<DeepModelTy = K::DeepModelTy>;
+
+                #[terminates] // can OOM
+                #[ensures(result@.0 == key.deep_model())]
+                #[ensures(*result@.1 == self@ && ^result@.1 == (^self)@)]
+                fn entry(&mut self, key: K) -> Entry<'_, K, V>;
+            }
+        }
+    }
+}
+
+/// An entry is modelled by the deep model of its key, and a borrow of the model of the map it
+/// was taken from. The final value of that borrow is only known once the entry is consumed by
+/// `or_insert`.
+impl<'a, K: DeepModel, V> ShallowModel for Entry<'a, K, V> {
+    type ShallowModelTy = (K::DeepModelTy, &'a mut FMap<K::DeepModelTy, V>);
+
+    #[logic]
+    #[trusted]
+    #[open(self)]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        pearlite! { absurd }
+    }
+}
+
+extern_spec! {
+    mod std {
+        mod collections {
+            mod hash_map {
+                impl<'a, K, V> Entry<'a, K, V>
+                    where K: DeepModel
+                {
+                    #[pure]
+                    #[ensures(result.deep_model() == self@.0)]
+                    fn key<'b>(&'b self) -> &'b K;
+
+                    #[terminates] // can OOM
+                    #[ensures(if (*self@.1).contains(self@.0) {
+                        *result == (*self@.1).lookup(self@.0)
+                    } else {
+                        *result == default
+                    })]
+                    #[ensures(^self@.1 == (*self@.1).insert(self@.0, ^result))]
+                    fn or_insert(self, default: V) -> &'a mut V;
+                }
+            }
+        }
+    }
+}
+
+impl<'a, K: DeepModel, V, S> IntoIterator for &'a HashMap<K, V, S> {
+    #[predicate]
+    #[open]
+    fn into_iter_pre(self) -> bool {
+        pearlite! { true }
+    }
+
+    #[predicate]
+    #[open]
+    fn into_iter_post(self, res: Self::IntoIter) -> bool {
+        pearlite! { self@ == res@ }
+    }
+}
+
+/// An iterator is modelled by the entries it has not yet produced.
+impl<'a, K: DeepModel, V> ShallowModel for Iter<'a, K, V> {
+    type ShallowModelTy = FMap<K::DeepModelTy, V>;
+
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        absurd
+    }
+}
+
+impl<'a, K, V> Invariant for Iter<'a, K, V> {}
+
+impl<'a, K: DeepModel, V> Iterator for Iter<'a, K, V> {
+    #[predicate(prophetic)]
+    #[open]
+    fn completed(&mut self) -> bool {
+        pearlite! { self.resolve() && self@.is_empty() }
+    }
+
+    #[predicate]
+    #[open]
+    fn produces(self, visited: Seq<Self::Item>, tl: Self) -> bool {
+        pearlite! {
+            tl@.subset(self@)
+            && visited.len() == self@.len() - tl@.len()
+            && (forall<i: Int> 0 <= i && i < visited.len() ==>
+                self@.contains(visited[i].0.deep_model())
+                && !tl@.contains(visited[i].0.deep_model())
+                && self@.lookup(visited[i].0.deep_model()) == *visited[i].1)
+            && (forall<i: Int, j: Int> 0 <= i && i < j && j < visited.len() ==>
+                visited[i].0.deep_model() != visited[j].0.deep_model())
+            && (forall<k: K::DeepModelTy> self@.contains(k) && !tl@.contains(k) ==>
+                exists<i: Int> 0 <= i && i < visited.len() && visited[i].0.deep_model() == k)
+        }
+    }
+
+    #[law]
+    #[open]
+    #[ensures(self.produces(Seq::EMPTY, self))]
+    fn produces_refl(self) {}
+
+    #[law]
+    #[open]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
@@ -0,0 +1,64 @@
+use crate::{std::alloc::Allocator, *};
+pub use ::std::hash::*;
+
+/// Types whose [`Hash`] implementation agrees with their deep model: values with equal deep
+/// models hash to the same value.
+///
+/// This is what makes it sound to model hashed collections by the deep model of their keys.
+/// Hashing is not modelled, so the property cannot be proved and implementations must be marked
+/// `#[trusted]`.
+#[trusted]
+pub trait DeepModelHash: Hash + DeepModel {}
+
+macro_rules! deep_model_hash {
+    ($($t:ty),*) => {
+        $(
+            #[trusted]
+            impl DeepModelHash for $t {}
+        )*
+    };
+}
+
+deep_model_hash!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, bool, char, ());
+
+#[trusted]
+impl<T: DeepModelHash + ?Sized> DeepModelHash for &T {}
+
+#[cfg(creusot)]
+#[trusted]
+impl<T: DeepModelHash + ?Sized, A: Allocator> DeepModelHash for Box<T, A> {}
+
+#[trusted]
+impl<T: DeepModelHash> DeepModelHash for Option<T> {}
+
+#[trusted]
+impl<T: DeepModelHash, E: DeepModelHash> DeepModelHash for Result<T, E> {}
+
+#[trusted]
+impl<T: DeepModelHash> DeepModelHash for [T] {}
+
+#[trusted]
+impl<T: DeepModelHash, const N: usize> DeepModelHash for [T; N] {}
+
+#[trusted]
+impl<T: DeepModelHash, A: Allocator> DeepModelHash for Vec<T, A> {}
+
+macro_rules! tuple_impls {
+    ($($name:ident)+) => {
+        #[trusted]
+        impl<$($name: DeepModelHash),+> DeepModelHash for ($($name,)+) {}
+    };
+}
+
+tuple_impls! { A }
+tuple_impls! { A B }
+tuple_impls! { A B C }
+tuple_impls! { A B C D }
+tuple_impls! { A B C D E }
+tuple_impls! { A B C D E F }
+tuple_impls! { A B C D E F G }
+tuple_impls! { A B C D E F G H }
+tuple_impls! { A B C D E F G H I }
+tuple_impls! { A B C D E F G H I J }
+tuple_impls! { A B C D E F G H I J K }
+tuple_impls! { A B C D E F G H I J K L }
@@ -1,26 +1,46 @@
 use crate::*;
 pub use ::std::iter::*;
 
+mod chain;
 mod cloned;
 mod copied;
 mod empty;
 mod enumerate;
+pub mod filter;
+pub mod filter_map;
+pub mod flat_map;
 mod fuse;
+pub mod map;
 mod map_inv;
 mod once;
+mod peekable;
 mod range;
 mod repeat;
+mod rev;
 mod skip;
+mod skip_while;
+mod step_by;
 mod take;
+mod take_while;
 mod zip;
 
+pub use chain::ChainExt;
 pub use cloned::ClonedExt;
 pub use copied::CopiedExt;
 pub use enumerate::EnumerateExt;
+pub use filter::FilterExt;
+pub use filter_map::FilterMapExt;
+pub use flat_map::FlatMapExt;
 pub use fuse::FusedIterator;
+pub use map::MapExt;
 pub use map_inv::MapInv;
+pub use peekable::PeekableExt;
+pub use rev::RevExt;
 pub use skip::SkipExt;
+pub use skip_while::SkipWhileExt;
+pub use step_by::StepByExt;
 pub use take::TakeExt;
+pub use take_while::TakeWhileExt;
 pub use zip::ZipExt;
 
 pub trait Iterator: ::std::iter::Iterator {
@@ -53,6 +73,22 @@ pub trait Iterator: ::std::iter::Iterator {
     }
 }
 
+pub trait DoubleEndedIterator: ::std::iter::DoubleEndedIterator + Iterator {
+    /// `self` produces `visited` from its back, in this order, and becomes `o`.
+    #[predicate(prophetic)]
+    fn produces_back(self, visited: Seq<Self::Item>, o: Self) -> bool;
+
+    #[law]
+    #[ensures(self.produces_back(Seq::EMPTY, self))]
+    fn produces_back_refl(self);
+
+    #[law]
+    #[requires(a.produces_back(ab, b))]
+    #[requires(b.produces_back(bc, c))]
+    #[ensures(a.produces_back(ab.concat(bc), c))]
+    fn produces_back_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self);
+}
+
 pub trait IntoIterator: ::std::iter::IntoIterator
 where
     Self::IntoIter: Iterator,
@@ -86,6 +122,45 @@ pub trait FromIterator<A>: ::std::iter::FromIterator<A> {
     fn from_iter_post(prod: Seq<A>, res: Self) -> bool;
 }
 
+pub trait Sum<A>: ::std::iter::Sum<A> {
+    #[predicate]
+    fn sum_post(prod: Seq<A>, res: Self) -> bool;
+}
+
+/// The sum of the models of the elements of `s`.
+#[logic]
+#[open]
+#[variant(s.len())]
+pub fn seq_sum<T: ShallowModel<ShallowModelTy = Int>>(s: Seq<T>) -> Int {
+    pearlite! {
+        if s.len() == 0 { 0 } else { s[0]@ + seq_sum(s.tail()) }
+    }
+}
+
+macro_rules! sum_int {
+    ($($t:ty)*) => {
+        $(
+            impl Sum<$t> for $t {
+                #[predicate]
+                #[open]
+                fn sum_post(prod: Seq<$t>, res: Self) -> bool {
+                    pearlite! { res@ == seq_sum(prod) }
+                }
+            }
+
+            impl<'a> Sum<&'a $t> for $t {
+                #[predicate]
+                #[open]
+                fn sum_post(prod: Seq<&'a $t>, res: Self) -> bool {
+                    pearlite! { res@ == seq_sum(prod) }
+                }
+            }
+        )*
+    };
+}
+
+sum_int!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
 extern_spec! {
     mod std {
         mod iter {
@@ -132,11 +207,178 @@ extern_spec! {
                 fn zip<U: IntoIterator>(self, other: U) -> Zip<Self, U::IntoIter>
                     where U::IntoIter: Iterator;
 
+                #[pure]
+                #[requires(forall<e: Self_::Item, i2: Self_> self.produces(Seq::singleton(e), i2) ==> f.precondition((e,)))]
+                #[requires(map::reinitialize::<Self_, B, F>())]
+                #[requires(map::preservation::<Self_, B, F>(self, f))]
+                #[ensures(result.iter() == self && result.func() == f)]
+                fn map<B, F>(self, f: F) -> Map<Self, F>
+                    where F: FnMut(Self::Item) -> B;
+
+                #[pure]
+                #[requires(filter::immutable(predicate) && filter::no_precondition(predicate) && filter::precise(predicate))]
+                #[ensures(result.iter() == self && result.func() == predicate)]
+                fn filter<P>(self, predicate: P) -> Filter<Self, P>
+                    where P: FnMut(&Self::Item) -> bool;
+
+                #[pure]
+                #[requires(filter::immutable(f) && filter::no_precondition(f) && filter_map::precise(f))]
+                #[ensures(result.iter() == self && result.func() == f)]
+                fn filter_map<B, F>(self, f: F) -> FilterMap<Self, F>
+                    where F: FnMut(Self::Item) -> Option<B>;
+
+                #[pure]
+                #[requires(other.into_iter_pre())]
+                #[ensures(result.a() == Some(self))]
+                #[ensures(exists<b: U::IntoIter> other.into_iter_post(b) && result.b() == Some(b))]
+                fn chain<U>(self, other: U) -> Chain<Self, U::IntoIter>
+                    where U: IntoIterator<Item = Self::Item>, U::IntoIter: Iterator;
+
+                #[pure]
+                #[ensures(result.iter() == self)]
+                fn rev(self) -> Rev<Self>
+                    where Self: Sized + DoubleEndedIterator;
+
+                #[pure]
+                #[requires(step@ != 0)]
+                #[ensures(result.iter() == self && result.step() == step@ && result.first_take())]
+                fn step_by(self, step: usize) -> StepBy<Self>;
+
+                #[pure]
+                #[ensures(result.iter() == self && result.peeked() == None)]
+                fn peekable(self) -> Peekable<Self>;
+
+                #[pure]
+                #[requires(filter::immutable(f) && filter::no_precondition(f))]
+                #[requires(forall<u: U> u.into_iter_pre())]
+                #[ensures(result.iter() == self && result.func() == f)]
+                #[ensures(result.frontiter() == None && result.backiter() == None)]
+                fn flat_map<U, F>(self, f: F) -> FlatMap<Self, U, F>
+                    where U: IntoIterator, U::IntoIter: Iterator, F: FnMut(Self::Item) -> U;
+
+                #[pure]
+                #[requires(filter::immutable(predicate) && filter::no_precondition(predicate) && filter::precise(predicate))]
+                #[ensures(result.iter() == self && result.func() == predicate && !result.flag())]
+                fn take_while<P>(self, predicate: P) -> TakeWhile<Self, P>
+                    where P: FnMut(&Self::Item) -> bool;
+
+                #[pure]
+                #[requires(filter::immutable(predicate) && filter::no_precondition(predicate) && filter::precise(predicate))]
+                #[ensures(result.iter() == self && result.func() == predicate && !result.flag())]
+                fn skip_while<P>(self, predicate: P) -> SkipWhile<Self, P>
+                    where P: FnMut(&Self::Item) -> bool;
+
                 // TODO: Investigate why Self_ needed
                 #[ensures(exists<done : &mut Self_, prod: Seq<_>> (^done).resolve() && done.completed() &&
                     self.produces(prod, *done) && B::from_iter_post(prod, result))]
                 fn collect<B>(self) -> B
                     where B: FromIterator<Self::Item>;
+
+                #[requires(forall<g: F, acc: B, e: Self_::Item> f.unnest(g) ==> g.precondition((acc, e)))]
+                #[ensures(exists<done: &mut Self_, prod: Seq<_>, fs: Seq<&mut F>, accs: Seq<B>>
+                    (^done).resolve() && done.completed() && self.produces(prod, *done) &&
+                    fs.len() == prod.len() && accs.len() == prod.len() + 1 &&
+                    accs[0] == init && accs[prod.len()] == result &&
+                    (forall<i: Int> 1 <= i && i < fs.len() ==> ^fs[i - 1] == *fs[i]) &&
+                    (if prod.len() == 0 { f.resolve() } else { *fs[0] == f && (^fs[prod.len() - 1]).resolve() }) &&
+                    forall<i: Int> 0 <= i && i < prod.len() ==> fs[i].postcondition_mut((accs[i], prod[i]), accs[i + 1]))]
+                fn fold<B, F>(self, init: B, f: F) -> B
+                    where F: FnMut(B, Self::Item) -> B;
+
+                #[ensures(exists<done: &mut Self_, prod: Seq<_>> (^done).resolve() && done.completed() &&
+                    self.produces(prod, *done) && S::sum_post(prod, result))]
+                fn sum<S>(self) -> S
+                    where S: Sum<Self::Item>;
+
+                #[requires(forall<g: F, e: Self_::Item> f.unnest(g) ==> g.precondition((e,)))]
+                #[ensures(exists<prod: Seq<Self_::Item>, fs: Seq<&mut F>>
+                    fs.len() == prod.len() &&
+                    (forall<i: Int> 1 <= i && i < fs.len() ==> ^fs[i - 1] == *fs[i]) &&
+                    (if prod.len() == 0 { f.resolve() } else { *fs[0] == f && (^fs[prod.len() - 1]).resolve() }) &&
+                    (forall<i: Int> 0 <= i && i < prod.len() ==>
+                        fs[i].postcondition_mut((prod[i],), result && i == prod.len() - 1)) &&
+                    if result { prod.len() > 0 && (*self).produces(prod, ^self) }
+                    else { exists<done: &mut Self_> (*self).produces(prod, *done) && done.completed() && ^done == ^self })]
+                fn any<F>(&mut self, f: F) -> bool
+                    where F: FnMut(Self::Item) -> bool;
+
+                #[requires(forall<g: F, e: Self_::Item> f.unnest(g) ==> g.precondition((e,)))]
+                #[ensures(exists<prod: Seq<Self_::Item>, fs: Seq<&mut F>>
+                    fs.len() == prod.len() &&
+                    (forall<i: Int> 1 <= i && i < fs.len() ==> ^fs[i - 1] == *fs[i]) &&
+                    (if prod.len() == 0 { f.resolve() } else { *fs[0] == f && (^fs[prod.len() - 1]).resolve() }) &&
+                    (forall<i: Int> 0 <= i && i < prod.len() ==>
+                        fs[i].postcondition_mut((prod[i],), result || i != prod.len() - 1)) &&
+                    if result { exists<done: &mut Self_> (*self).produces(prod, *done) && done.completed() && ^done == ^self }
+                    else { prod.len() > 0 && (*self).produces(prod, ^self) })]
+                fn all<F>(&mut self, f: F) -> bool
+                    where F: FnMut(Self::Item) -> bool;
+
+                #[requires(forall<g: P, e: Self_::Item> predicate.unnest(g) ==> g.precondition((e,)))]
+                #[ensures(exists<prod: Seq<Self_::Item>, fs: Seq<&mut P>>
+                    fs.len() == prod.len() &&
+                    (forall<i: Int> 1 <= i && i < fs.len() ==> ^fs[i - 1] == *fs[i]) &&
+                    (if prod.len() == 0 { predicate.resolve() } else { *fs[0] == predicate && (^fs[prod.len() - 1]).resolve() }) &&
+                    (forall<i: Int> 0 <= i && i < prod.len() ==>
+                        fs[i].postcondition_mut((prod[i],), result != None && i == prod.len() - 1)) &&
+                    match result {
+                        Some(n) => n@ == prod.len() - 1 && (*self).produces(prod, ^self),
+                        None => exists<done: &mut Self_> (*self).produces(prod, *done) && done.completed() && ^done == ^self,
+                    })]
+                fn position<P>(&mut self, predicate: P) -> Option<usize>
+                    where P: FnMut(Self::Item) -> bool;
+
+                #[requires(forall<g: P, e: Self_::Item> predicate.unnest(g) ==> g.precondition((&e,)))]
+                #[ensures(exists<prod: Seq<Self_::Item>, fs: Seq<&mut P>>
+                    fs.len() == prod.len() &&
+                    (forall<i: Int> 1 <= i && i < fs.len() ==> ^fs[i - 1] == *fs[i]) &&
+                    (if prod.len() == 0 { predicate.resolve() } else { *fs[0] == predicate && (^fs[prod.len() - 1]).resolve() }) &&
+                    (forall<i: Int> 0 <= i && i < prod.len() ==>
+                        fs[i].postcondition_mut((&prod[i],), result != None && i == prod.len() - 1)) &&
+                    match result {
+                        Some(x) => prod.len() > 0 && x == prod[prod.len() - 1] && (*self).produces(prod, ^self) &&
+                            forall<i: Int> 0 <= i && i < prod.len() - 1 ==> prod[i].resolve(),
+                        None => (exists<done: &mut Self_> (*self).produces(prod, *done) && done.completed() && ^done == ^self) &&
+                            forall<i: Int> 0 <= i && i < prod.len() ==> prod[i].resolve(),
+                    })]
+                fn find<P>(&mut self, predicate: P) -> Option<Self::Item>
+                    where P: FnMut(&Self::Item) -> bool;
+
+                #[requires(forall<s: Seq<Self_::Item>, i: Self_> self.produces(s, i) ==> s.len() <= usize::MAX@)]
+                #[ensures(exists<done: &mut Self_, prod: Seq<_>> (^done).resolve() && done.completed() &&
+                    self.produces(prod, *done) && prod.len() == result@ &&
+                    forall<i: Int> 0 <= i && i < prod.len() ==> prod[i].resolve())]
+                fn count(self) -> usize;
+
+                #[ensures(exists<done: &mut Self_, prod: Seq<_>> (^done).resolve() && done.completed() &&
+                    self.produces(prod, *done) &&
+                    match result {
+                        None => prod.len() == 0,
+                        Some(m) => prod.contains(m) &&
+                            forall<i: Int> 0 <= i && i < prod.len() ==> prod[i].deep_model() <= m.deep_model(),
+                    })]
+                fn max(self) -> Option<Self::Item>
+                    where Self::Item: Ord + DeepModel, <Self::Item as DeepModel>::DeepModelTy: OrdLogic;
+
+                #[ensures(exists<done: &mut Self_, prod: Seq<_>> (^done).resolve() && done.completed() &&
+                    self.produces(prod, *done) &&
+                    match result {
+                        None => prod.len() == 0,
+                        Some(m) => prod.contains(m) &&
+                            forall<i: Int> 0 <= i && i < prod.len() ==> m.deep_model() <= prod[i].deep_model(),
+                    })]
+                fn min(self) -> Option<Self::Item>
+                    where Self::Item: Ord + DeepModel, <Self::Item as DeepModel>::DeepModelTy: OrdLogic;
+            }
+
+            trait DoubleEndedIterator
+                where Self: DoubleEndedIterator {
+
+                #[ensures(match result {
+                    None => self.completed(),
+                    Some(v) => (*self).produces_back(Seq::singleton(v), ^self)
+                })]
+                fn next_back(&mut self) -> Option<Self::Item>;
             }
 
             trait IntoIterator
@@ -160,6 +402,20 @@ extern_spec! {
                     where T: IntoIterator<Item = A>, T::IntoIter: Iterator;
             }
 
+            impl<I: Iterator> Peekable<I> {
+                #[ensures((*self).peeked() != None ==> ^self == *self)]
+                #[ensures((*self).peeked() == None ==> match (^self).peeked() {
+                    None => false,
+                    Some(None) => exists<inner: &mut I> *inner == (*self).iter() && ^inner == (^self).iter() && inner.completed(),
+                    Some(Some(v)) => (*self).iter().produces(Seq::singleton(v), (^self).iter()),
+                })]
+                #[ensures(match result {
+                    None => (^self).peeked() == Some(None),
+                    Some(v) => (^self).peeked() == Some(Some(*v)),
+                })]
+                fn peek(&mut self) -> Option<&I::Item>;
+            }
+
             #[pure]
             fn empty<T>() -> Empty<T>;
 
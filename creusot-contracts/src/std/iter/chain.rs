@@ -0,0 +1,98 @@
+use crate::{std::iter::Chain, *};
+
+pub trait ChainExt<A, B> {
+    /// The first iterator, which is dropped once it is exhausted.
+    #[logic]
+    fn a(self) -> Option<A>;
+
+    #[logic]
+    fn b(self) -> Option<B>;
+}
+
+impl<A, B> ChainExt<A, B> for Chain<A, B> {
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn a(self) -> Option<A> {
+        pearlite! { absurd }
+    }
+
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn b(self) -> Option<B> {
+        pearlite! { absurd }
+    }
+}
+
+#[trusted]
+impl<A, B> Resolve for Chain<A, B> {
+    #[open]
+    #[predicate(prophetic)]
+    fn resolve(self) -> bool {
+        pearlite! {
+            self.a().resolve() && self.b().resolve()
+        }
+    }
+}
+
+impl<A: Iterator, B: Iterator<Item = A::Item>> Iterator for Chain<A, B> {
+    #[open]
+    #[predicate(prophetic)]
+    fn completed(&mut self) -> bool {
+        pearlite! {
+            (^self).a() == None
+            && match (*self).a() {
+                None => true,
+                Some(a) => exists<done: &mut A> *done == a && done.completed() && (^done).resolve(),
+            }
+            && match (*self).b() {
+                None => (^self).b() == None,
+                Some(b) => exists<done: &mut B> *done == b && Some(^done) == (^self).b() && done.completed(),
+            }
+        }
+    }
+
+    #[open]
+    #[predicate(prophetic)]
+    fn produces(self, visited: Seq<Self::Item>, o: Self) -> bool {
+        pearlite! {
+            match self.a() {
+                None => o.a() == None && produces_opt(self.b(), visited, o.b()),
+                Some(a) => match o.a() {
+                    Some(oa) => a.produces(visited, oa) && self.b() == o.b(),
+                    None => exists<s1: Seq<A::Item>, s2: Seq<A::Item>, done: &mut A>
+                        visited == s1.concat(s2) && a.produces(s1, *done) && done.completed() && (^done).resolve()
+                        && produces_opt(self.b(), s2, o.b()),
+                },
+            }
+        }
+    }
+
+    #[law]
+    #[open(self)]
+    #[ensures(self.produces(Seq::EMPTY, self))]
+    fn produces_refl(self) {}
+
+    #[law]
+    #[open(self)]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
+
+/// `produces` for an iterator which may be absent, in which case it produces nothing.
+#[open]
+#[predicate(prophetic)]
+pub fn produces_opt<I: Iterator>(i: Option<I>, visited: Seq<I::Item>, o: Option<I>) -> bool {
+    pearlite! {
+        match i {
+            None => visited == Seq::EMPTY && o == None,
+            Some(i) => match o {
+                None => false,
+                Some(o) => i.produces(visited, o),
+            },
+        }
+    }
+}
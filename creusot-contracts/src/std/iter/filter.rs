@@ -0,0 +1,118 @@
+use crate::{invariant::Invariant, logic::Mapping, std::iter::Filter, *};
+
+pub trait FilterExt<I, F> {
+    #[logic]
+    fn iter(self) -> I;
+
+    #[logic]
+    fn func(self) -> F;
+}
+
+impl<I, F> FilterExt<I, F> for Filter<I, F> {
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn iter(self) -> I {
+        pearlite! { absurd }
+    }
+
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn func(self) -> F {
+        pearlite! { absurd }
+    }
+}
+
+#[trusted]
+impl<I, F> Resolve for Filter<I, F> {
+    #[open]
+    #[predicate(prophetic)]
+    fn resolve(self) -> bool {
+        pearlite! {
+            self.iter().resolve() && self.func().resolve()
+        }
+    }
+}
+
+impl<I: Iterator, F: FnMut(&I::Item) -> bool> Invariant for Filter<I, F> {
+    // The closure is restricted to keep the specification of `Filter` simple.
+    #[open(self)]
+    #[predicate(prophetic)]
+    fn invariant(self) -> bool {
+        pearlite! {
+            immutable(self.func()) && no_precondition(self.func()) && precise(self.func())
+        }
+    }
+}
+
+impl<I: Iterator, F: FnMut(&I::Item) -> bool> Iterator for Filter<I, F> {
+    #[open]
+    #[predicate(prophetic)]
+    fn completed(&mut self) -> bool {
+        pearlite! {
+            (exists<s: Seq<I::Item>, e: &mut I>
+                self.iter().produces(s, *e) && e.completed() && ^e == (^self).iter() &&
+                forall<i: Int> 0 <= i && i < s.len() ==> returns(self.func(), &s[i], false) && s[i].resolve())
+            && (*self).func() == (^self).func()
+        }
+    }
+
+    #[open]
+    #[predicate(prophetic)]
+    fn produces(self, visited: Seq<Self::Item>, succ: Self) -> bool {
+        pearlite! {
+            self.func() == succ.func()
+            && exists<s: Seq<I::Item>, f: Mapping<Int, Int>> self.iter().produces(s, succ.iter())
+                && (forall<i: Int, j: Int> 0 <= i && i < j && j < visited.len() ==> f.get(i) < f.get(j))
+                && (forall<i: Int> 0 <= i && i < visited.len() ==>
+                    0 <= f.get(i) && f.get(i) < s.len() && visited[i] == s[f.get(i)] && returns(self.func(), &visited[i], true))
+                && forall<i: Int> 0 <= i && i < s.len() ==>
+                    (exists<j: Int> 0 <= j && j < visited.len() && f.get(j) == i)
+                    || returns(self.func(), &s[i], false) && s[i].resolve()
+        }
+    }
+
+    #[law]
+    #[open(self)]
+    #[ensures(self.produces(Seq::EMPTY, self))]
+    fn produces_refl(self) {}
+
+    #[law]
+    #[open(self)]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
+
+/// Asserts that the captures of `f` are used immutably: calling `f` never changes its state.
+#[open]
+#[predicate(prophetic)]
+pub fn immutable<A, R, F: FnMut(A) -> R>(f: F) -> bool {
+    pearlite! { forall<g: F> f.unnest(g) ==> f == g }
+}
+
+/// Asserts that `f` has no precondition: it can be called on any input.
+#[open]
+#[predicate]
+pub fn no_precondition<A, R, F: FnMut(A) -> R>(f: F) -> bool {
+    pearlite! { forall<a: A> f.precondition((a,)) }
+}
+
+/// Asserts that the postcondition of `f` is precise: `f` never both accepts and rejects the same
+/// input.
+#[open]
+#[predicate(prophetic)]
+pub fn precise<A, F: FnMut(A) -> bool>(f: F) -> bool {
+    pearlite! {
+        forall<g: &mut F, a: A> *g == f ==> !(g.postcondition_mut((a,), true) && g.postcondition_mut((a,), false))
+    }
+}
+
+/// `f` may return `res` when called on `a`.
+#[open]
+#[predicate(prophetic)]
+pub fn returns<A, R, F: FnMut(A) -> R>(f: F, a: A, res: R) -> bool {
+    pearlite! { exists<g: &mut F> *g == f && g.postcondition_mut((a,), res) }
+}
@@ -0,0 +1,98 @@
+use crate::{invariant::Invariant, logic::Mapping, std::iter::FilterMap, *};
+
+pub trait FilterMapExt<I, F> {
+    #[logic]
+    fn iter(self) -> I;
+
+    #[logic]
+    fn func(self) -> F;
+}
+
+impl<I, F> FilterMapExt<I, F> for FilterMap<I, F> {
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn iter(self) -> I {
+        pearlite! { absurd }
+    }
+
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn func(self) -> F {
+        pearlite! { absurd }
+    }
+}
+
+#[trusted]
+impl<I, F> Resolve for FilterMap<I, F> {
+    #[open]
+    #[predicate(prophetic)]
+    fn resolve(self) -> bool {
+        pearlite! {
+            self.iter().resolve() && self.func().resolve()
+        }
+    }
+}
+
+impl<I: Iterator, B, F: FnMut(I::Item) -> Option<B>> Invariant for FilterMap<I, F> {
+    // The closure is restricted to keep the specification of `FilterMap` simple.
+    #[open(self)]
+    #[predicate(prophetic)]
+    fn invariant(self) -> bool {
+        pearlite! {
+            super::filter::immutable(self.func()) && super::filter::no_precondition(self.func()) && precise(self.func())
+        }
+    }
+}
+
+impl<I: Iterator, B, F: FnMut(I::Item) -> Option<B>> Iterator for FilterMap<I, F> {
+    #[open]
+    #[predicate(prophetic)]
+    fn completed(&mut self) -> bool {
+        pearlite! {
+            (exists<s: Seq<I::Item>, e: &mut I>
+                self.iter().produces(s, *e) && e.completed() && ^e == (^self).iter() &&
+                forall<i: Int> 0 <= i && i < s.len() ==> super::filter::returns(self.func(), s[i], None))
+            && (*self).func() == (^self).func()
+        }
+    }
+
+    #[open]
+    #[predicate(prophetic)]
+    fn produces(self, visited: Seq<Self::Item>, succ: Self) -> bool {
+        pearlite! {
+            self.func() == succ.func()
+            && exists<s: Seq<I::Item>, f: Mapping<Int, Int>> self.iter().produces(s, succ.iter())
+                && (forall<i: Int, j: Int> 0 <= i && i < j && j < visited.len() ==> f.get(i) < f.get(j))
+                && (forall<i: Int> 0 <= i && i < visited.len() ==>
+                    0 <= f.get(i) && f.get(i) < s.len() && super::filter::returns(self.func(), s[f.get(i)], Some(visited[i])))
+                && forall<i: Int> 0 <= i && i < s.len() ==>
+                    (exists<j: Int> 0 <= j && j < visited.len() && f.get(j) == i)
+                    || super::filter::returns(self.func(), s[i], None)
+        }
+    }
+
+    #[law]
+    #[open(self)]
+    #[ensures(self.produces(Seq::EMPTY, self))]
+    fn produces_refl(self) {}
+
+    #[law]
+    #[open(self)]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
+
+/// Asserts that the postcondition of `f` is precise: `f` never both keeps and discards the same
+/// input.
+#[open]
+#[predicate(prophetic)]
+pub fn precise<A, B, F: FnMut(A) -> Option<B>>(f: F) -> bool {
+    pearlite! {
+        forall<g: &mut F, a: A, b: B> *g == f ==>
+            !(g.postcondition_mut((a,), Some(b)) && g.postcondition_mut((a,), None))
+    }
+}
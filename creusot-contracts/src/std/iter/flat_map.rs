@@ -0,0 +1,154 @@
+use crate::{invariant::Invariant, std::iter::FlatMap, *};
+
+pub trait FlatMapExt<I, U: IntoIterator, F>
+where
+    U::IntoIter: Iterator,
+{
+    #[logic]
+    fn iter(self) -> I;
+
+    #[logic]
+    fn func(self) -> F;
+
+    /// The inner iterator currently being consumed by `next`.
+    #[logic]
+    fn frontiter(self) -> Option<U::IntoIter>;
+
+    /// The inner iterator currently being consumed by `next_back`.
+    #[logic]
+    fn backiter(self) -> Option<U::IntoIter>;
+}
+
+impl<I, U: IntoIterator, F> FlatMapExt<I, U, F> for FlatMap<I, U, F>
+where
+    U::IntoIter: Iterator,
+{
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn iter(self) -> I {
+        pearlite! { absurd }
+    }
+
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn func(self) -> F {
+        pearlite! { absurd }
+    }
+
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn frontiter(self) -> Option<U::IntoIter> {
+        pearlite! { absurd }
+    }
+
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn backiter(self) -> Option<U::IntoIter> {
+        pearlite! { absurd }
+    }
+}
+
+impl<I: Iterator, U: IntoIterator, F: FnMut(I::Item) -> U> Invariant for FlatMap<I, U, F>
+where
+    U::IntoIter: Iterator,
+{
+    // The closure is restricted to keep the specification of `FlatMap` simple. Only forward
+    // iteration is specified, so the back iterator is never used.
+    #[open(self)]
+    #[predicate(prophetic)]
+    fn invariant(self) -> bool {
+        pearlite! {
+            super::filter::immutable(self.func()) && super::filter::no_precondition(self.func())
+            && (forall<u: U> u.into_iter_pre())
+            && self.backiter() == None
+        }
+    }
+}
+
+impl<I: Iterator, U: IntoIterator, F: FnMut(I::Item) -> U> Iterator for FlatMap<I, U, F>
+where
+    U::IntoIter: Iterator,
+{
+    #[open]
+    #[predicate(prophetic)]
+    fn completed(&mut self) -> bool {
+        pearlite! {
+            (*self).func() == (^self).func() && (^self).frontiter() == None
+            && (^self).backiter() == (*self).backiter()
+            && exists<s: Seq<I::Item>, its: Seq<U::IntoIter>, e: &mut I>
+                self.iter().produces(s, *e) && e.completed() && ^e == (^self).iter()
+                && inner_iters(self.func(), self.frontiter(), s, its)
+                && forall<k: Int> 0 <= k && k < its.len() ==>
+                    exists<done: &mut U::IntoIter> *done == its[k] && done.completed() && (^done).resolve()
+        }
+    }
+
+    #[open]
+    #[predicate(prophetic)]
+    fn produces(self, visited: Seq<Self::Item>, o: Self) -> bool {
+        pearlite! {
+            visited == Seq::EMPTY && self == o ||
+            visited.len() > 0 && self.func() == o.func() && self.backiter() == o.backiter()
+            && exists<s: Seq<I::Item>, its: Seq<U::IntoIter>, parts: Seq<Seq<U::Item>>, last: U::IntoIter>
+                self.iter().produces(s, o.iter())
+                && inner_iters(self.func(), self.frontiter(), s, its)
+                && its.len() > 0 && parts.len() == its.len() && o.frontiter() == Some(last)
+                && (forall<k: Int> 0 <= k && k < its.len() - 1 ==>
+                    exists<done: &mut U::IntoIter>
+                        its[k].produces(parts[k], *done) && done.completed() && (^done).resolve())
+                && its[its.len() - 1].produces(parts[its.len() - 1], last)
+                && visited == flatten(parts)
+        }
+    }
+
+    #[law]
+    #[open(self)]
+    #[ensures(self.produces(Seq::EMPTY, self))]
+    fn produces_refl(self) {}
+
+    #[law]
+    #[open(self)]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
+
+/// `its` are the inner iterators used by a `FlatMap` with the front iterator `front` and the
+/// closure `func`, when the outer iterator produces `s`.
+#[open]
+#[predicate(prophetic)]
+pub fn inner_iters<A, U: IntoIterator, F: FnMut(A) -> U>(
+    func: F,
+    front: Option<U::IntoIter>,
+    s: Seq<A>,
+    its: Seq<U::IntoIter>,
+) -> bool
+where
+    U::IntoIter: Iterator,
+{
+    pearlite! {
+        match front {
+            None => its.len() == s.len()
+                && forall<k: Int> 0 <= k && k < s.len() ==>
+                    exists<u: U> super::filter::returns(func, s[k], u) && u.into_iter_post(its[k]),
+            Some(front) => its.len() == s.len() + 1 && its[0] == front
+                && forall<k: Int> 0 <= k && k < s.len() ==>
+                    exists<u: U> super::filter::returns(func, s[k], u) && u.into_iter_post(its[k + 1]),
+        }
+    }
+}
+
+/// The concatenation of the sequences in `s`.
+#[open]
+#[logic]
+#[variant(s.len())]
+pub fn flatten<T>(s: Seq<Seq<T>>) -> Seq<T> {
+    pearlite! {
+        if s.len() == 0 { Seq::EMPTY } else { s[0].concat(flatten(s.tail())) }
+    }
+}
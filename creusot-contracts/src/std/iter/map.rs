@@ -0,0 +1,125 @@
+use crate::{invariant::Invariant, std::iter::Map, *};
+
+pub trait MapExt<I, F> {
+    #[logic]
+    fn iter(self) -> I;
+
+    #[logic]
+    fn func(self) -> F;
+}
+
+impl<I, F> MapExt<I, F> for Map<I, F> {
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn iter(self) -> I {
+        pearlite! { absurd }
+    }
+
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn func(self) -> F {
+        pearlite! { absurd }
+    }
+}
+
+#[trusted]
+impl<I, F> Resolve for Map<I, F> {
+    #[open]
+    #[predicate(prophetic)]
+    fn resolve(self) -> bool {
+        pearlite! {
+            self.iter().resolve() && self.func().resolve()
+        }
+    }
+}
+
+impl<I: Iterator, B, F: FnMut(I::Item) -> B> Invariant for Map<I, F> {
+    // Should not quantify over self or the `invariant` cannot be made into a type invariant
+    #[open(self)]
+    #[predicate(prophetic)]
+    fn invariant(self) -> bool {
+        pearlite! {
+            reinitialize::<I, B, F>() &&
+            preservation::<I, B, F>(self.iter(), self.func()) &&
+            next_precondition::<I, B, F>(self.iter(), self.func())
+        }
+    }
+}
+
+impl<I: Iterator, B, F: FnMut(I::Item) -> B> Iterator for Map<I, F> {
+    #[open]
+    #[predicate(prophetic)]
+    fn completed(&mut self) -> bool {
+        pearlite! {
+            (exists<inner: &mut _> *inner == self.iter() && ^inner == (^self).iter() && inner.completed())
+            && (*self).func() == (^self).func()
+        }
+    }
+
+    #[open]
+    #[predicate(prophetic)]
+    #[why3::attr = "inline:trivial"]
+    fn produces(self, visited: Seq<Self::Item>, succ: Self) -> bool {
+        pearlite! {
+            self.func().unnest(succ.func())
+            && exists<s: Seq<I::Item>> s.len() == visited.len() && self.iter().produces(s, succ.iter())
+            && exists<fs: Seq<&mut F>> fs.len() == visited.len()
+            && (forall<i: Int> 1 <= i && i < fs.len() ==> ^fs[i - 1] == *fs[i])
+            && if visited.len() == 0 { self.func() == succ.func() }
+               else { *fs[0] == self.func() && ^fs[visited.len() - 1] == succ.func() }
+            && forall<i: Int> 0 <= i && i < visited.len() ==>
+                 self.func().unnest(*fs[i])
+                 && (*fs[i]).precondition((s[i],))
+                 && fs[i].postcondition_mut((s[i],), visited[i])
+        }
+    }
+
+    #[law]
+    #[open(self)]
+    #[ensures(self.produces(Seq::EMPTY, self))]
+    fn produces_refl(self) {}
+
+    #[law]
+    #[open(self)]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
+
+/// The closure `func` can be called on the next element produced by `iter`.
+#[open]
+#[predicate(prophetic)]
+pub fn next_precondition<I: Iterator, B, F: FnMut(I::Item) -> B>(iter: I, func: F) -> bool {
+    pearlite! {
+        forall<e: I::Item, i: I> iter.produces(Seq::singleton(e), i) ==> func.precondition((e,))
+    }
+}
+
+/// Calling `func` on an element produced by `iter` preserves the precondition of `func` on the
+/// following element.
+#[open]
+#[predicate(prophetic)]
+pub fn preservation<I: Iterator, B, F: FnMut(I::Item) -> B>(iter: I, func: F) -> bool {
+    pearlite! {
+        forall<s: Seq<I::Item>, e1: I::Item, e2: I::Item, f: &mut F, b: B, i: I>
+            func.unnest(*f) ==>
+            iter.produces(s.push(e1).push(e2), i) ==>
+            (*f).precondition((e1,)) ==>
+            f.postcondition_mut((e1,), b) ==>
+            (^f).precondition((e2,))
+    }
+}
+
+/// Once an iterator is completed, the closure can be used again on the elements it produces next.
+#[open]
+#[predicate(prophetic)]
+pub fn reinitialize<I: Iterator, B, F: FnMut(I::Item) -> B>() -> bool {
+    pearlite! {
+        forall<iter: &mut I, func: F>
+            iter.completed() ==>
+            next_precondition::<I, B, F>(^iter, func) && preservation::<I, B, F>(^iter, func)
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{std::iter::Peekable, *};
+
+pub trait PeekableExt<I: Iterator> {
+    #[logic]
+    fn iter(self) -> I;
+
+    /// The element that was peeked, if any. `Some(None)` means that the end of the iterator was
+    /// peeked.
+    #[logic]
+    fn peeked(self) -> Option<Option<I::Item>>;
+}
+
+impl<I: Iterator> PeekableExt<I> for Peekable<I> {
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn iter(self) -> I {
+        pearlite! { absurd }
+    }
+
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn peeked(self) -> Option<Option<I::Item>> {
+        pearlite! { absurd }
+    }
+}
+
+impl<I: Iterator> Iterator for Peekable<I> {
+    #[open]
+    #[predicate(prophetic)]
+    fn completed(&mut self) -> bool {
+        pearlite! {
+            (^self).peeked() == None &&
+            match (*self).peeked() {
+                Some(None) => (*self).iter() == (^self).iter(),
+                Some(Some(_)) => false,
+                None => exists<inner: &mut _> *inner == self.iter() && ^inner == (^self).iter() && inner.completed(),
+            }
+        }
+    }
+
+    #[open]
+    #[predicate(prophetic)]
+    fn produces(self, visited: Seq<Self::Item>, o: Self) -> bool {
+        pearlite! {
+            visited == Seq::EMPTY && self == o ||
+            visited.len() > 0 && self.peeked() != Some(None) && o.peeked() != Some(None) &&
+            exists<s: Seq<I::Item>>
+                self.iter().produces(s, o.iter()) &&
+                match self.peeked() { Some(Some(v)) => Seq::singleton(v).concat(s), _ => s }
+                    == match o.peeked() { Some(Some(v)) => visited.push(v), _ => visited }
+        }
+    }
+
+    #[law]
+    #[open(self)]
+    #[ensures(self.produces(Seq::EMPTY, self))]
+    fn produces_refl(self) {}
+
+    #[law]
+    #[open(self)]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
@@ -1,6 +1,6 @@
 use crate::{
     std::{
-        iter::Step,
+        iter::{DoubleEndedIterator, Step},
         ops::{Range, RangeInclusive},
     },
     *,
@@ -40,6 +40,32 @@ impl<Idx: DeepModel<DeepModelTy = Int> + Step> Iterator for Range<Idx> {
     fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
 }
 
+impl<Idx: DeepModel<DeepModelTy = Int> + Step> DoubleEndedIterator for Range<Idx> {
+    #[predicate]
+    #[open]
+    fn produces_back(self, visited: Seq<Self::Item>, o: Self) -> bool {
+        pearlite! {
+            self.start == o.start && self.end.deep_model() >= o.end.deep_model()
+            && (visited.len() > 0 ==> o.start.deep_model() <= o.end.deep_model())
+            && visited.len() == self.end.deep_model() - o.end.deep_model()
+            && forall<i : Int> 0 <= i && i < visited.len() ==>
+                visited[i].deep_model() == self.end.deep_model() - (i + 1)
+        }
+    }
+
+    #[law]
+    #[open(self)]
+    #[ensures(self.produces_back(Seq::EMPTY, self))]
+    fn produces_back_refl(self) {}
+
+    #[law]
+    #[open(self)]
+    #[requires(a.produces_back(ab, b))]
+    #[requires(b.produces_back(bc, c))]
+    #[ensures(a.produces_back(ab.concat(bc), c))]
+    fn produces_back_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
+
 #[logic]
 #[open]
 #[ensures(r.is_empty_log() == (result == 0))]
@@ -0,0 +1,81 @@
+use crate::{
+    std::iter::{DoubleEndedIterator, Rev},
+    *,
+};
+
+pub trait RevExt<I> {
+    #[logic]
+    fn iter(self) -> I;
+}
+
+impl<I> RevExt<I> for Rev<I> {
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn iter(self) -> I {
+        pearlite! { absurd }
+    }
+}
+
+#[trusted]
+impl<I> Resolve for Rev<I> {
+    #[open]
+    #[predicate(prophetic)]
+    fn resolve(self) -> bool {
+        pearlite! {
+            self.iter().resolve()
+        }
+    }
+}
+
+impl<I: DoubleEndedIterator> Iterator for Rev<I> {
+    #[open]
+    #[predicate(prophetic)]
+    fn completed(&mut self) -> bool {
+        pearlite! {
+            exists<inner: &mut _> *inner == self.iter() && ^inner == (^self).iter() && inner.completed()
+        }
+    }
+
+    #[open]
+    #[predicate(prophetic)]
+    fn produces(self, visited: Seq<Self::Item>, o: Self) -> bool {
+        pearlite! {
+            self.iter().produces_back(visited, o.iter())
+        }
+    }
+
+    #[law]
+    #[open(self)]
+    #[ensures(self.produces(Seq::EMPTY, self))]
+    fn produces_refl(self) {}
+
+    #[law]
+    #[open(self)]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedIterator for Rev<I> {
+    #[open]
+    #[predicate(prophetic)]
+    fn produces_back(self, visited: Seq<Self::Item>, o: Self) -> bool {
+        pearlite! {
+            self.iter().produces(visited, o.iter())
+        }
+    }
+
+    #[law]
+    #[open(self)]
+    #[ensures(self.produces_back(Seq::EMPTY, self))]
+    fn produces_back_refl(self) {}
+
+    #[law]
+    #[open(self)]
+    #[requires(a.produces_back(ab, b))]
+    #[requires(b.produces_back(bc, c))]
+    #[ensures(a.produces_back(ab.concat(bc), c))]
+    fn produces_back_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
@@ -0,0 +1,101 @@
+use crate::{invariant::Invariant, std::iter::SkipWhile, *};
+
+pub trait SkipWhileExt<I, P> {
+    #[logic]
+    fn iter(self) -> I;
+
+    #[logic]
+    fn func(self) -> P;
+
+    /// Whether the skipping is over, in which case the predicate is not called anymore.
+    #[predicate]
+    fn flag(self) -> bool;
+}
+
+impl<I, P> SkipWhileExt<I, P> for SkipWhile<I, P> {
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn iter(self) -> I {
+        pearlite! { absurd }
+    }
+
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn func(self) -> P {
+        pearlite! { absurd }
+    }
+
+    #[predicate]
+    #[open(self)]
+    #[trusted]
+    fn flag(self) -> bool {
+        pearlite! { absurd }
+    }
+}
+
+#[trusted]
+impl<I, P> Resolve for SkipWhile<I, P> {
+    #[open]
+    #[predicate(prophetic)]
+    fn resolve(self) -> bool {
+        pearlite! {
+            self.iter().resolve() && self.func().resolve()
+        }
+    }
+}
+
+impl<I: Iterator, P: FnMut(&I::Item) -> bool> Invariant for SkipWhile<I, P> {
+    // The closure is restricted to keep the specification of `SkipWhile` simple.
+    #[open(self)]
+    #[predicate(prophetic)]
+    fn invariant(self) -> bool {
+        pearlite! {
+            super::filter::immutable(self.func()) && super::filter::no_precondition(self.func()) && super::filter::precise(self.func())
+        }
+    }
+}
+
+impl<I: Iterator, P: FnMut(&I::Item) -> bool> Iterator for SkipWhile<I, P> {
+    #[open]
+    #[predicate(prophetic)]
+    fn completed(&mut self) -> bool {
+        pearlite! {
+            (*self).func() == (^self).func() && (*self).flag() == (^self).flag() &&
+            exists<s: Seq<I::Item>, e: &mut I>
+                self.iter().produces(s, *e) && e.completed() && ^e == (^self).iter() &&
+                (self.flag() ==> s == Seq::EMPTY) &&
+                forall<i: Int> 0 <= i && i < s.len() ==> super::filter::returns(self.func(), &s[i], true) && s[i].resolve()
+        }
+    }
+
+    #[open]
+    #[predicate(prophetic)]
+    fn produces(self, visited: Seq<Self::Item>, o: Self) -> bool {
+        pearlite! {
+            visited == Seq::EMPTY && self == o ||
+            visited.len() > 0 && o.flag() && self.func() == o.func() &&
+            exists<s: Seq<I::Item>>
+                self.iter().produces(s.concat(visited), o.iter()) &&
+                if self.flag() {
+                    s == Seq::EMPTY
+                } else {
+                    super::filter::returns(self.func(), &visited[0], false) &&
+                    forall<i: Int> 0 <= i && i < s.len() ==> super::filter::returns(self.func(), &s[i], true) && s[i].resolve()
+                }
+        }
+    }
+
+    #[law]
+    #[open(self)]
+    #[ensures(self.produces(Seq::EMPTY, self))]
+    fn produces_refl(self) {}
+
+    #[law]
+    #[open(self)]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
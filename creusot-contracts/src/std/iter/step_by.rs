@@ -0,0 +1,93 @@
+use crate::{std::iter::StepBy, *};
+
+pub trait StepByExt<I> {
+    #[logic]
+    fn iter(self) -> I;
+
+    /// The step given to `step_by`.
+    #[logic]
+    fn step(self) -> Int;
+
+    /// Whether the next element is the first one, which is taken without skipping.
+    #[predicate]
+    fn first_take(self) -> bool;
+}
+
+impl<I> StepByExt<I> for StepBy<I> {
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn iter(self) -> I {
+        pearlite! { absurd }
+    }
+
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    #[ensures(0 < result && result <= usize::MAX@)]
+    fn step(self) -> Int {
+        pearlite! { absurd }
+    }
+
+    #[predicate]
+    #[open(self)]
+    #[trusted]
+    fn first_take(self) -> bool {
+        pearlite! { absurd }
+    }
+}
+
+#[trusted]
+impl<I> Resolve for StepBy<I> {
+    #[open]
+    #[predicate(prophetic)]
+    fn resolve(self) -> bool {
+        pearlite! {
+            self.iter().resolve()
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for StepBy<I> {
+    #[open]
+    #[predicate(prophetic)]
+    fn completed(&mut self) -> bool {
+        pearlite! {
+            !(^self).first_take() && (^self).step() == self.step() &&
+            exists<s: Seq<I::Item>, i: &mut I>
+                s.len() <= (if self.first_take() { 0 } else { self.step() - 1 }) &&
+                self.iter().produces(s, *i) &&
+                (forall<i: Int> 0 <= i && i < s.len() ==> s[i].resolve()) &&
+                i.completed() &&
+                ^i == (^self).iter()
+        }
+    }
+
+    #[open]
+    #[predicate(prophetic)]
+    fn produces(self, visited: Seq<Self::Item>, o: Self) -> bool {
+        pearlite! {
+            visited == Seq::EMPTY && self == o ||
+            visited.len() > 0 && !o.first_take() && o.step() == self.step() &&
+            exists<s: Seq<I::Item>, k: Int>
+                k == (if self.first_take() { 0 } else { self.step() - 1 }) &&
+                s.len() == k + (visited.len() - 1) * self.step() + 1 &&
+                self.iter().produces(s, o.iter()) &&
+                (forall<i: Int> 0 <= i && i < visited.len() ==> visited[i] == s[k + i * self.step()]) &&
+                forall<j: Int> 0 <= j && j < s.len() ==>
+                    (exists<i: Int> 0 <= i && i < visited.len() && j == k + i * self.step()) || s[j].resolve()
+        }
+    }
+
+    #[law]
+    #[open(self)]
+    #[ensures(self.produces(Seq::EMPTY, self))]
+    fn produces_refl(self) {}
+
+    #[law]
+    #[open(self)]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
@@ -0,0 +1,99 @@
+use crate::{invariant::Invariant, std::iter::TakeWhile, *};
+
+pub trait TakeWhileExt<I, P> {
+    #[logic]
+    fn iter(self) -> I;
+
+    #[logic]
+    fn func(self) -> P;
+
+    /// Whether an element was rejected by the predicate, ending the iteration.
+    #[predicate]
+    fn flag(self) -> bool;
+}
+
+impl<I, P> TakeWhileExt<I, P> for TakeWhile<I, P> {
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn iter(self) -> I {
+        pearlite! { absurd }
+    }
+
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn func(self) -> P {
+        pearlite! { absurd }
+    }
+
+    #[predicate]
+    #[open(self)]
+    #[trusted]
+    fn flag(self) -> bool {
+        pearlite! { absurd }
+    }
+}
+
+#[trusted]
+impl<I, P> Resolve for TakeWhile<I, P> {
+    #[open]
+    #[predicate(prophetic)]
+    fn resolve(self) -> bool {
+        pearlite! {
+            self.iter().resolve() && self.func().resolve()
+        }
+    }
+}
+
+impl<I: Iterator, P: FnMut(&I::Item) -> bool> Invariant for TakeWhile<I, P> {
+    // The closure is restricted to keep the specification of `TakeWhile` simple.
+    #[open(self)]
+    #[predicate(prophetic)]
+    fn invariant(self) -> bool {
+        pearlite! {
+            super::filter::immutable(self.func()) && super::filter::no_precondition(self.func()) && super::filter::precise(self.func())
+        }
+    }
+}
+
+impl<I: Iterator, P: FnMut(&I::Item) -> bool> Iterator for TakeWhile<I, P> {
+    #[open]
+    #[predicate(prophetic)]
+    fn completed(&mut self) -> bool {
+        pearlite! {
+            (*self).func() == (^self).func() &&
+            if (*self).flag() {
+                self.resolve()
+            } else {
+                exists<inner: &mut I> *inner == self.iter() && ^inner == (^self).iter() &&
+                    (inner.completed() && !(^self).flag()
+                    || (^self).flag() && exists<x: I::Item> (*inner).produces(Seq::singleton(x), ^inner)
+                        && super::filter::returns(self.func(), &x, false) && x.resolve())
+            }
+        }
+    }
+
+    #[open]
+    #[predicate(prophetic)]
+    fn produces(self, visited: Seq<Self::Item>, o: Self) -> bool {
+        pearlite! {
+            visited == Seq::EMPTY && self == o ||
+            !self.flag() && !o.flag() && self.func() == o.func()
+            && self.iter().produces(visited, o.iter())
+            && forall<i: Int> 0 <= i && i < visited.len() ==> super::filter::returns(self.func(), &visited[i], true)
+        }
+    }
+
+    #[law]
+    #[open(self)]
+    #[ensures(self.produces(Seq::EMPTY, self))]
+    fn produces_refl(self) {}
+
+    #[law]
+    #[open(self)]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
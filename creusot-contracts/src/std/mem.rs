@@ -1,6 +1,17 @@
 use crate::*;
 pub use ::std::mem::*;
 
+/// The size in bytes of a value of type `T`, as given by [`size_of`]
+///
+/// Nothing is known about it beyond its sign.
+#[trusted]
+#[logic]
+#[open(self)]
+#[ensures(result >= 0)]
+pub fn size_of_logic<T>() -> Int {
+    absurd
+}
+
 extern_spec! {
     mod std {
         mod mem {
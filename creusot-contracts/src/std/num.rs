@@ -1,4 +1,4 @@
-use crate::{Default, *};
+use crate::{logic::FloatLogic, std::string::FromStrSpec, Default, *};
 pub use ::std::num::*;
 
 macro_rules! mach_int {
@@ -30,6 +30,22 @@ macro_rules! mach_int {
                 pearlite! { self == $zero }
             }
         }
+
+        impl FromStrSpec for $t {
+            #[predicate]
+            #[open]
+            fn parses_to(s: Seq<char>, res: Result<Self, Self::Err>) -> bool {
+                pearlite! {
+                    match res {
+                        Ok(v) => crate::std::string::int_of_str(s, $t::MIN@ < 0) == Some(v@),
+                        Err(_) => match crate::std::string::int_of_str(s, $t::MIN@ < 0) {
+                            Some(n) => n < $t::MIN@ || $t::MAX@ < n,
+                            None => true,
+                        },
+                    }
+                }
+            }
+        }
     };
 }
 
@@ -48,13 +64,13 @@ mach_int!(i128, "prelude.prelude.Int128", 0i128);
 mach_int!(isize, "prelude.prelude.IntSize", 9isize);
 
 /// Adds specifications for checked, wrapping, saturating, and overflowing operations on the given
-/// integer type
+/// integer type. `$div` is the division on bit vectors matching the signedness of the type.
 macro_rules! spec_type {
-    ($type:ty) => {
+    ($type:ty, $div:ident) => {
         // Specify addition, subtraction and multiplication
-        spec_op_common!($type, +, checked_add, wrapping_add, saturating_add, overflowing_add);
-        spec_op_common!($type, -, checked_sub, wrapping_sub, saturating_sub, overflowing_sub);
-        spec_op_common!($type, *, checked_mul, wrapping_mul, saturating_mul, overflowing_mul);
+        spec_op_common!($type, +, add, checked_add, wrapping_add, saturating_add, overflowing_add);
+        spec_op_common!($type, -, sub, checked_sub, wrapping_sub, saturating_sub, overflowing_sub);
+        spec_op_common!($type, *, mul, checked_mul, wrapping_mul, saturating_mul, overflowing_mul);
 
         // Specify division separately, because it has the additional precondition that the divisor
         // is non-zero. Overflow on division can only occur on signed types and only when computing
@@ -77,6 +93,8 @@ macro_rules! spec_type {
                 #[ensures((self@ == $type::MIN@ && rhs@ == -1) ==> result@ == self@)]
                 // Else, returns the result of the division
                 #[ensures((self@ == $type::MIN@ && rhs@ == -1) || result@ == self@ / rhs@)]
+                // Returns the result of the division on bit vectors
+                #[ensures(result.to_bv() == self.to_bv().$div(rhs.to_bv()))]
                 fn wrapping_div(self, rhs: $type) -> $type;
 
                 #[allow(dead_code)]
@@ -97,6 +115,8 @@ macro_rules! spec_type {
                 #[ensures((self@ == $type::MIN@ && rhs@ == -1) ==> result.0@ == self@)]
                 // Else, returns the result of the division
                 #[ensures((self@ == $type::MIN@ && rhs@ == -1) || result.0@ == self@ / rhs@)]
+                // Returns the result of the division on bit vectors
+                #[ensures(result.0.to_bv() == self.to_bv().$div(rhs.to_bv()))]
                 // Overflow only occurs when computing `$type::MIN / -1`
                 #[ensures(result.1 == (self@ == $type::MIN@ && rhs@ == -1))]
                 fn overflowing_div(self, rhs: $type) -> ($type, bool);
@@ -112,6 +132,7 @@ macro_rules! spec_op_common {
     (
         $type:ty,
         $op:tt,
+        $bv_op:ident,
         $checked:ident,
         $wrapping:ident,
         $saturating:ident,
@@ -136,9 +157,12 @@ macro_rules! spec_op_common {
                 // Wrapping: performs the operation on `Int` and converts back to `$type`
                 #[allow(dead_code)]
                 #[pure]
-                // Returns result converted to `$type`
+                // Returns the result of the operation on bit vectors
+                #[ensures(result.to_bv() == self.to_bv().$bv_op(rhs.to_bv()))]
+                // Returns the result wrapped around into the range of `$type`
                 #[ensures(
-                    result@ == (self@ $op rhs@).rem_euclid(2.pow($type::BITS@)) + $type::MIN@
+                    result@ == (self@ $op rhs@ - $type::MIN@).rem_euclid(2.pow($type::BITS@))
+                        + $type::MIN@
                 )]
                 // Returns the result if it is in range
                 #[ensures(
@@ -178,9 +202,12 @@ macro_rules! spec_op_common {
                 // indicates whether an overflow occurred
                 #[allow(dead_code)]
                 #[pure]
-                // Returns result converted to `$type`
+                // Returns the result of the operation on bit vectors
+                #[ensures(result.0.to_bv() == self.to_bv().$bv_op(rhs.to_bv()))]
+                // Returns the result wrapped around into the range of `$type`
                 #[ensures(
-                    result.0@ == (self@ $op rhs@).rem_euclid(2.pow($type::BITS@)) + $type::MIN@
+                    result.0@ == (self@ $op rhs@ - $type::MIN@).rem_euclid(2.pow($type::BITS@))
+                        + $type::MIN@
                 )]
                 // Returns the result if it is in range
                 #[ensures(
@@ -232,19 +259,19 @@ macro_rules! spec_abs_diff {
     };
 }
 
-spec_type!(u8);
-spec_type!(u16);
-spec_type!(u32);
-spec_type!(u64);
-spec_type!(u128);
-spec_type!(usize);
+spec_type!(u8, udiv);
+spec_type!(u16, udiv);
+spec_type!(u32, udiv);
+spec_type!(u64, udiv);
+spec_type!(u128, udiv);
+spec_type!(usize, udiv);
 
-spec_type!(i8);
-spec_type!(i16);
-spec_type!(i32);
-spec_type!(i64);
-spec_type!(i128);
-spec_type!(isize);
+spec_type!(i8, sdiv);
+spec_type!(i16, sdiv);
+spec_type!(i32, sdiv);
+spec_type!(i64, sdiv);
+spec_type!(i128, sdiv);
+spec_type!(isize, sdiv);
 
 spec_abs_diff!(u8, i8);
 spec_abs_diff!(u16, i16);
@@ -252,3 +279,186 @@ spec_abs_diff!(u32, i32);
 spec_abs_diff!(u64, i64);
 spec_abs_diff!(u128, i128);
 spec_abs_diff!(usize, isize);
+
+macro_rules! float {
+    ($t:ty, $ty_nm:expr) => {
+        impl FloatLogic for $t {
+            #[predicate]
+            #[open]
+            #[trusted]
+            #[creusot::builtins = concat!($ty_nm, ".is_nan")]
+            fn is_nan_log(self) -> bool {
+                pearlite! { absurd }
+            }
+
+            #[predicate]
+            #[open]
+            #[trusted]
+            #[creusot::builtins = concat!($ty_nm, ".is_infinite")]
+            fn is_infinite_log(self) -> bool {
+                pearlite! { absurd }
+            }
+
+            #[predicate]
+            #[open]
+            #[trusted]
+            #[creusot::builtins = concat!($ty_nm, ".is_finite")]
+            fn is_finite_log(self) -> bool {
+                pearlite! { absurd }
+            }
+
+            #[predicate]
+            #[open]
+            #[trusted]
+            #[creusot::builtins = concat!($ty_nm, ".is_zero")]
+            fn is_zero_log(self) -> bool {
+                pearlite! { absurd }
+            }
+
+            #[predicate]
+            #[open]
+            #[trusted]
+            #[creusot::builtins = concat!($ty_nm, ".is_positive")]
+            fn is_positive_log(self) -> bool {
+                pearlite! { absurd }
+            }
+
+            #[predicate]
+            #[open]
+            #[trusted]
+            #[creusot::builtins = concat!($ty_nm, ".is_negative")]
+            fn is_negative_log(self) -> bool {
+                pearlite! { absurd }
+            }
+
+            #[logic]
+            #[open]
+            #[trusted]
+            #[creusot::builtins = concat!($ty_nm, ".abs")]
+            fn abs_log(self) -> Self {
+                pearlite! { absurd }
+            }
+
+            #[logic]
+            #[open]
+            #[trusted]
+            #[creusot::builtins = concat!($ty_nm, ".sqrt")]
+            fn sqrt_log(self) -> Self {
+                pearlite! { absurd }
+            }
+
+            #[logic]
+            #[open]
+            #[trusted]
+            #[creusot::builtins = concat!($ty_nm, ".fma")]
+            fn mul_add_log(self, _: Self, _: Self) -> Self {
+                pearlite! { absurd }
+            }
+
+            #[predicate]
+            #[open]
+            #[trusted]
+            #[creusot::builtins = concat!($ty_nm, ".eq")]
+            fn eq_log(self, _: Self) -> bool {
+                pearlite! { absurd }
+            }
+
+            #[predicate]
+            #[open]
+            #[trusted]
+            #[creusot::builtins = concat!($ty_nm, ".lt")]
+            fn lt_log(self, _: Self) -> bool {
+                pearlite! { absurd }
+            }
+
+            #[predicate]
+            #[open]
+            #[trusted]
+            #[creusot::builtins = concat!($ty_nm, ".le")]
+            fn le_log(self, _: Self) -> bool {
+                pearlite! { absurd }
+            }
+
+            #[predicate]
+            #[open]
+            #[trusted]
+            #[creusot::builtins = concat!($ty_nm, ".gt")]
+            fn gt_log(self, _: Self) -> bool {
+                pearlite! { absurd }
+            }
+
+            #[predicate]
+            #[open]
+            #[trusted]
+            #[creusot::builtins = concat!($ty_nm, ".ge")]
+            fn ge_log(self, _: Self) -> bool {
+                pearlite! { absurd }
+            }
+        }
+
+        extern_spec! {
+            impl $t {
+                #[allow(dead_code)]
+                #[pure]
+                #[ensures(result == self.is_nan_log())]
+                fn is_nan(self) -> bool;
+
+                #[allow(dead_code)]
+                #[pure]
+                #[ensures(result == self.is_infinite_log())]
+                fn is_infinite(self) -> bool;
+
+                #[allow(dead_code)]
+                #[pure]
+                #[ensures(result == self.is_finite_log())]
+                fn is_finite(self) -> bool;
+
+                // The sign of NaN is not modeled
+                #[allow(dead_code)]
+                #[pure]
+                #[ensures(!self.is_nan_log() ==> result == self.is_positive_log())]
+                fn is_sign_positive(self) -> bool;
+
+                #[allow(dead_code)]
+                #[pure]
+                #[ensures(!self.is_nan_log() ==> result == self.is_negative_log())]
+                fn is_sign_negative(self) -> bool;
+
+                #[allow(dead_code)]
+                #[pure]
+                #[ensures(result == self.abs_log())]
+                fn abs(self) -> $t;
+
+                #[allow(dead_code)]
+                #[pure]
+                #[ensures(result == self.sqrt_log())]
+                fn sqrt(self) -> $t;
+
+                #[allow(dead_code)]
+                #[pure]
+                #[ensures(result == self.mul_add_log(a, b))]
+                fn mul_add(self, a: $t, b: $t) -> $t;
+
+                // If one argument is NaN, the other is returned
+                #[allow(dead_code)]
+                #[pure]
+                #[ensures(self.is_nan_log() ==> result == other)]
+                #[ensures(other.is_nan_log() ==> result == self)]
+                #[ensures(!self.is_nan_log() && !other.is_nan_log() ==>
+                    (result == self || result == other) && result.le_log(self) && result.le_log(other))]
+                fn min(self, other: $t) -> $t;
+
+                #[allow(dead_code)]
+                #[pure]
+                #[ensures(self.is_nan_log() ==> result == other)]
+                #[ensures(other.is_nan_log() ==> result == self)]
+                #[ensures(!self.is_nan_log() && !other.is_nan_log() ==>
+                    (result == self || result == other) && result.ge_log(self) && result.ge_log(other))]
+                fn max(self, other: $t) -> $t;
+            }
+        }
+    };
+}
+
+float!(f32, "prelude.prelude.Float32");
+float!(f64, "prelude.prelude.Float64");
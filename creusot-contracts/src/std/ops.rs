@@ -30,13 +30,17 @@ pub trait FnMutExt<Args: Tuple>: FnMut<Args> + FnOnceExt<Args> {
 
     #[law]
     #[ensures(self.unnest(self))]
-    fn unnest_refl(self);
+    fn unnest_refl(self)
+    where
+        Self: Sized;
 
     #[law]
     #[requires(self.unnest(b))]
     #[requires(b.unnest(c))]
     #[ensures(self.unnest(c))]
-    fn unnest_trans(self, b: Self, c: Self);
+    fn unnest_trans(self, b: Self, c: Self)
+    where
+        Self: Sized;
 
     #[law]
     #[ensures(self.postcondition_once(args, res) == exists<s: &mut Self> *s == self && s.postcondition_mut(args, res) && (^s).resolve())]
@@ -145,6 +149,113 @@ impl<Args: Tuple, F: Fn<Args>> FnExt<Args> for F {
     fn fn_once(self, args: Args, res: Self::Output) {}
 }
 
+// Closures used through trait objects: the specification of a trait object is linked to the one of
+// the closure it hides when it is created.
+macro_rules! fn_once_ext_dyn {
+    ($($dyn:tt)*) => {
+        impl<'a, Args: Tuple, O> FnOnceExt<Args> for $($dyn)* {
+            #[predicate]
+            #[trusted]
+            #[open(self)]
+            fn precondition(self, _: Args) -> bool {
+                absurd
+            }
+
+            #[predicate]
+            #[trusted]
+            #[open(self)]
+            fn postcondition_once(self, _: Args, _: O) -> bool {
+                absurd
+            }
+        }
+    };
+}
+
+fn_once_ext_dyn!(dyn FnOnce<Args, Output = O> + 'a);
+fn_once_ext_dyn!(dyn FnMut<Args, Output = O> + 'a);
+fn_once_ext_dyn!(dyn Fn<Args, Output = O> + 'a);
+
+macro_rules! fn_mut_ext_dyn {
+    ($($dyn:tt)*) => {
+        impl<'a, Args: Tuple, O> FnMutExt<Args> for $($dyn)* {
+            #[predicate]
+            #[trusted]
+            #[open(self)]
+            fn postcondition_mut(&mut self, _: Args, _: O) -> bool {
+                absurd
+            }
+
+            #[predicate]
+            #[trusted]
+            #[open(self)]
+            fn unnest(self, _: Self) -> bool {
+                absurd
+            }
+
+            #[law]
+            #[trusted]
+            #[open(self)]
+            #[requires(self.postcondition_mut(args, res))]
+            #[ensures((*self).unnest(^self))]
+            fn postcondition_mut_unnest(&mut self, args: Args, res: O) {}
+
+            #[law]
+            #[trusted]
+            #[open(self)]
+            fn unnest_refl(self)
+            where
+                Self: Sized,
+            {
+            }
+
+            #[law]
+            #[trusted]
+            #[open(self)]
+            fn unnest_trans(self, _: Self, _: Self)
+            where
+                Self: Sized,
+            {
+            }
+
+            #[law]
+            #[trusted]
+            #[open(self)]
+            fn fn_mut_once(self, _: Args, _: O)
+            where
+                Self: Sized,
+            {
+            }
+        }
+    };
+}
+
+fn_mut_ext_dyn!(dyn FnMut<Args, Output = O> + 'a);
+fn_mut_ext_dyn!(dyn Fn<Args, Output = O> + 'a);
+
+impl<'a, Args: Tuple, O> FnExt<Args> for dyn Fn<Args, Output = O> + 'a {
+    #[predicate]
+    #[trusted]
+    #[open(self)]
+    fn postcondition(&self, _: Args, _: O) -> bool {
+        absurd
+    }
+
+    #[law]
+    #[open(self)]
+    #[trusted]
+    #[ensures(self.postcondition_mut(args, res) == (self.resolve() && self.postcondition(args, res)))]
+    fn fn_mut(&mut self, args: Args, res: O) {}
+
+    #[law]
+    #[open(self)]
+    #[trusted]
+    fn fn_once(self, _: Args, _: O)
+    where
+        Self: Sized,
+    {
+    }
+}
+
 extern_spec! {
     mod std {
         mod ops {
@@ -240,6 +351,113 @@ extern_spec! {
     }
 }
 
+/// Ranges whose bounds are known in the logic, as taken by methods like `Vec::drain`.
+///
+/// `RangeInclusive` is not included, since its end bound depends on whether it was exhausted.
+pub trait RangeBounds<Idx>: ::std::ops::RangeBounds<Idx> {
+    #[logic]
+    fn start_bound_log(self) -> Bound<Idx>;
+
+    #[logic]
+    fn end_bound_log(self) -> Bound<Idx>;
+}
+
+impl<Idx> RangeBounds<Idx> for Range<Idx> {
+    #[logic]
+    #[open]
+    fn start_bound_log(self) -> Bound<Idx> {
+        Bound::Included(self.start)
+    }
+
+    #[logic]
+    #[open]
+    fn end_bound_log(self) -> Bound<Idx> {
+        Bound::Excluded(self.end)
+    }
+}
+
+impl<Idx> RangeBounds<Idx> for RangeFrom<Idx> {
+    #[logic]
+    #[open]
+    fn start_bound_log(self) -> Bound<Idx> {
+        Bound::Included(self.start)
+    }
+
+    #[logic]
+    #[open]
+    fn end_bound_log(self) -> Bound<Idx> {
+        Bound::Unbounded
+    }
+}
+
+impl<Idx> RangeBounds<Idx> for RangeTo<Idx> {
+    #[logic]
+    #[open]
+    fn start_bound_log(self) -> Bound<Idx> {
+        Bound::Unbounded
+    }
+
+    #[logic]
+    #[open]
+    fn end_bound_log(self) -> Bound<Idx> {
+        Bound::Excluded(self.end)
+    }
+}
+
+impl<Idx> RangeBounds<Idx> for RangeToInclusive<Idx> {
+    #[logic]
+    #[open]
+    fn start_bound_log(self) -> Bound<Idx> {
+        Bound::Unbounded
+    }
+
+    #[logic]
+    #[open]
+    fn end_bound_log(self) -> Bound<Idx> {
+        Bound::Included(self.end)
+    }
+}
+
+impl<Idx> RangeBounds<Idx> for RangeFull {
+    #[logic]
+    #[open]
+    fn start_bound_log(self) -> Bound<Idx> {
+        Bound::Unbounded
+    }
+
+    #[logic]
+    #[open]
+    fn end_bound_log(self) -> Bound<Idx> {
+        Bound::Unbounded
+    }
+}
+
+/// The index of the first element of a sequence designated by `r`.
+#[logic]
+#[open]
+pub fn start_index<R: RangeBounds<usize>>(r: R) -> Int {
+    pearlite! {
+        match r.start_bound_log() {
+            Bound::Included(s) => s@,
+            Bound::Excluded(s) => s@ + 1,
+            Bound::Unbounded => 0,
+        }
+    }
+}
+
+/// The index following the last element designated by `r` in a sequence of length `len`.
+#[logic]
+#[open]
+pub fn end_index<R: RangeBounds<usize>>(r: R, len: Int) -> Int {
+    pearlite! {
+        match r.end_bound_log() {
+            Bound::Included(e) => e@ + 1,
+            Bound::Excluded(e) => e@,
+            Bound::Unbounded => len,
+        }
+    }
+}
+
 extern_spec! {
     mod std {
         mod ops {
@@ -39,12 +39,12 @@ extern_spec! {
                 fn is_none(&self) -> bool;
 
                 #[pure]
-                #[requires(self != None)]
+                #[panics_if(self == None)]
                 #[ensures(Some(result) == self)]
                 fn unwrap(self) -> T;
 
                 #[pure]
-                #[requires(self != None)]
+                #[panics_if(self == None)]
                 #[ensures(Some(result) == self)]
                 fn expect(self, msg: &str) -> T;
 
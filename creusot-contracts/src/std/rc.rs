@@ -0,0 +1,33 @@
+use crate::{std::alloc::Allocator, *};
+use ::std::ops::Deref;
+pub use ::std::rc::*;
+
+extern_spec! {
+    mod std {
+        mod rc {
+            impl<T> Rc<T> {
+                #[pure]
+                #[ensures(result@ == value)]
+                fn new(value: T) -> Rc<T>;
+            }
+
+            impl<T, A: Allocator> AsRef<T> for Rc<T, A> {
+                #[pure]
+                #[ensures(*result == self@)]
+                fn as_ref(&self) -> &T;
+            }
+
+            impl<T, A: Allocator> Deref for Rc<T, A> {
+                #[pure]
+                #[ensures(*result == self@)]
+                fn deref(&self) -> &T;
+            }
+
+            impl<T, A: Allocator + Clone> Clone for Rc<T, A> {
+                #[pure]
+                #[ensures(result@ == self@)]
+                fn clone(&self) -> Rc<T, A>;
+            }
+        }
+    }
+}
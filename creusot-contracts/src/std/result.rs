@@ -50,21 +50,21 @@ extern_spec! {
                 fn as_mut(&mut self) -> Result<&mut T, &mut E>;
 
                 #[pure]
-                #[requires(exists<t: T> self == Ok(t))]
+                #[panics_if(exists<e: E> self == Err(e))]
                 #[ensures(Ok(result) == self)]
                 fn unwrap(self) -> T
                 where
                     E: Debug;
 
                 #[pure]
-                #[requires(exists<t: T> self == Ok(t))]
+                #[panics_if(exists<e: E> self == Err(e))]
                 #[ensures(Ok(result) == self)]
                 fn expect(self, msg: &str) -> T
                 where
                     E: Debug;
 
                 #[pure]
-                #[requires(exists<e: E> self == Err(e))]
+                #[panics_if(exists<t: T> self == Ok(t))]
                 #[ensures(Err(result) == self)]
                 fn unwrap_err(self) -> E
                 where
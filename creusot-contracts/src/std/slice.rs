@@ -1,6 +1,7 @@
 use crate::{
     std::{
         alloc::Allocator,
+        cmp::Ordering,
         ops::{Index, IndexMut, Range, RangeFrom, RangeFull, RangeTo, RangeToInclusive},
     },
     *,
@@ -235,6 +236,62 @@ impl<T> SliceIndex<[T]> for RangeToInclusive<usize> {
     }
 }
 
+/// `f` may return `o` when comparing `a` and `b`.
+#[open]
+#[predicate(prophetic)]
+pub fn compares<T, F: FnMut(&T, &T) -> Ordering>(f: F, a: &T, b: &T, o: Ordering) -> bool {
+    pearlite! { exists<g: &mut F> *g == f && g.postcondition_mut((a, b), o) }
+}
+
+/// Asserts that `f` can be used to sort a slice: it can be called on any pair of elements without
+/// changing its state, and it describes a total order.
+#[open]
+#[predicate(prophetic)]
+pub fn total_order_by<T, F: FnMut(&T, &T) -> Ordering>(f: F) -> bool {
+    pearlite! {
+        (forall<g: F> f.unnest(g) ==> f == g) &&
+        (forall<a: &T, b: &T> f.precondition((a, b)) && exists<o: Ordering> compares(f, a, b, o)) &&
+        (forall<a: &T, b: &T, o1: Ordering, o2: Ordering>
+            compares(f, a, b, o1) && compares(f, a, b, o2) ==> o1 == o2) &&
+        (forall<a: &T, b: &T>
+            compares(f, a, b, Ordering::Less) == compares(f, b, a, Ordering::Greater) &&
+            (compares(f, a, b, Ordering::Equal) ==> compares(f, b, a, Ordering::Equal))) &&
+        forall<a: &T, b: &T, c: &T, o: Ordering>
+            compares(f, a, b, o) && compares(f, b, c, o) ==> compares(f, a, c, o)
+    }
+}
+
+pub trait Concat<Item: ?Sized>: ::std::slice::Concat<Item> {
+    #[predicate]
+    fn concat_post(self, res: <Self as ::std::slice::Concat<Item>>::Output) -> bool;
+}
+
+/// The concatenation of the models of the elements of `s`.
+#[logic]
+#[open]
+#[variant(s.len())]
+pub fn seq_concat<T, V: ShallowModel<ShallowModelTy = Seq<T>>>(s: Seq<V>) -> Seq<T> {
+    pearlite! {
+        if s.len() == 0 { Seq::EMPTY } else { s[0]@.concat(seq_concat(s.tail())) }
+    }
+}
+
+impl<T: Clone> Concat<T> for [Vec<T>] {
+    #[predicate]
+    #[open]
+    fn concat_post(self, res: Vec<T>) -> bool {
+        pearlite! { res@ == seq_concat(self@) }
+    }
+}
+
+impl<'a, T: Clone> Concat<T> for [&'a [T]] {
+    #[predicate]
+    #[open]
+    fn concat_post(self, res: Vec<T>) -> bool {
+        pearlite! { res@ == seq_concat(self@) }
+    }
+}
+
 extern_spec! {
     impl<T> [T] {
         #[pure]
@@ -299,6 +356,16 @@ extern_spec! {
         #[ensures(result@ == self)]
         fn iter_mut(&mut self) -> IterMut<'_, T>;
 
+        #[pure]
+        #[requires(size@ > 0)]
+        #[ensures(result.slice() == self && result.size() == size@)]
+        fn windows(&self, size: usize) -> Windows<'_, T>;
+
+        #[pure]
+        #[requires(chunk_size@ > 0)]
+        #[ensures(result.slice() == self && result.size() == chunk_size@)]
+        fn chunks(&self, chunk_size: usize) -> Chunks<'_, T>;
+
         #[pure]
         #[ensures(result == None ==> self@.len() == 0)]
         #[ensures(forall<x : _> result == Some(x) ==> self[self@.len() - 1] == *x)]
@@ -321,6 +388,47 @@ extern_spec! {
         fn binary_search(&self, x : &T) -> Result<usize, usize>
             where T: Ord + DeepModel,  T::DeepModelTy: OrdLogic,;
 
+        #[pure]
+        #[ensures((^self)@ == self@.reverse())]
+        fn reverse(&mut self);
+
+        #[pure]
+        #[requires(mid@ <= self@.len())]
+        #[ensures((^self)@ == self@.subsequence(mid@, self@.len()).concat(self@.subsequence(0, mid@)))]
+        fn rotate_left(&mut self, mid: usize);
+
+        #[ensures((^self)@.len() == self@.len())]
+        #[ensures(forall<i: Int> 0 <= i && i < self@.len() ==> (^self)[i] == value && self[i].resolve())]
+        fn fill(&mut self, value: T) where T: Clone;
+
+        #[ensures(result == self.deep_model().contains(x.deep_model()))]
+        fn contains(&self, x: &T) -> bool where T: PartialEq + DeepModel;
+
+        #[ensures(result == (needle@.len() <= self@.len() &&
+            self.deep_model().subsequence(0, needle@.len()) == needle.deep_model()))]
+        fn starts_with(&self, needle: &[T]) -> bool where T: PartialEq + DeepModel;
+
+        #[ensures((^self)@.permutation_of(self@))]
+        #[ensures((^self).deep_model().sorted())]
+        fn sort(&mut self) where T: Ord + DeepModel, T::DeepModelTy: OrdLogic;
+
+        #[ensures((^self)@.permutation_of(self@))]
+        #[ensures((^self).deep_model().sorted())]
+        fn sort_unstable(&mut self) where T: Ord + DeepModel, T::DeepModelTy: OrdLogic;
+
+        #[requires(total_order_by(compare))]
+        #[ensures((^self)@.permutation_of(self@))]
+        #[ensures(forall<i: Int, j: Int> 0 <= i && i < j && j < (^self)@.len() ==>
+            !compares(compare, &(^self)[i], &(^self)[j], Ordering::Greater))]
+        fn sort_by<F>(&mut self, compare: F) where F: FnMut(&T, &T) -> Ordering;
+
+        #[ensures(result@ == self@)]
+        fn to_vec(&self) -> Vec<T> where T: Clone;
+
+        #[ensures((*self).concat_post(result))]
+        fn concat<Item: ?Sized>(&self) -> <[T] as ::std::slice::Concat<Item>>::Output
+            where [T]: Concat<Item>;
+
         #[terminates] // can OOM (?)
         #[ensures(result@ == self_@)]
         fn into_vec<A: Allocator>(self_: Box<Self, A>) -> Vec<T, A>;
@@ -413,6 +521,28 @@ impl<'a, T> Iterator for Iter<'a, T> {
     fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
 }
 
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    #[predicate]
+    #[open]
+    fn produces_back(self, visited: Seq<Self::Item>, tl: Self) -> bool {
+        pearlite! {
+            self@.to_ref_seq() == tl@.to_ref_seq().concat(visited.reverse())
+        }
+    }
+
+    #[law]
+    #[open]
+    #[ensures(self.produces_back(Seq::EMPTY, self))]
+    fn produces_back_refl(self) {}
+
+    #[law]
+    #[open]
+    #[requires(a.produces_back(ab, b))]
+    #[requires(b.produces_back(bc, c))]
+    #[ensures(a.produces_back(ab.concat(bc), c))]
+    fn produces_back_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
+
 impl<'a, T> ShallowModel for IterMut<'a, T> {
     type ShallowModelTy = &'a mut [T];
 
@@ -461,3 +591,150 @@ impl<'a, T> Iterator for IterMut<'a, T> {
     #[ensures(a.produces(ab.concat(bc), c))]
     fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
 }
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    #[predicate]
+    #[open]
+    fn produces_back(self, visited: Seq<Self::Item>, tl: Self) -> bool {
+        pearlite! {
+            self@.to_mut_seq() == tl@.to_mut_seq().concat(visited.reverse())
+        }
+    }
+
+    #[law]
+    #[open]
+    #[ensures(self.produces_back(Seq::EMPTY, self))]
+    fn produces_back_refl(self) {}
+
+    #[law]
+    #[open]
+    #[requires(a.produces_back(ab, b))]
+    #[requires(b.produces_back(bc, c))]
+    #[ensures(a.produces_back(ab.concat(bc), c))]
+    fn produces_back_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
+
+pub trait WindowsExt<'a, T> {
+    /// The remaining part of the slice, whose windows are not produced yet.
+    #[logic]
+    fn slice(self) -> &'a [T];
+
+    #[logic]
+    fn size(self) -> Int;
+}
+
+impl<'a, T> WindowsExt<'a, T> for Windows<'a, T> {
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn slice(self) -> &'a [T] {
+        pearlite! { absurd }
+    }
+
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    #[ensures(0 < result && result <= usize::MAX@)]
+    fn size(self) -> Int {
+        pearlite! { absurd }
+    }
+}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    #[predicate(prophetic)]
+    #[open]
+    fn completed(&mut self) -> bool {
+        pearlite! { self.resolve() && self.slice()@.len() < self.size() }
+    }
+
+    #[predicate]
+    #[open]
+    fn produces(self, visited: Seq<Self::Item>, tl: Self) -> bool {
+        pearlite! {
+            self.size() == tl.size()
+            && (visited.len() > 0 ==> visited.len() + self.size() - 1 <= self.slice()@.len())
+            && (visited.len() == 0 ==> self.slice() == tl.slice())
+            && tl.slice()@ == self.slice()@.subsequence(visited.len(), self.slice()@.len())
+            && forall<i: Int> 0 <= i && i < visited.len() ==>
+                visited[i]@ == self.slice()@.subsequence(i, i + self.size())
+        }
+    }
+
+    #[law]
+    #[open]
+    #[ensures(self.produces(Seq::EMPTY, self))]
+    fn produces_refl(self) {}
+
+    #[law]
+    #[open]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
+
+pub trait ChunksExt<'a, T> {
+    /// The remaining part of the slice, whose chunks are not produced yet.
+    #[logic]
+    fn slice(self) -> &'a [T];
+
+    #[logic]
+    fn size(self) -> Int;
+}
+
+impl<'a, T> ChunksExt<'a, T> for Chunks<'a, T> {
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn slice(self) -> &'a [T] {
+        pearlite! { absurd }
+    }
+
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    #[ensures(0 < result && result <= usize::MAX@)]
+    fn size(self) -> Int {
+        pearlite! { absurd }
+    }
+}
+
+impl<'a, T> Iterator for Chunks<'a, T> {
+    #[predicate(prophetic)]
+    #[open]
+    fn completed(&mut self) -> bool {
+        pearlite! { self.resolve() && self.slice()@ == Seq::EMPTY }
+    }
+
+    #[predicate]
+    #[open]
+    fn produces(self, visited: Seq<Self::Item>, tl: Self) -> bool {
+        pearlite! {
+            self.size() == tl.size()
+            && (visited.len() > 0 ==> (visited.len() - 1) * self.size() < self.slice()@.len())
+            && (visited.len() == 0 ==> self.slice() == tl.slice())
+            && tl.slice()@ == if visited.len() * self.size() <= self.slice()@.len() {
+                self.slice()@.subsequence(visited.len() * self.size(), self.slice()@.len())
+            } else {
+                Seq::EMPTY
+            }
+            && forall<i: Int> 0 <= i && i < visited.len() ==>
+                visited[i]@ == self.slice()@.subsequence(
+                    i * self.size(),
+                    if (i + 1) * self.size() <= self.slice()@.len() { (i + 1) * self.size() } else { self.slice()@.len() },
+                )
+        }
+    }
+
+    #[law]
+    #[open]
+    #[ensures(self.produces(Seq::EMPTY, self))]
+    fn produces_refl(self) {}
+
+    #[law]
+    #[open]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
@@ -1,5 +1,19 @@
 use crate::*;
-use ::std::ops::Deref;
+use ::std::{
+    ops::Deref,
+    str::{pattern::Pattern, Bytes, Chars, FromStr, Split},
+};
+
+impl Seq<char> {
+    /// The UTF-8 encoding of a sequence of characters
+    #[trusted]
+    #[logic]
+    #[open(self)]
+    #[creusot::builtins = "prelude.prelude.Str.to_utf8"]
+    pub fn to_utf8(self) -> Seq<u8> {
+        absurd
+    }
+}
 
 extern_spec! {
     mod std {
@@ -12,8 +26,28 @@ extern_spec! {
 
             impl String {
                 #[pure]
-                #[ensures(result@ == self@.len())]
+                #[ensures(result@ == Seq::EMPTY)]
+                fn new() -> String;
+
+                #[pure]
+                #[ensures(result@ == self@.to_utf8().len())]
                 fn len(&self) -> usize;
+
+                #[pure]
+                #[ensures(result == (self@.len() == 0))]
+                fn is_empty(&self) -> bool;
+
+                #[pure]
+                #[ensures(result@ == self@)]
+                fn as_str(&self) -> &str;
+
+                #[terminates] // can OOM
+                #[ensures((^self)@ == self@.push(ch))]
+                fn push(&mut self, ch: char);
+
+                #[terminates] // can OOM
+                #[ensures((^self)@ == self@.concat(string@))]
+                fn push_str(&mut self, string: &str);
             }
         }
     }
@@ -26,9 +60,276 @@ extern_spec! {
         fn to_string(&self) -> String;
 
         #[pure]
-        #[requires(ix@ < self@.len())]
+        #[ensures(result@ == self@.to_utf8().len())]
+        fn len(&self) -> usize;
+
+        #[pure]
+        #[ensures(result == (self@.len() == 0))]
+        fn is_empty(&self) -> bool;
+
+        #[pure]
+        #[requires(exists<i: Int> 0 <= i && i <= self@.len() && self@.subsequence(0, i).to_utf8().len() == ix@)]
         #[ensures(result.0@.concat(result.1@) == self@)]
-        #[ensures(result.0@.len() == ix@)]
+        #[ensures(result.0@.to_utf8().len() == ix@)]
         fn split_at(&self, ix : usize) -> (&str, &str);
+
+        #[pure]
+        #[ensures(result@ == self@.to_utf8())]
+        fn as_bytes(&self) -> &[u8];
+
+        #[pure]
+        #[ensures(result@ == self@)]
+        fn chars(&self) -> Chars<'_>;
+
+        #[pure]
+        #[ensures(result@ == self@.to_utf8())]
+        fn bytes(&self) -> Bytes<'_>;
+
+        #[pure]
+        #[ensures(result == pat.is_prefix_of_log(self@))]
+        fn starts_with<'a, P: Pattern<'a>>(&'a self, pat: P) -> bool;
+
+        #[pure]
+        #[ensures(match result {
+            None => forall<i: Int> 0 <= i && i <= self@.len() ==> !pat.matches_at(self@, i),
+            Some(b) => exists<i: Int> 0 <= i && i <= self@.len() && pat.matches_at(self@, i)
+                && b@ == self@.subsequence(0, i).to_utf8().len()
+                && forall<j: Int> 0 <= j && j < i ==> !pat.matches_at(self@, j),
+        })]
+        fn find<'a, P: Pattern<'a>>(&'a self, pat: P) -> Option<usize>;
+
+        #[pure]
+        fn split<'a, P: Pattern<'a>>(&'a self, pat: P) -> Split<'a, P>;
+
+        #[pure]
+        #[ensures(exists<i: Int, j: Int> 0 <= i && i <= j && j <= self@.len()
+            && result@ == self@.subsequence(i, j)
+            && (forall<k: Int> 0 <= k && k < i ==> self@[k].is_whitespace_log())
+            && (forall<k: Int> j <= k && k < self@.len() ==> self@[k].is_whitespace_log())
+            && (i < j ==> !self@[i].is_whitespace_log() && !self@[j - 1].is_whitespace_log())
+        )]
+        fn trim(&self) -> &str;
+
+        #[ensures(F::parses_to(self@, result))]
+        fn parse<F: FromStr>(&self) -> Result<F, F::Err>;
+    }
+}
+
+impl<'a> ShallowModel for Chars<'a> {
+    type ShallowModelTy = Seq<char>;
+
+    /// The characters which remain to be produced
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        absurd
+    }
+}
+
+impl<'a> Iterator for Chars<'a> {
+    #[predicate(prophetic)]
+    #[open]
+    fn completed(&mut self) -> bool {
+        pearlite! { self.resolve() && (*self)@ == Seq::EMPTY }
+    }
+
+    #[predicate]
+    #[open]
+    fn produces(self, visited: Seq<Self::Item>, tl: Self) -> bool {
+        pearlite! { self@ == visited.concat(tl@) }
+    }
+
+    #[law]
+    #[open]
+    #[ensures(self.produces(Seq::EMPTY, self))]
+    fn produces_refl(self) {}
+
+    #[law]
+    #[open]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
+
+impl<'a> ShallowModel for Bytes<'a> {
+    type ShallowModelTy = Seq<u8>;
+
+    /// The bytes which remain to be produced
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        absurd
+    }
+}
+
+impl<'a> Iterator for Bytes<'a> {
+    #[predicate(prophetic)]
+    #[open]
+    fn completed(&mut self) -> bool {
+        pearlite! { self.resolve() && (*self)@ == Seq::EMPTY }
+    }
+
+    #[predicate]
+    #[open]
+    fn produces(self, visited: Seq<Self::Item>, tl: Self) -> bool {
+        pearlite! { self@ == visited.concat(tl@) }
+    }
+
+    #[law]
+    #[open]
+    #[ensures(self.produces(Seq::EMPTY, self))]
+    fn produces_refl(self) {}
+
+    #[law]
+    #[open]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
+
+// The pieces produced by `split` are not specified
+impl<'a, P: Pattern<'a>> Iterator for Split<'a, P> {
+    #[predicate(prophetic)]
+    #[open(self)]
+    #[trusted]
+    fn completed(&mut self) -> bool {
+        absurd
+    }
+
+    #[predicate]
+    #[open(self)]
+    #[trusted]
+    fn produces(self, _: Seq<Self::Item>, _: Self) -> bool {
+        absurd
+    }
+
+    #[law]
+    #[open(self)]
+    #[trusted]
+    #[ensures(self.produces(Seq::EMPTY, self))]
+    fn produces_refl(self) {}
+
+    #[law]
+    #[open(self)]
+    #[trusted]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<Self::Item>, b: Self, bc: Seq<Self::Item>, c: Self) {}
+}
+
+/// Logical model of the patterns used to search in strings
+pub trait PatternExt {
+    /// The pattern matches the beginning of `s`
+    #[predicate]
+    fn is_prefix_of_log(self, s: Seq<char>) -> bool;
+
+    /// The pattern matches `s` at the character of index `i`
+    #[predicate]
+    #[open]
+    fn matches_at(self, s: Seq<char>, i: Int) -> bool
+    where
+        Self: Sized,
+    {
+        pearlite! { self.is_prefix_of_log(s.subsequence(i, s.len())) }
+    }
+}
+
+// Other patterns, such as closures, are not specified
+#[cfg(creusot)]
+impl<P> PatternExt for P {
+    #[predicate]
+    #[open(self)]
+    #[trusted]
+    default fn is_prefix_of_log(self, _: Seq<char>) -> bool {
+        absurd
+    }
+}
+
+impl PatternExt for char {
+    #[predicate]
+    #[open]
+    fn is_prefix_of_log(self, s: Seq<char>) -> bool {
+        pearlite! { s.len() > 0 && s[0] == self }
+    }
+}
+
+impl PatternExt for &str {
+    #[predicate]
+    #[open]
+    fn is_prefix_of_log(self, s: Seq<char>) -> bool {
+        pearlite! { self@.len() <= s.len() && s.subsequence(0, self@.len()) == self@ }
+    }
+}
+
+impl PatternExt for &String {
+    #[predicate]
+    #[open]
+    fn is_prefix_of_log(self, s: Seq<char>) -> bool {
+        pearlite! { self@.len() <= s.len() && s.subsequence(0, self@.len()) == self@ }
+    }
+}
+
+/// Specification of the parsing of values from strings
+pub trait FromStrSpec: FromStr {
+    /// `res` is the result of parsing `s`
+    #[predicate]
+    fn parses_to(s: Seq<char>, res: Result<Self, Self::Err>) -> bool;
+}
+
+// Other types can be parsed into any value
+#[cfg(creusot)]
+impl<T: FromStr> FromStrSpec for T {
+    #[predicate]
+    #[open]
+    default fn parses_to(_: Seq<char>, _: Result<Self, Self::Err>) -> bool {
+        true
+    }
+}
+
+/// The value of the non-empty sequence of decimal digits `s`
+#[logic]
+#[open]
+#[variant(s.len())]
+pub fn digits_value(s: Seq<char>) -> Option<Int> {
+    pearlite! {
+        if s.len() == 0 {
+            None
+        } else if '0' <= s[s.len() - 1] && s[s.len() - 1] <= '9' {
+            let d = s[s.len() - 1]@ - 48;
+            if s.len() == 1 {
+                Some(d)
+            } else {
+                match digits_value(s.subsequence(0, s.len() - 1)) {
+                    Some(n) => Some(n * 10 + d),
+                    None => None,
+                }
+            }
+        } else {
+            None
+        }
+    }
+}
+
+/// The integer written in decimal in `s`, with an optional sign, following the rules of `FromStr`
+/// for integers
+#[logic]
+#[open]
+pub fn int_of_str(s: Seq<char>, signed: bool) -> Option<Int> {
+    pearlite! {
+        if s.len() > 0 && s[0] == '+' {
+            digits_value(s.tail())
+        } else if signed && s.len() > 0 && s[0] == '-' {
+            match digits_value(s.tail()) {
+                Some(n) => Some(-n),
+                None => None,
+            }
+        } else {
+            digits_value(s)
+        }
     }
 }
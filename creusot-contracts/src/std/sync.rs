@@ -0,0 +1,109 @@
+use crate::{
+    std::{alloc::Allocator, cell::Inv},
+    *,
+};
+pub use ::std::sync::*;
+use ::std::{marker::PhantomData, ops::Deref};
+
+extern_spec! {
+    mod std {
+        mod sync {
+            impl<T> Arc<T> {
+                #[pure]
+                #[ensures(result@ == value)]
+                fn new(value: T) -> Arc<T>;
+            }
+
+            impl<T, A: Allocator> AsRef<T> for Arc<T, A> {
+                #[pure]
+                #[ensures(*result == self@)]
+                fn as_ref(&self) -> &T;
+            }
+
+            impl<T, A: Allocator> Deref for Arc<T, A> {
+                #[pure]
+                #[ensures(*result == self@)]
+                fn deref(&self) -> &T;
+            }
+
+            impl<T, A: Allocator + Clone> Clone for Arc<T, A> {
+                #[pure]
+                #[ensures(result@ == self@)]
+                fn clone(&self) -> Arc<T, A>;
+            }
+        }
+    }
+}
+
+/// A [`Mutex`] whose content always satisfies the invariant `I`
+///
+/// Poisoning is ignored: the content is only ever replaced by values satisfying the invariant, so
+/// a panic while the lock is held cannot break it.
+#[trusted]
+pub struct PredMutex<T, I>(Mutex<T>, PhantomData<I>);
+
+impl<T, I: Inv<T>> PredMutex<T, I> {
+    #[trusted]
+    #[requires(I::inv(val))]
+    pub fn new(val: T) -> Self {
+        PredMutex(Mutex::new(val), PhantomData)
+    }
+
+    /// Blocks until the lock is acquired
+    #[trusted]
+    #[ensures(I::inv(result@))]
+    pub fn lock(&self) -> PredMutexGuard<'_, T, I> {
+        let guard = match self.0.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        PredMutexGuard(guard, PhantomData)
+    }
+
+    #[trusted]
+    #[ensures(I::inv(result))]
+    pub fn into_inner(self) -> T {
+        match self.0.into_inner() {
+            Ok(val) => val,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+/// The lock on a [`PredMutex`], released when dropped
+///
+/// The content can only be replaced by values satisfying the invariant, using [`set`](Self::set).
+#[trusted]
+pub struct PredMutexGuard<'a, T, I>(MutexGuard<'a, T>, PhantomData<I>);
+
+impl<'a, T, I> ShallowModel for PredMutexGuard<'a, T, I> {
+    type ShallowModelTy = T;
+
+    /// The current value of the content of the mutex
+    #[logic]
+    #[open(self)]
+    #[trusted]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        pearlite! { absurd }
+    }
+}
+
+impl<'a, T, I: Inv<T>> PredMutexGuard<'a, T, I> {
+    #[trusted]
+    #[requires(I::inv(val))]
+    #[ensures((^self)@ == val)]
+    pub fn set(&mut self, val: T) {
+        *self.0 = val
+    }
+}
+
+impl<'a, T, I: Inv<T>> Deref for PredMutexGuard<'a, T, I> {
+    type Target = T;
+
+    #[trusted]
+    #[ensures(*result == self@)]
+    #[ensures(I::inv(*result))]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
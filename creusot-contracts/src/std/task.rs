@@ -0,0 +1,18 @@
+use crate::*;
+pub use ::std::task::*;
+
+extern_spec! {
+    mod std {
+        mod task {
+            impl<T> Poll<T> {
+                #[pure]
+                #[ensures(result == (*self != Poll::Pending))]
+                fn is_ready(&self) -> bool;
+
+                #[pure]
+                #[ensures(result == (*self == Poll::Pending))]
+                fn is_pending(&self) -> bool;
+            }
+        }
+    }
+}
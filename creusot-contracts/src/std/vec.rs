@@ -1,8 +1,9 @@
 use crate::{
     invariant::Invariant,
+    logic::Mapping,
     std::{
         alloc::Allocator,
-        ops::{Deref, DerefMut, Index, IndexMut},
+        ops::{Deref, DerefMut, Index, IndexMut, RangeBounds},
         slice::SliceIndex,
     },
     Default, *,
@@ -128,6 +129,76 @@ extern_spec! {
                 #[pure]
                 #[ensures((^self)@.len() == 0)]
                 fn clear(&mut self);
+
+                #[pure]
+                #[ensures(len@ <= self@.len() ==> (^self)@ == self@.subsequence(0, len@))]
+                #[ensures(len@ > self@.len() ==> (^self)@ == self@)]
+                #[ensures(forall<i: Int> len@ <= i && i < self@.len() ==> self[i].resolve())]
+                fn truncate(&mut self, len: usize);
+
+                #[pure]
+                #[requires(index@ < self@.len())]
+                #[ensures(result == self[index@])]
+                #[ensures((^self)@ == self@.set(index@, self[self@.len() - 1]).subsequence(0, self@.len() - 1))]
+                fn swap_remove(&mut self, index: usize) -> T;
+
+                #[requires(crate::std::iter::filter::immutable(f))]
+                #[requires(crate::std::iter::filter::no_precondition(f))]
+                #[requires(crate::std::iter::filter::precise(f))]
+                #[ensures(exists<s: Mapping<Int, Int>>
+                    (forall<i: Int, j: Int> 0 <= i && i < j && j < (^self)@.len() ==> s.get(i) < s.get(j)) &&
+                    (forall<i: Int> 0 <= i && i < (^self)@.len() ==>
+                        0 <= s.get(i) && s.get(i) < self@.len() && (^self)[i] == self[s.get(i)] &&
+                        crate::std::iter::filter::returns(f, &self[s.get(i)], true)) &&
+                    forall<j: Int> 0 <= j && j < self@.len() ==>
+                        (exists<i: Int> 0 <= i && i < (^self)@.len() && s.get(i) == j) ||
+                        crate::std::iter::filter::returns(f, &self[j], false) && self[j].resolve())]
+                fn retain<F>(&mut self, f: F) where F: FnMut(&T) -> bool;
+
+                #[pure]
+                #[requires(crate::std::ops::start_index(range) <= crate::std::ops::end_index(range, self@.len()))]
+                #[requires(crate::std::ops::end_index(range, self@.len()) <= self@.len())]
+                #[ensures(result@ == self@.subsequence(
+                    crate::std::ops::start_index(range), crate::std::ops::end_index(range, self@.len())))]
+                #[ensures((^self)@ == self@.subsequence(0, crate::std::ops::start_index(range))
+                    .concat(self@.subsequence(crate::std::ops::end_index(range, self@.len()), self@.len())))]
+                fn drain<R>(&mut self, range: R) -> Drain<'_, T, A> where R: RangeBounds<usize>;
+
+                #[terminates] // can OOM
+                #[requires(at@ <= self@.len())]
+                #[ensures(result@ == self@.subsequence(at@, self@.len()))]
+                #[ensures((^self)@ == self@.subsequence(0, at@))]
+                fn split_off(&mut self, at: usize) -> Self where A: Clone;
+
+                #[terminates] // can OOM
+                #[ensures((^self)@ == self@.concat(other@))]
+                #[ensures((^other)@.len() == 0)]
+                fn append(&mut self, other: &mut Self);
+            }
+
+            impl<T: Clone, A: Allocator> Vec<T, A> {
+                #[ensures((^self)@ == self@.concat(other@))]
+                fn extend_from_slice(&mut self, other: &[T]);
+
+                #[ensures((^self)@.len() == new_len@)]
+                #[ensures(forall<i: Int> 0 <= i && i < new_len@ && i < self@.len() ==> (^self)[i] == self[i])]
+                #[ensures(forall<i: Int> self@.len() <= i && i < new_len@ ==> (^self)[i] == value)]
+                #[ensures(forall<i: Int> new_len@ <= i && i < self@.len() ==> self[i].resolve())]
+                fn resize(&mut self, new_len: usize, value: T);
+            }
+
+            impl<T: PartialEq + DeepModel, A: Allocator> Vec<T, A> {
+                #[ensures(forall<i: Int> 0 <= i && i < (^self)@.len() - 1 ==>
+                    (^self).deep_model()[i] != (^self).deep_model()[i + 1])]
+                #[ensures(exists<s: Mapping<Int, Int>>
+                    (forall<i: Int, j: Int> 0 <= i && i < j && j < (^self)@.len() ==> s.get(i) < s.get(j)) &&
+                    (forall<i: Int> 0 <= i && i < (^self)@.len() ==>
+                        0 <= s.get(i) && s.get(i) < self@.len() && (^self)[i] == self[s.get(i)]) &&
+                    forall<j: Int> 0 <= j && j < self@.len() ==>
+                        exists<i: Int> 0 <= i && i < (^self)@.len() && s.get(i) <= j &&
+                            (i + 1 < (^self)@.len() ==> j < s.get(i + 1)) &&
+                            self.deep_model()[j] == (^self).deep_model()[i])]
+                fn dedup(&mut self);
             }
 
             impl<T, A : Allocator> Extend<T> for Vec<T, A> {
@@ -268,6 +339,98 @@ impl<T, A: Allocator> Iterator for std::vec::IntoIter<T, A> {
     fn produces_trans(a: Self, ab: Seq<T>, b: Self, bc: Seq<T>, c: Self) {}
 }
 
+impl<T, A: Allocator> DoubleEndedIterator for std::vec::IntoIter<T, A> {
+    #[predicate]
+    #[open]
+    fn produces_back(self, visited: Seq<T>, rhs: Self) -> bool {
+        pearlite! {
+            self@ == rhs@.concat(visited.reverse())
+        }
+    }
+
+    #[law]
+    #[open]
+    #[ensures(self.produces_back(Seq::EMPTY, self))]
+    fn produces_back_refl(self) {}
+
+    #[law]
+    #[open]
+    #[requires(a.produces_back(ab, b))]
+    #[requires(b.produces_back(bc, c))]
+    #[ensures(a.produces_back(ab.concat(bc), c))]
+    fn produces_back_trans(a: Self, ab: Seq<T>, b: Self, bc: Seq<T>, c: Self) {}
+}
+
+impl<'a, T, A: Allocator> ShallowModel for Drain<'a, T, A> {
+    type ShallowModelTy = Seq<T>;
+
+    #[open(self)]
+    #[logic]
+    #[trusted]
+    fn shallow_model(self) -> Self::ShallowModelTy {
+        absurd
+    }
+}
+
+#[trusted]
+impl<'a, T, A: Allocator> Resolve for Drain<'a, T, A> {
+    #[predicate(prophetic)]
+    #[open]
+    fn resolve(self) -> bool {
+        pearlite! { forall<i: Int> 0 <= i && i < self@.len() ==> self@[i].resolve() }
+    }
+}
+
+impl<'a, T, A: Allocator> Iterator for Drain<'a, T, A> {
+    #[predicate(prophetic)]
+    #[open]
+    fn completed(&mut self) -> bool {
+        pearlite! { self.resolve() && self@ == Seq::EMPTY }
+    }
+
+    #[predicate]
+    #[open]
+    fn produces(self, visited: Seq<T>, rhs: Self) -> bool {
+        pearlite! {
+            self@ == visited.concat(rhs@)
+        }
+    }
+
+    #[law]
+    #[open]
+    #[ensures(self.produces(Seq::EMPTY, self))]
+    fn produces_refl(self) {}
+
+    #[law]
+    #[open]
+    #[requires(a.produces(ab, b))]
+    #[requires(b.produces(bc, c))]
+    #[ensures(a.produces(ab.concat(bc), c))]
+    fn produces_trans(a: Self, ab: Seq<T>, b: Self, bc: Seq<T>, c: Self) {}
+}
+
+impl<'a, T, A: Allocator> DoubleEndedIterator for Drain<'a, T, A> {
+    #[predicate]
+    #[open]
+    fn produces_back(self, visited: Seq<T>, rhs: Self) -> bool {
+        pearlite! {
+            self@ == rhs@.concat(visited.reverse())
+        }
+    }
+
+    #[law]
+    #[open]
+    #[ensures(self.produces_back(Seq::EMPTY, self))]
+    fn produces_back_refl(self) {}
+
+    #[law]
+    #[open]
+    #[requires(a.produces_back(ab, b))]
+    #[requires(b.produces_back(bc, c))]
+    #[ensures(a.produces_back(ab.concat(bc), c))]
+    fn produces_back_trans(a: Self, ab: Seq<T>, b: Self, bc: Seq<T>, c: Self) {}
+}
+
 impl<T> FromIterator<T> for Vec<T> {
     #[predicate]
     #[open]
@@ -29,6 +29,12 @@ pub fn forall<T, F: Fn(T) -> bool>(_: F) -> bool {
     panic!()
 }
 
+#[creusot::no_translate]
+#[rustc_diagnostic_item = "trigger"]
+pub fn trigger<T>(_: T, _: bool) -> bool {
+    panic!()
+}
+
 #[creusot::no_translate]
 #[rustc_diagnostic_item = "implication"]
 pub fn implication(_: bool, _: bool) -> bool {
@@ -24,6 +24,7 @@ impl<T: ?Sized> MakeSized for T {
 #[requires(false)]
 #[ensures(false)]
 #[variant(0)]
+#[rustc_diagnostic_item = "unreachable_logic"]
 pub fn unreachable<T>() -> T {
     unreachable()
 }
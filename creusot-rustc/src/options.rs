@@ -10,14 +10,17 @@ fn why3_command(
     path: PathBuf,
     config_file: PathBuf,
     cmd: CreusotSubCommand,
-) -> options::Why3Command {
-    let CreusotSubCommand::Why3 { command, args, .. } = cmd;
+) -> Result<options::Why3Command, String> {
+    let CreusotSubCommand::Why3 { command, report, args, .. } = cmd;
     let sub = match command {
         Why3SubCommand::Prove => options::Why3Sub::Prove,
         Why3SubCommand::Ide => options::Why3Sub::Ide,
         Why3SubCommand::Replay => options::Why3Sub::Replay,
     };
-    options::Why3Command { path, config_file, sub, args }
+    if report.is_some() && !matches!(sub, options::Why3Sub::Prove) {
+        return Err("--report is only supported by `why3 prove`".into());
+    }
+    Ok(options::Why3Command { path, config_file, sub, args, report })
 }
 impl CreusotArgsExt for CreusotArgs {
     fn to_options(self) -> Result<Options, String> {
@@ -48,6 +51,11 @@ impl CreusotArgsExt for CreusotArgs {
                 }
             }?;
 
+        let why3_cmd = self
+            .subcommand
+            .map(|cmd| why3_command(self.why3_path, self.why3_config_file, cmd))
+            .transpose()?;
+
         Ok(Options {
             extern_paths,
             metadata_path,
@@ -58,9 +66,7 @@ impl CreusotArgsExt for CreusotArgs {
             span_mode,
             match_str: self.options.focus_on,
             simple_triggers: self.options.simple_triggers,
-            why3_cmd: self
-                .subcommand
-                .map(|cmd| why3_command(self.why3_path, self.why3_config_file, cmd)),
+            why3_cmd,
         })
     }
 }
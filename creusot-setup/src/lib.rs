@@ -148,6 +148,7 @@ pub fn status() -> anyhow::Result<()> {
 pub struct CreusotFlags {
     pub why3_path: PathBuf,
     pub why3_config: PathBuf,
+    pub provers_parallelism: usize,
 }
 
 /// compute the flags to pass to creusot-rustc.
@@ -178,11 +179,37 @@ pub fn status_for_creusot() -> anyhow::Result<CreusotFlags> {
             Ok(CreusotFlags {
                 why3_path: cfg.why3.path.to_path_buf(),
                 why3_config: paths.why3_config_file,
+                provers_parallelism: cfg.provers_parallelism,
             })
         }
     }
 }
 
+/// The provers of the installation, as (name, version) pairs identifying them in Why3, in the
+/// order in which they should be tried. Provers whose version cannot be detected are skipped.
+pub fn provers() -> anyhow::Result<Vec<(&'static str, String)>> {
+    let paths = get_config_paths()?;
+    let cfg = Config::read_from_file(&paths.config_file).map_err(|err| anyhow!("{err}"))?;
+    let managed_path = |bin: ManagedBinary, tool: &ManagedTool| match tool {
+        ManagedTool::Builtin { .. } => paths.bin_subdir.join(bin.bin.binary_name),
+        ManagedTool::External(tool) => tool.path.clone(),
+    };
+    let bins = [
+        (ALTERGO, cfg.altergo.path.clone()),
+        (Z3.bin, managed_path(Z3, &cfg.z3)),
+        (CVC5.bin, managed_path(CVC5, &cfg.cvc5)),
+        (CVC4.bin, managed_path(CVC4, &cfg.cvc4)),
+    ];
+    let provers = bins.into_iter().filter_map(|(bin, path)| {
+        let version = match bin.detect_version(&path) {
+            DetectedVersion::Good => bin.version.to_owned(),
+            DetectedVersion::Bad(version) => version?,
+        };
+        Some((bin.display_name, version))
+    });
+    Ok(provers.collect())
+}
+
 pub struct ExternalFlag {
     pub check_version: bool,
 }
@@ -2,7 +2,7 @@ use indexmap::{IndexMap, IndexSet};
 use rustc_hir::{def::DefKind, def_id::DefId};
 use rustc_middle::ty::{AliasTy, GenericParamDef, GenericParamDefKind, TyCtxt};
 use rustc_span::{RealFileName, Span, DUMMY_SP};
-use why3::declaration::{Decl, TyDecl};
+use why3::declaration::{Constant, Decl, TyDecl, Use};
 
 use crate::{
     backend::interface::interface_for,
@@ -15,7 +15,11 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use crate::{options::SpanMode, run_why3::SpanMap};
+use crate::{
+    options::SpanMode,
+    run_why3::{LocalMap, SpanMap},
+    translation::fmir::LocalDecls,
+};
 pub(crate) use clone_map::*;
 
 use self::{
@@ -59,6 +63,7 @@ pub struct Why3Generator<'tcx> {
     translated_items: IndexSet<TransId>,
     in_translation: Vec<IndexSet<TransId>>,
     pub(crate) span_map: SpanMap,
+    pub(crate) local_map: LocalMap<'tcx>,
 }
 
 impl<'tcx> Deref for Why3Generator<'tcx> {
@@ -85,6 +90,7 @@ impl<'tcx> Why3Generator<'tcx> {
             translated_items: Default::default(),
             in_translation: Default::default(),
             span_map: Default::default(),
+            local_map: Default::default(),
         }
     }
 
@@ -355,6 +361,11 @@ impl<'tcx> Why3Generator<'tcx> {
         }
     }
 
+    /// Remember the locals of a program function, so that counterexamples can refer to them.
+    pub(crate) fn record_locals(&mut self, def_id: DefId, locals: &LocalDecls<'tcx>) {
+        self.local_map.record(&self.ctx.opts, def_id, locals)
+    }
+
     pub(crate) fn span_attr(&mut self, span: Span) -> Option<why3::declaration::Attribute> {
         if span.is_dummy() {
             return None;
@@ -430,25 +441,34 @@ pub(crate) fn closure_generic_decls(
 pub(crate) fn all_generic_decls_for(tcx: TyCtxt, def_id: DefId) -> impl Iterator<Item = Decl> + '_ {
     let generics = tcx.generics_of(def_id);
 
-    generic_decls((0..generics.count()).map(move |i| generics.param_at(i, tcx)))
+    generic_decls(tcx, (0..generics.count()).map(move |i| generics.param_at(i, tcx)))
 }
 
 pub(crate) fn own_generic_decls_for(tcx: TyCtxt, def_id: DefId) -> impl Iterator<Item = Decl> + '_ {
     let generics = tcx.generics_of(def_id);
-    generic_decls(generics.own_params.iter())
+    generic_decls(tcx, generics.own_params.iter())
 }
 
 fn generic_decls<'tcx, I: Iterator<Item = &'tcx GenericParamDef> + 'tcx>(
+    tcx: TyCtxt<'tcx>,
     it: I,
 ) -> impl Iterator<Item = Decl> + 'tcx {
-    it.filter_map(|param| {
-        if let GenericParamDefKind::Type { .. } = param.kind {
-            Some(Decl::TyDecl(TyDecl::Opaque {
-                ty_name: (&*param.name.as_str().to_lowercase()).into(),
-                ty_params: vec![],
-            }))
-        } else {
-            None
+    it.flat_map(move |param| match param.kind {
+        GenericParamDefKind::Type { .. } => vec![Decl::TyDecl(TyDecl::Opaque {
+            ty_name: (&*param.name.as_str().to_lowercase()).into(),
+            ty_params: vec![],
+        })],
+        // Const generic parameters are abstract constants of the module
+        GenericParamDefKind::Const { .. } => {
+            let (modl, type_) = ty::const_param_ty(tcx, param);
+            let use_decl =
+                modl.map(|m| Decl::UseDecl(Use { name: m.qname(), as_: None, export: false }));
+            let name = util::ident_of(param.name);
+            use_decl
+                .into_iter()
+                .chain([Decl::ConstantDecl(Constant { name, type_, body: None })])
+                .collect()
         }
+        GenericParamDefKind::Lifetime => vec![],
     })
 }
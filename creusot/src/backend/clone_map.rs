@@ -51,6 +51,7 @@ pub enum PreludeModule {
     UInt128,
     Usize,
     Char,
+    Str,
     Bool,
     Borrow,
     Slice,
@@ -78,6 +79,7 @@ impl PreludeModule {
             PreludeModule::UInt64 => QName::from_string("prelude.prelude.UInt64").unwrap(),
             PreludeModule::UInt128 => QName::from_string("prelude.prelude.UInt128").unwrap(),
             PreludeModule::Char => QName::from_string("prelude.prelude.Char").unwrap(),
+            PreludeModule::Str => QName::from_string("prelude.prelude.Str").unwrap(),
             PreludeModule::Opaque => QName::from_string("prelude.prelude.Opaque").unwrap(),
             PreludeModule::Ref => QName::from_string("Ref").unwrap(),
             PreludeModule::Seq => QName::from_string("prelude.prelude.Seq").unwrap(),
@@ -114,10 +116,14 @@ pub(crate) trait Namer<'tcx> {
     fn constructor(&mut self, def_id: DefId, subst: GenericArgsRef<'tcx>) -> QName {
         let type_id = match self.tcx().def_kind(def_id) {
             DefKind::Closure | DefKind::Struct | DefKind::Enum | DefKind::Union => def_id,
-            DefKind::Variant => self.tcx().parent(def_id),
+            // The constructor of a union for one of its fields
+            DefKind::Variant | DefKind::Field => self.tcx().parent(def_id),
             _ => unreachable!("Not a type or constructor"),
         };
-        let mut name = item_name(self.tcx(), def_id, Namespace::ValueNS);
+        let mut name = match self.tcx().def_kind(def_id) {
+            DefKind::Field => format!("C_{}", self.tcx().item_name(def_id)).into(),
+            _ => item_name(self.tcx(), def_id, Namespace::ValueNS),
+        };
         name.capitalize();
         let mut qname = self.ty(type_id, subst);
         qname.name = name.into();
@@ -178,6 +184,11 @@ pub(crate) trait Namer<'tcx> {
                 qname.name = DepNode::new(tcx, (def_id, subst)).base_ident(tcx).to_string().into();
                 qname
             }
+            DefKind::Field => {
+                let mut qname = self.insert(DepNode::new(tcx, (tcx.parent(def_id), subst))).qname();
+                qname.name = tcx.item_name(def_id).to_string().into();
+                qname
+            }
             DefKind::Closure | DefKind::Struct | DefKind::Union => {
                 let mut node = DepNode::new(tcx, (def_id, subst));
 
@@ -1,7 +1,7 @@
 use crate::{
     backend::{
         dependency::{Dependency, ExtendedId},
-        logic::{lower_logical_defn, lower_pure_defn, sigs, spec_axiom},
+        logic::{lower_inductive_defn, lower_logical_defn, lower_pure_defn, sigs, spec_axiom},
         program,
         signature::sig_to_why3,
         term::lower_pure,
@@ -16,7 +16,7 @@ use crate::{
     util::{self, get_builtin, PreSignature},
 };
 use indexmap::IndexSet;
-use rustc_middle::ty::{self, Const, EarlyBinder, ParamEnv, Ty, TyCtxt, TyKind, TypeFoldable};
+use rustc_middle::ty::{EarlyBinder, ParamEnv, Ty, TyCtxt, TyKind, TypeFoldable};
 use rustc_span::{Span, Symbol};
 use why3::{
     declaration::{Attribute, Axiom, Constant, Decl, LetKind, Signature, Use, ValDecl},
@@ -189,7 +189,9 @@ impl<'tcx> SymbolElaborator<'tcx> {
             let Some(term) = term(ctx, item) else { return Vec::new() };
             let mut term = EarlyBinder::bind(term).instantiate(ctx.tcx, subst);
             normalize(ctx.tcx, param_env, &mut term);
-            if is_accessor {
+            if let Some(ind_kind) = util::inductive_kind(ctx.tcx, def_id) {
+                lower_inductive_defn(ctx, names, sig, ind_kind, term)
+            } else if is_accessor {
                 lower_logical_defn(ctx, names, sig, kind, term)
             } else if item.is_hacked() {
                 // TODO: Clean this up and merge with previous branches
@@ -198,15 +200,8 @@ impl<'tcx> SymbolElaborator<'tcx> {
                 lower_logical_defn(ctx, names, sig, kind, term)
             }
         } else if util::item_type(ctx.tcx, def_id) == ItemType::Constant {
-            let uneval = ty::UnevaluatedConst::new(def_id, subst);
-            let constant = Const::new(
-                ctx.tcx,
-                ty::ConstKind::Unevaluated(uneval),
-                ctx.type_of(def_id).instantiate_identity(),
-            );
-
             let span = ctx.def_span(def_id);
-            let res = crate::constant::from_ty_const(&mut ctx.ctx, constant, param_env, span);
+            let res = crate::constant::from_item(&mut ctx.ctx, def_id, subst, param_env, span);
 
             let res = lower_pure(ctx, names, &res);
             vec![Decl::ConstantDecl(Constant {
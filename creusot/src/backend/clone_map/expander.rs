@@ -12,7 +12,7 @@ use petgraph::Direction;
 use rustc_hir::def_id::DefId;
 use rustc_middle::{
     mir::Mutability,
-    ty::{AliasTyKind, GenericArgsRef, ParamEnv, Ty, TyKind},
+    ty::{AliasTyKind, EarlyBinder, GenericArgsRef, ParamEnv, Ty, TyKind},
 };
 
 use super::*;
@@ -121,7 +121,7 @@ impl<'a, 'tcx> Expander<'a, 'tcx> {
     }
 
     fn expand_projections(&mut self, ctx: &mut Why3Generator<'tcx>, key: DepNode<'tcx>) {
-        let Some((id, _)) = key.did() else {
+        let Some((id, subst)) = key.did() else {
             return;
         };
         let ItemType::Type = util::item_type(ctx.tcx, id) else {
@@ -131,8 +131,10 @@ impl<'a, 'tcx> Expander<'a, 'tcx> {
         let key_public = self.clone_graph.info(key).level;
 
         for p in ctx.projections_in_ty(id).to_owned() {
-            let node = self
-                .resolve_dep(ctx, DepNode::new(ctx.tcx, (p.def_id, p.args)).subst(ctx.tcx, key));
+            // Instantiate with the arguments of the type, even when `key` is its invariant
+            let node = EarlyBinder::bind(DepNode::new(ctx.tcx, (p.def_id, p.args)))
+                .instantiate(ctx.tcx, subst);
+            let node = self.resolve_dep(ctx, node);
 
             let is_type = self
                 .self_did()
@@ -1,7 +1,7 @@
 use rustc_hir::def_id::DefId;
-use rustc_middle::ty::{self, Const, GenericArgs};
+use rustc_middle::ty::GenericArgs;
 
-use crate::{ctx::TranslatedItem, translation::constant::from_ty_const};
+use crate::{ctx::TranslatedItem, translation::constant::from_item};
 
 use super::{
     clone_map::{CloneSummary, Dependencies},
@@ -16,16 +16,9 @@ impl<'tcx> Why3Generator<'tcx> {
         def_id: DefId,
     ) -> (TranslatedItem, CloneSummary<'tcx>) {
         let subst = GenericArgs::identity_for_item(self.tcx, def_id);
-        let uneval = ty::UnevaluatedConst::new(def_id, subst);
-        let constant = Const::new(
-            self.tcx,
-            ty::ConstKind::Unevaluated(uneval),
-            self.type_of(def_id).instantiate_identity(),
-        );
-
         let param_env = self.param_env(def_id);
         let span = self.def_span(def_id);
-        let res = from_ty_const(&mut self.ctx, constant, param_env, span);
+        let res = from_item(&mut self.ctx, def_id, subst, param_env, span);
         let mut names = Dependencies::new(self.tcx, [def_id]);
         let _ = lower_pure(self, &mut names, &res);
 
@@ -2,7 +2,9 @@ use heck::ToSnakeCase;
 use rustc_ast_ir::visit::VisitorResult;
 use rustc_hir::{def::DefKind, def_id::DefId};
 use rustc_macros::{TypeFoldable, TypeVisitable};
-use rustc_middle::ty::{EarlyBinder, GenericArgs, GenericArgsRef, ParamEnv, Ty, TyCtxt, TyKind};
+use rustc_middle::ty::{
+    EarlyBinder, GenericArgs, GenericArgsRef, Mutability, ParamEnv, Ty, TyCtxt, TyKind,
+};
 use rustc_span::Symbol;
 use rustc_type_ir::{fold::TypeFoldable, visit::TypeVisitable, AliasTyKind, Interner};
 
@@ -266,6 +268,37 @@ pub(crate) fn closure_hack<'tcx>(
         if let TyKind::Closure(id, csubst) = self_ty.kind() {
             return (hacked, *id, csubst);
         }
+
+        // Trait objects, and pointers to them, use the specification of the trait object
+        let (object, item) = match (self_ty.kind(), hacked.unwrap()) {
+            (TyKind::Dynamic(..), _) => (self_ty, tcx.associated_item(def_id).trait_item_def_id),
+            (TyKind::Ref(_, ty, Mutability::Not), ExtendedId::PostconditionOnce) => (
+                *ty,
+                tcx.get_diagnostic_item(Symbol::intern("fn_spec")).and_then(|tr| {
+                    tcx.associated_items(tr)
+                        .filter_by_name_unhygienic(Symbol::intern("postcondition"))
+                        .next()
+                        .map(|item| item.def_id)
+                }),
+            ),
+            (
+                TyKind::Ref(_, ty, Mutability::Not),
+                ExtendedId::Precondition | ExtendedId::Postcondition,
+            ) => (*ty, tcx.associated_item(def_id).trait_item_def_id),
+            (TyKind::Adt(def, args), _) if def.is_box() => {
+                (args.type_at(0), tcx.associated_item(def_id).trait_item_def_id)
+            }
+            _ => (self_ty, None),
+        };
+        if object.is_trait()
+            && let Some(item) = item
+        {
+            let args = tcx.mk_args(&[object.into(), subst[0]]);
+            if let Some((id, subst)) = traits::resolve_opt(tcx, ParamEnv::reveal_all(), item, args)
+            {
+                return (None, id, subst);
+            }
+        }
     };
 
     if tcx.is_diagnostic_item(Symbol::intern("creusot_resolve_default"), def_id)
@@ -1,7 +1,7 @@
 use crate::{
     backend::all_generic_decls_for,
     ctx::*,
-    translation::pearlite::Term,
+    translation::pearlite::{self, Term, TermKind},
     util::{self, get_builtin},
 };
 use rustc_hir::def_id::DefId;
@@ -157,9 +157,54 @@ fn body_decls<'tcx, N: Namer<'tcx>>(
 
     let sig = signature_of(ctx, names, def_id);
 
+    if let Some(kind) = util::inductive_kind(ctx.tcx, def_id) {
+        return lower_inductive_defn(ctx, names, sig, kind, term);
+    }
+
     lower_logical_defn(ctx, names, sig, util::item_type(ctx.tcx, def_id).let_kind(), term)
 }
 
+// Each disjunct `D` of the body of an inductive predicate `p` becomes the introduction rule
+// `forall args. D -> p args`.
+pub(crate) fn lower_inductive_defn<'tcx, N: Namer<'tcx>>(
+    ctx: &mut Why3Generator<'tcx>,
+    names: &mut N,
+    sig: Signature,
+    kind: IndKind,
+    body: Term<'tcx>,
+) -> Vec<Decl> {
+    let (mut sig, val_sig) = sigs(ctx, sig);
+    sig.retty = None;
+    sig.args = binders_to_args(ctx, sig.args).1;
+
+    let call = function_call(&sig);
+    let args: Vec<_> = sig.args.iter().cloned().flat_map(|b| b.var_type_pairs()).collect();
+
+    let mut disjuncts = Vec::new();
+    split_disjuncts(body, &mut disjuncts);
+    let rules = disjuncts
+        .into_iter()
+        .enumerate()
+        .map(|(i, disjunct)| {
+            let rule = lower_pure(ctx, names, &disjunct).implies(call.clone());
+            let rule = if args.is_empty() { rule } else { Exp::forall(args.clone(), rule) };
+            (format!("{}_rule{i}", &*sig.name).into(), rule)
+        })
+        .collect();
+
+    vec![Decl::IndDecl(IndDecl { kind, sig, rules }), program::val(ctx, val_sig)]
+}
+
+fn split_disjuncts<'tcx>(term: Term<'tcx>, disjuncts: &mut Vec<Term<'tcx>>) {
+    match term.kind {
+        TermKind::Binary { op: pearlite::BinOp::Or, lhs, rhs } => {
+            split_disjuncts(*lhs, disjuncts);
+            split_disjuncts(*rhs, disjuncts);
+        }
+        kind => disjuncts.push(Term { kind, ..term }),
+    }
+}
+
 pub(crate) fn lower_logical_defn<'tcx, N: Namer<'tcx>>(
     ctx: &mut Why3Generator<'tcx>,
     names: &mut N,
@@ -11,7 +11,10 @@ use why3::{declaration::Signature, ty::Type, Exp, Ident, QName};
 use crate::{
     backend::{
         signature::{sig_to_why3, signature_of},
-        term::{binop_to_binop, lower_literal, lower_pure},
+        term::{
+            binop_to_binop, lower_bitwise, lower_float_binop, lower_literal, lower_prelude_unop,
+            lower_pure,
+        },
         ty::{is_int, translate_ty},
         Namer as _, Why3Generator,
     },
@@ -122,14 +125,14 @@ fn is_structurally_recursive(ctx: &mut Why3Generator<'_>, self_id: DefId, t: &Te
                         }
                     }
                 }
-                TermKind::Exists { binder, body } => {
+                TermKind::Exists { binder, body, .. } => {
                     let old_smaller = self.smaller_than.clone();
                     self.smaller_than.remove(&binder.0);
                     self.visit_term(body);
                     self.smaller_than = old_smaller;
                 }
 
-                TermKind::Forall { binder, body } => {
+                TermKind::Forall { binder, body, .. } => {
                     let old_smaller = self.smaller_than.clone();
                     self.smaller_than.remove(&binder.0);
                     self.visit_term(body);
@@ -287,9 +290,39 @@ impl<'a, 'tcx> VCGen<'a, 'tcx> {
                 // BinOp::Or => self.build_vc(lhs, &|lhs| {
                 //     Ok(Exp::if_(lhs, k(Exp::mk_true())?, self.build_vc(rhs, k)?,))
                 // }),
+                BinOp::Add | BinOp::Sub | BinOp::Mul | BinOp::Div | BinOp::Rem
+                    if lhs.ty.is_floating_point() =>
+                {
+                    self.build_vc(lhs, &|lhs_exp| {
+                        self.build_vc(rhs, &|rhs_exp| {
+                            let float = lower_float_binop(
+                                *self.names.borrow_mut(),
+                                *op,
+                                lhs.ty,
+                                lhs_exp.clone(),
+                                rhs_exp,
+                            );
+                            k(float)
+                        })
+                    })
+                }
                 BinOp::Div => self.build_vc(&lhs, &|lhs| {
                     self.build_vc(rhs, &|rhs| k(Exp::var("div").app(vec![lhs.clone(), rhs])))
                 }),
+                BinOp::BitAnd | BinOp::BitOr | BinOp::BitXor | BinOp::Shl | BinOp::Shr => self
+                    .build_vc(lhs, &|lhs_exp| {
+                        self.build_vc(rhs, &|rhs_exp| {
+                            let bitwise = lower_bitwise(
+                                *self.names.borrow_mut(),
+                                *op,
+                                lhs.ty,
+                                rhs.ty,
+                                lhs_exp.clone(),
+                                rhs_exp,
+                            );
+                            k(bitwise)
+                        })
+                    }),
                 _ => self.build_vc(&lhs, &|lhs| {
                     self.build_vc(rhs, &|rhs| {
                         k(Exp::BinaryOp(binop_to_binop(*op), Box::new(lhs.clone()), Box::new(rhs)))
@@ -297,6 +330,14 @@ impl<'a, 'tcx> VCGen<'a, 'tcx> {
                 }),
             },
             // VC(OP A, Q) = VC(A |a| Q(OP a))
+            TermKind::Unary { op, arg }
+                if arg.ty.is_floating_point()
+                    || (arg.ty.is_integral() && matches!(op, UnOp::Not)) =>
+            {
+                self.build_vc(arg, &|arg_exp| {
+                    k(lower_prelude_unop(*self.names.borrow_mut(), op, arg.ty, arg_exp))
+                })
+            }
             TermKind::Unary { op, arg } => self.build_vc(arg, &|arg| {
                 let op = match op {
                     UnOp::Not => why3::exp::UnOp::Not,
@@ -308,7 +349,7 @@ impl<'a, 'tcx> VCGen<'a, 'tcx> {
             // // the dual rule should be the one below but that seems weird...
             // // VC(forall<x> P(x), Q) => (exists<x> VC(P, false)) \/ Q(forall<x>P(x))
             // // Instead, I think the rule should just be the same as for the existential quantifiers?
-            TermKind::Forall { binder, body } => {
+            TermKind::Forall { binder, body, .. } => {
                 let forall_pre = self.build_vc(body, &|_| Ok(Exp::mk_true()))?;
                 let ty = self.ty(binder.1);
 
@@ -317,7 +358,7 @@ impl<'a, 'tcx> VCGen<'a, 'tcx> {
                 Ok(forall_pre.log_and(k(forall_pure)?))
             }
             // // VC(exists<x> P(x), Q) => (forall<x> VC(P, true)) /\ Q(exists<x>P(x))
-            TermKind::Exists { binder, body } => {
+            TermKind::Exists { binder, body, .. } => {
                 let exists_pre = self.build_vc(body, &|_| Ok(Exp::mk_true()))?;
                 let ty = self.ty(binder.1);
 
@@ -333,7 +374,7 @@ impl<'a, 'tcx> VCGen<'a, 'tcx> {
                     let TyKind::Adt(_, subst) = t.creusot_ty().kind() else { unreachable!() };
 
                     let ctor = self.names.borrow_mut().constructor(
-                        self.ctx.borrow().adt_def(typ).variants()[*variant].def_id,
+                        util::constructor_did(self.ctx.borrow().adt_def(typ), *variant),
                         subst,
                     );
 
@@ -113,7 +113,7 @@ impl<'a, 'tcx> LocalUsage<'a, 'tcx> {
     fn visit_rvalue(&mut self, r: &RValue<'tcx>) {
         match r {
             RValue::Ghost(t) => self.visit_term(t),
-            RValue::Borrow(_, p) => {
+            RValue::Borrow(_, p) | RValue::Ptr(p) => {
                 self.read_place(p);
                 self.read_place(p)
             }
@@ -272,7 +272,7 @@ impl<'tcx> SimplePropagator<'tcx> {
             Terminator::Goto(_) => {}
             Terminator::Switch(e, _) => self.visit_operand(e),
             Terminator::Return => {}
-            Terminator::Abort(_) => {}
+            Terminator::Abort(_) | Terminator::Panic(_) => {}
         }
     }
 
@@ -296,7 +296,7 @@ impl<'tcx> SimplePropagator<'tcx> {
     fn visit_rvalue(&mut self, r: &mut RValue<'tcx>) {
         match r {
             RValue::Ghost(t) => self.visit_term(t),
-            RValue::Borrow(_, p) => {
+            RValue::Borrow(_, p) | RValue::Ptr(p) => {
                 assert!(self.prop.get(&p.local).is_none(), "Trying to propagate borrowed variable")
             }
             RValue::Operand(op) => self.visit_operand(op),
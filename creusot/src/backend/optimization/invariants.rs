@@ -56,8 +56,10 @@ pub fn infer_proph_invariants<'tcx>(
             let subst = ctx.mk_args(&[u.ty(tcx, &body.locals).into()]);
             let ty = Ty::new_adt(ctx.tcx, ctx.adt_def(snap_ty), subst);
 
-            body.locals
-                .insert(local, fmir::LocalDecl { span: DUMMY_SP, ty, temp: true, arg: false });
+            body.locals.insert(
+                local,
+                fmir::LocalDecl { span: DUMMY_SP, name: None, ty, temp: true, arg: false },
+            );
 
             let Some(pterm) = place_to_term(u, tcx, &body.locals) else { break };
             for p in &incoming {
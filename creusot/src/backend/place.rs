@@ -3,11 +3,13 @@ use crate::{
     translation::fmir::{self},
     util,
 };
+use rustc_hir::def_id::DefId;
 use rustc_middle::{
     mir::{self, tcx::PlaceTy, ProjectionElem},
-    ty::{self, Ty, TyCtxt, TyKind},
+    ty::{self, AdtDef, FieldDef, Ty, TyCtxt, TyKind},
 };
 use rustc_span::{Span, Symbol};
+use rustc_target::abi::FieldIdx;
 use rustc_type_ir::AliasTyKind;
 use why3::{
     coma::{self, Arg, Expr, Param},
@@ -60,7 +62,7 @@ pub(crate) fn lplace_to_expr<'tcx>(
         (0..n).map(|_| lower.fresh_from("l")).collect()
     };
 
-    for elem in proj {
+    for (depth, elem) in proj.iter().enumerate() {
         match elem {
             Deref => {
                 let mutable = place_ty.ty.is_mutable_ptr();
@@ -75,13 +77,19 @@ pub(crate) fn lplace_to_expr<'tcx>(
                     focus = Exp::Current(Box::new(focus))
                 }
             }
+            // Writing a field of a union makes it active, without reading the union first
+            Field(ix, _) if place_ty.ty.is_union() && depth + 1 == proj.len() => {
+                let TyKind::Adt(def, subst) = place_ty.ty.kind() else { unreachable!() };
+                let field = &def.non_enum_variant().fields[*ix];
+                let constr = Exp::qvar(lower.names.constructor(field.did, subst));
+                let ty = lower.ty(place_ty.ty);
+                constructor = Box::new(|is, t| constructor(is, constr.app(vec![t]).ascribe(ty)));
+            }
             Field(ix, _) => match place_ty.ty.kind() {
                 TyKind::Adt(def, subst) => {
-                    let variant_id = place_ty.variant_index.unwrap_or_else(|| 0u32.into());
-                    let variant = &def.variants()[variant_id];
-                    let acc_name = lower.names.eliminator(variant.def_id, subst);
-                    let fields: Vec<_> = variant
-                        .fields
+                    let (cons_id, fields, ix) = adt_constructor_fields(def, place_ty, *ix);
+                    let acc_name = lower.names.eliminator(cons_id, subst);
+                    let fields: Vec<_> = fields
                         .iter()
                         .map(|f| {
                             Param::Term(
@@ -116,9 +124,9 @@ pub(crate) fn lplace_to_expr<'tcx>(
                         Expr::Symbol(acc_name),
                         params,
                     ));
-                    let constr = Exp::qvar(lower.names.constructor(variant.def_id, subst));
+                    let constr = Exp::qvar(lower.names.constructor(cons_id, subst));
                     let ty = lower.ty(place_ty.ty);
-                    constructor = Box::new(|is, t| {
+                    constructor = Box::new(move |is, t| {
                         let mut fields: Vec<_> =
                             fields.into_iter().map(|f| Exp::var(f.as_term().0.clone())).collect();
                         fields[ix.as_usize()] = t;
@@ -274,11 +282,9 @@ pub(crate) fn rplace_to_expr<'tcx>(
             }
             Field(ix, _) => match place_ty.ty.kind() {
                 TyKind::Adt(def, subst) => {
-                    let variant_id = place_ty.variant_index.unwrap_or_else(|| 0u32.into());
-                    let variant = &def.variants()[variant_id];
-                    let acc_name = lower.names.eliminator(variant.def_id, subst);
-                    let fields: Vec<_> = variant
-                        .fields
+                    let (cons_id, fields, ix) = adt_constructor_fields(def, place_ty, *ix);
+                    let acc_name = lower.names.eliminator(cons_id, subst);
+                    let fields: Vec<_> = fields
                         .iter()
                         .map(|f| {
                             Param::Term(
@@ -377,6 +383,25 @@ pub(crate) fn rplace_to_expr<'tcx>(
     (focus, istmts)
 }
 
+/// The constructor to destructure to access the field `ix` of an ADT place, its fields, and
+/// the position of the accessed field among them.
+///
+/// Unions have a constructor per field, and reading a field through its eliminator checks that
+/// it is the active field.
+fn adt_constructor_fields<'a, 'tcx>(
+    def: &'a AdtDef<'tcx>,
+    place_ty: PlaceTy<'tcx>,
+    ix: FieldIdx,
+) -> (DefId, &'a [FieldDef], FieldIdx) {
+    if def.is_union() {
+        let field = &def.non_enum_variant().fields[ix];
+        (field.did, std::slice::from_ref(field), FieldIdx::ZERO)
+    } else {
+        let variant = &def.variants()[place_ty.variant_index.unwrap_or_else(|| 0u32.into())];
+        (variant.def_id, &variant.fields.raw, ix)
+    }
+}
+
 pub fn projection_ty<'tcx>(
     pty: PlaceTy<'tcx>,
     tcx: TyCtxt<'tcx>,
@@ -17,7 +17,11 @@ use crate::{
     },
     ctx::{BodyId, Dependencies, TranslationCtx},
     fmir::{Body, BorrowKind, Operand},
-    translation::fmir::{self, Block, Branches, LocalDecls, Place, RValue, Statement, Terminator},
+    pearlite::{self, Literal, TermKind},
+    translation::{
+        fmir::{self, Block, Branches, LocalDecls, Place, RValue, Statement, Terminator},
+        traits,
+    },
     util::{self, module_name},
 };
 
@@ -25,7 +29,7 @@ use petgraph::graphmap::DiGraphMap;
 use rustc_hir::{def_id::DefId, Unsafety};
 use rustc_middle::{
     mir::{self, BasicBlock, BinOp, ProjectionElem, UnOp, START_BLOCK},
-    ty::{AdtDef, GenericArgsRef, Ty, TyKind},
+    ty::{AdtDef, AssocKind, ClosureKind, FnSig, GenericArgsRef, Mutability, ParamEnv, Ty, TyKind},
 };
 use rustc_span::{Span, Symbol, DUMMY_SP};
 use rustc_target::abi::VariantIdx;
@@ -206,6 +210,7 @@ pub fn val<'tcx>(_: &mut Why3Generator<'tcx>, sig: Signature) -> Decl {
         .into_iter()
         .flat_map(|b| b.var_type_pairs())
         .map(|(v, ty)| Param::Term(v, ty))
+        .chain(panic_param(&sig.contract))
         .chain([Param::Cont(
             "return".into(),
             Vec::new(),
@@ -215,6 +220,7 @@ pub fn val<'tcx>(_: &mut Why3Generator<'tcx>, sig: Signature) -> Decl {
 
     use coma::*;
     let mut body = Expr::Any;
+    let panics = sig.contract.panics_disj();
 
     body = sig.contract.requires.into_iter().fold(body, |acc, ensures| {
         Expr::Assert(
@@ -225,25 +231,42 @@ pub fn val<'tcx>(_: &mut Why3Generator<'tcx>, sig: Signature) -> Decl {
 
     let mut postcond = Expr::Symbol("return".into()).app(vec![Arg::Term(Exp::var("result"))]);
     postcond = Expr::BlackBox(Box::new(postcond));
+    if let Some(panics) = &panics {
+        postcond = Expr::Assert(Box::new(panics.clone().not()), Box::new(postcond));
+    }
     postcond = sig
         .contract
         .ensures
         .into_iter()
         .fold(postcond, |acc, ensures| Expr::Assert(Box::new(ensures), Box::new(acc)));
 
-    let body = Expr::Defn(
-        Box::new(body),
-        false,
-        vec![Defn {
-            name: "return".into(),
-            writes: Vec::new(),
-            params: vec![Param::Term("result".into(), sig.retty.clone().unwrap())],
-            body: postcond,
-        }],
-    );
+    let mut handlers = vec![Defn {
+        name: "return".into(),
+        writes: Vec::new(),
+        params: vec![Param::Term("result".into(), sig.retty.clone().unwrap())],
+        body: postcond,
+    }];
+    if sig.contract.may_panic {
+        handlers.push(panic_handler(panics));
+    }
+
+    let body = Expr::Defn(Box::new(body), false, handlers);
     why3::declaration::Decl::Coma(Defn { name: sig.name, writes: Vec::new(), params, body })
 }
 
+/// The continuation through which a function whose contract allows it to panic exits when it does.
+fn panic_param(contract: &Contract) -> Option<Param> {
+    contract.may_panic.then(|| Param::Cont("panic'".into(), Vec::new(), Vec::new()))
+}
+
+fn panic_handler(panics: Option<Exp>) -> Defn {
+    let mut body = Expr::BlackBox(Box::new(Expr::Symbol("panic'".into())));
+    if let Some(panics) = panics {
+        body = Expr::Assert(Box::new(panics), Box::new(body));
+    }
+    Defn { name: "panic'".into(), writes: Vec::new(), params: Vec::new(), body }
+}
+
 // TODO: move to a more "central" location
 pub(crate) fn node_graph(x: &Body) -> petgraph::graphmap::DiGraphMap<BasicBlock, ()> {
     let mut graph = DiGraphMap::default();
@@ -263,6 +286,9 @@ pub fn to_why<'tcx>(
     body_id: BodyId,
 ) -> Decl {
     let mut body = ctx.fmir_body(body_id).unwrap().clone();
+    if body_id.promoted.is_none() {
+        ctx.record_locals(body_id.def_id(), &body.locals);
+    }
 
     let usage = optimization::gather_usage(&body);
     optimization::simplify_fmir(usage, &mut body);
@@ -270,8 +296,23 @@ pub fn to_why<'tcx>(
     let wto = weak_topological_order(&node_graph(&body), START_BLOCK);
     infer_proph_invariants(ctx, body_id.def_id(), &mut body);
 
+    let can_panic = body_id.promoted.is_none() && ctx.sig(body_id.def_id()).contract.can_panic();
+
+    // The value of each loop variant at the start of the current iteration of its loop
+    let variant_vars: Vec<_> = body
+        .blocks
+        .iter()
+        .filter_map(|(bb, block)| {
+            let variant = block.variant.as_ref()?;
+            let ty = ty::translate_ty(ctx, names, variant.span, variant.ty);
+            names.import_prelude_module(PreludeModule::Intrinsic);
+            let init = Exp::var("any_l").app_to(Exp::Tuple(Vec::new()));
+            Some(coma::Var(variant_ident(*bb), ty, init, coma::IsRef::Ref))
+        })
+        .collect();
+
     let blocks: Vec<Defn> =
-        wto.into_iter().map(|c| component_to_defn(&mut body, ctx, names, c)).collect();
+        wto.into_iter().map(|c| component_to_defn(&mut body, ctx, names, can_panic, c)).collect();
     let ret = body.locals.first().map(|(_, decl)| decl.clone());
 
     let vars: Vec<_> = body
@@ -288,6 +329,7 @@ pub fn to_why<'tcx>(
             };
             coma::Var(Ident::build(id.as_str()), ty.clone(), init, coma::IsRef::Ref)
         })
+        .chain(variant_vars)
         .collect();
 
     let sig = if body_id.promoted.is_none() {
@@ -305,11 +347,21 @@ pub fn to_why<'tcx>(
     };
     let mut body = Expr::Defn(Box::new(Expr::Symbol("bb0".into())), true, blocks);
 
+    let panic_cont = panic_param(&sig.contract);
+    let panics = sig.contract.panics_disj();
+
     let mut postcond = Expr::Symbol("return".into()).app(vec![Arg::Term(Exp::var("result"))]);
 
     if body_id.promoted.is_none() {
         postcond = Expr::BlackBox(Box::new(postcond));
     }
+    if let Some(panics) = &panics {
+        let no_panic = Exp::Attr(
+            Attribute::Attr("expl:no panic condition".into()),
+            Box::new(panics.clone().not()),
+        );
+        postcond = Expr::Assert(Box::new(no_panic), Box::new(postcond));
+    }
     postcond = sig.contract.ensures.into_iter().fold(postcond, |acc, ensures| {
         Expr::Assert(
             Box::new(Exp::Attr(Attribute::Attr("expl:postcondition".into()), Box::new(ensures))),
@@ -323,16 +375,19 @@ pub fn to_why<'tcx>(
 
     body = Expr::Let(Box::new(body), vars);
 
-    body = Expr::Defn(
-        Box::new(body),
-        false,
-        vec![Defn {
-            name: "return".into(),
-            writes: Vec::new(),
-            params: vec![Param::Term("result".into(), sig.retty.clone().unwrap())],
-            body: postcond,
-        }],
-    );
+    let mut handlers = vec![Defn {
+        name: "return".into(),
+        writes: Vec::new(),
+        params: vec![Param::Term("result".into(), sig.retty.clone().unwrap())],
+        body: postcond,
+    }];
+    if can_panic {
+        let panics = panics.map(|panics| {
+            Exp::Attr(Attribute::Attr("expl:panic condition".into()), Box::new(panics))
+        });
+        handlers.push(panic_handler(panics));
+    }
+    body = Expr::Defn(Box::new(body), false, handlers);
 
     body = sig
         .contract
@@ -345,6 +400,7 @@ pub fn to_why<'tcx>(
         .into_iter()
         .flat_map(|b| b.var_type_pairs())
         .map(|(v, ty)| Param::Term(v, ty))
+        .chain(panic_cont)
         .chain([Param::Cont(
             "return".into(),
             Vec::new(),
@@ -362,10 +418,16 @@ fn component_to_defn<'tcx>(
     body: &mut Body<'tcx>,
     ctx: &mut Why3Generator<'tcx>,
     names: &mut Dependencies<'tcx>,
+    can_panic: bool,
     c: Component<BasicBlock>,
 ) -> coma::Defn {
-    let mut lower =
-        LoweringState { ctx, names, locals: &body.locals, name_supply: Default::default() };
+    let mut lower = LoweringState {
+        ctx,
+        names,
+        locals: &body.locals,
+        can_panic,
+        name_supply: Default::default(),
+    };
     let (head, tl) = match c {
         Component::Vertex(v) => {
             let block = body.blocks.remove(&v).unwrap();
@@ -375,27 +437,72 @@ fn component_to_defn<'tcx>(
     };
 
     let block = body.blocks.remove(&head).unwrap();
+    let decrease =
+        block.variant.as_ref().map(|variant| variant_decrease(&mut lower, head, variant));
     let mut block = block.to_why(&mut lower, head);
 
-    let defns = tl.into_iter().map(|id| component_to_defn(body, ctx, names, id)).collect();
+    let mut defns: Vec<_> =
+        tl.into_iter().map(|id| component_to_defn(body, ctx, names, can_panic, id)).collect();
 
     if !block.body.is_guarded() {
         block.body = Expr::BlackBox(Box::new(block.body));
     }
 
+    let mut head_name = block.name.clone();
+    if let Some(decrease) = decrease {
+        // Inside the loop, jumping to its head goes through a check that the variant decreased
+        head_name = format!("{}'0", &*block.name).into();
+        let check =
+            Expr::Assert(Box::new(decrease), Box::new(Expr::Symbol(head_name.clone().into())));
+        defns.push(Defn::simple(block.name.clone(), check));
+    }
+
     let inner = Expr::Defn(Box::new(block.body), true, defns);
     block.body = Expr::Defn(
-        Box::new(Expr::Symbol(block.name.clone().into())),
+        Box::new(Expr::Symbol(head_name.clone().into())),
         true,
-        vec![Defn::simple(block.name.clone(), inner)],
+        vec![Defn::simple(head_name, inner)],
     );
     block
 }
 
+/// The variable holding the value of the variant of the loop with head `head` at the start of the
+/// current iteration.
+fn variant_ident(head: BasicBlock) -> Ident {
+    format!("variant'{}", head.as_usize()).into()
+}
+
+/// The condition for going back to the head of a loop: its variant must have decreased since the
+/// start of the iteration, without going below zero.
+fn variant_decrease<'tcx>(
+    lower: &mut LoweringState<'_, 'tcx>,
+    head: BasicBlock,
+    variant: &pearlite::Term<'tcx>,
+) -> Exp {
+    let tcx = lower.ctx.tcx;
+    let old = pearlite::Term::var(Symbol::intern(&variant_ident(head).to_string()), variant.ty);
+    let mut decrease = variant.clone().bin_op(tcx, pearlite::BinOp::Lt, old.clone());
+    let zero = match variant.ty.kind() {
+        TyKind::Uint(_) => None,
+        TyKind::Int(ity) => Some(Literal::MachSigned(0, *ity)),
+        _ => Some(Literal::Integer(0)),
+    };
+    if let Some(zero) = zero {
+        let zero = pearlite::Term { ty: variant.ty, kind: TermKind::Lit(zero), span: DUMMY_SP };
+        decrease = zero.bin_op(tcx, pearlite::BinOp::Le, old).conj(decrease);
+    }
+    Exp::Attr(
+        Attribute::Attr("expl:loop variant".into()),
+        Box::new(lower_pure(lower.ctx, lower.names, &decrease)),
+    )
+}
+
 pub(crate) struct LoweringState<'a, 'tcx> {
     pub(super) ctx: &'a mut Why3Generator<'tcx>,
     pub(super) names: &'a mut Dependencies<'tcx>,
     pub(super) locals: &'a LocalDecls<'tcx>,
+    /// Whether the function being lowered is allowed to panic
+    pub(super) can_panic: bool,
     pub(super) name_supply: NameSupply,
 }
 
@@ -413,6 +520,24 @@ impl<'a, 'tcx> LoweringState<'a, 'tcx> {
     pub(super) fn fresh_from(&mut self, base: impl AsRef<str>) -> Ident {
         self.name_supply.freshen(Symbol::intern(base.as_ref())).to_string().into()
     }
+
+    /// Program point which must be proven unreachable
+    fn abort(&mut self, span: Span) -> coma::Expr {
+        let mut exp = Exp::mk_false();
+        if let Some(attr) = self.names.span(span) {
+            exp = exp.with_attr(attr);
+        };
+        coma::Expr::Assert(Box::new(exp), Box::new(coma::Expr::Any))
+    }
+
+    /// Exit through the `panic'` continuation, if the function is allowed to panic
+    fn panic(&mut self, span: Span) -> coma::Expr {
+        if self.can_panic {
+            coma::Expr::Symbol("panic'".into())
+        } else {
+            self.abort(span)
+        }
+    }
 }
 
 impl<'tcx> Operand<'tcx> {
@@ -455,6 +580,15 @@ impl<'tcx> RValue<'tcx> {
             RValue::BinOp(BinOp::BitAnd, l, r) if l.ty(lower.ctx.tcx, lower.locals).is_bool() => {
                 l.to_why(lower, istmts).lazy_and(r.to_why(lower, istmts))
             }
+            RValue::BinOp(BinOp::BitOr, l, r) if l.ty(lower.ctx.tcx, lower.locals).is_bool() => {
+                l.to_why(lower, istmts).log_or(r.to_why(lower, istmts))
+            }
+            RValue::BinOp(BinOp::BitXor, l, r) if l.ty(lower.ctx.tcx, lower.locals).is_bool() => {
+                lower.names.import_prelude_module(PreludeModule::Bool);
+
+                Exp::qvar(QName::from_string("Bool.ne").unwrap())
+                    .app(vec![l.to_why(lower, istmts), r.to_why(lower, istmts)])
+            }
             RValue::BinOp(BinOp::Eq, l, r) if l.ty(lower.ctx.tcx, lower.locals).is_bool() => {
                 lower.names.import_prelude_module(PreludeModule::Bool);
 
@@ -469,10 +603,17 @@ impl<'tcx> RValue<'tcx> {
             }
             RValue::BinOp(op, l, r) => {
                 let l_ty = l.ty(lower.ctx.tcx, lower.locals);
+                let r_ty = r.ty(lower.ctx.tcx, lower.locals);
                 let fname = binop_to_binop(lower.names, l_ty, op);
                 let call = coma::Expr::Symbol(fname);
-                let args =
-                    vec![Arg::Term(l.to_why(lower, istmts)), Arg::Term(r.to_why(lower, istmts))];
+                let l = l.to_why(lower, istmts);
+                let mut r = r.to_why(lower, istmts);
+                // Shift amounts may be of any integer type
+                if matches!(op, BinOp::Shl | BinOp::ShlUnchecked | BinOp::Shr | BinOp::ShrUnchecked)
+                {
+                    r = int_ty_to_int(lower.names, r_ty, r);
+                }
+                let args = vec![Arg::Term(l), Arg::Term(r)];
                 istmts.extend([IntermediateStmt::call("_ret'".into(), lower.ty(ty), call, args)]);
                 // let ty = l.ty(lower.ctx.tcx, locals);
                 // // Hack
@@ -483,33 +624,18 @@ impl<'tcx> RValue<'tcx> {
                 // // Hack
                 // translate_ty(ctx, names, DUMMY_SP, op_ty);
             }
-            RValue::UnaryOp(UnOp::Not, arg) => arg.to_why(lower, istmts).not(),
-            RValue::UnaryOp(UnOp::Neg, arg) => {
-                let prelude: PreludeModule = match ty.kind() {
-                    TyKind::Int(ity) => int_to_prelude(*ity),
-                    TyKind::Uint(uty) => uint_to_prelude(*uty),
-                    TyKind::Float(FloatTy::F32) => PreludeModule::Float32,
-                    TyKind::Float(FloatTy::F64) => PreludeModule::Float64,
-                    TyKind::Bool => PreludeModule::Bool,
-                    _ => unreachable!("non-primitive type for negation {ty:?}"),
-                };
-
-                lower.names.import_prelude_module(prelude);
-                let mut module = prelude.qname();
-                module = module.without_search_path();
-                module.push_ident("neg");
-
-                let id: Ident = "_ret".into();
-                let ty = lower.ty(ty);
+            RValue::UnaryOp(UnOp::Not, arg) if ty.is_bool() => arg.to_why(lower, istmts).not(),
+            RValue::UnaryOp(op, arg) => {
+                let fname = unop_to_unop(lower.names, ty, op);
                 let arg = arg.to_why(lower, istmts);
                 istmts.push(IntermediateStmt::call(
-                    id.clone(),
-                    ty,
-                    Expr::Symbol(module),
+                    "_ret".into(),
+                    lower.ty(ty),
+                    Expr::Symbol(fname),
                     vec![Arg::Term(arg)],
                 ));
 
-                Exp::var(id)
+                Exp::var("_ret")
             }
             RValue::Constructor(id, subst, args) => {
                 let args = args.into_iter().map(|a| a.to_why(lower, istmts)).collect();
@@ -520,6 +646,96 @@ impl<'tcx> RValue<'tcx> {
             RValue::Tuple(f) => {
                 Exp::Tuple(f.into_iter().map(|f| f.to_why(lower, istmts)).collect())
             }
+            // Unsizing into a trait object: the object is an unknown value, agreeing with the
+            // value it hides on the logic functions of the trait.
+            RValue::Cast(e, source, target) if !(target.is_numeric() || target.is_char()) => {
+                let src = e.to_why(lower, istmts);
+                // Only the lifetime or the auto traits of the object change
+                if source.builtin_deref(true).is_some_and(|ty| ty.is_trait()) {
+                    src
+                } else if source.is_unsafe_ptr() && target.is_unsafe_ptr() {
+                    // Raw pointers are untyped addresses
+                    src
+                } else {
+                    let id = lower.fresh_from("__dyn");
+                    let ty = lower.ty(target);
+                    istmts.push(IntermediateStmt::Any(id.clone(), ty));
+
+                    let res = Exp::var(id);
+                    let assumptions = unsize_assumptions(lower, source, target, src, res.clone());
+                    if let Some(mut assumptions) = assumptions.into_iter().reduce(Exp::log_and) {
+                        assumptions.reassociate();
+                        istmts.push(IntermediateStmt::Assume(assumptions));
+                    }
+                    res
+                }
+            }
+            RValue::Cast(e, source, target)
+                if source.is_floating_point() || target.is_floating_point() =>
+            {
+                let e = e.to_why(lower, istmts);
+                let (conv, arg) = match (source.kind(), target.kind()) {
+                    (TyKind::Float(FloatTy::F32), TyKind::Float(FloatTy::F64)) => {
+                        lower.names.import_prelude_module(PreludeModule::Float32);
+                        lower.names.import_prelude_module(PreludeModule::Float64);
+                        (QName::from_string("Float64.of_float32").unwrap(), e)
+                    }
+                    (TyKind::Float(FloatTy::F64), TyKind::Float(FloatTy::F32)) => {
+                        lower.names.import_prelude_module(PreludeModule::Float32);
+                        lower.names.import_prelude_module(PreludeModule::Float64);
+                        (QName::from_string("Float64.to_float32").unwrap(), e)
+                    }
+                    (TyKind::Float(_), TyKind::Float(_)) => return e,
+                    // Float to integer casts round towards zero and saturate: NaN is mapped to 0,
+                    // and values out of the range of the target type to its bounds.
+                    (TyKind::Float(fty), _) => {
+                        let int_prelude = match target.kind() {
+                            TyKind::Int(ity) => int_to_prelude(*ity),
+                            TyKind::Uint(uty) => uint_to_prelude(*uty),
+                            _ => lower.ctx.crash_and_error(
+                                DUMMY_SP,
+                                "Non integral casts are currently unsupported",
+                            ),
+                        };
+                        lower.names.import_prelude_module(int_prelude);
+                        let bound = |name| {
+                            let mut bound = int_prelude.qname().without_search_path();
+                            bound.push_ident(name);
+                            Exp::qvar(bound)
+                        };
+
+                        let mut to_int = float_to_prelude(*fty).qname().without_search_path();
+                        lower.names.import_prelude_module(float_to_prelude(*fty));
+                        to_int.push_ident("to_int_sat");
+                        istmts.push(IntermediateStmt::call(
+                            "_int".into(),
+                            Type::Integer,
+                            Expr::Symbol(to_int),
+                            vec![Arg::Term(e), Arg::Term(bound("min")), Arg::Term(bound("max"))],
+                        ));
+
+                        let mut from_int = int_prelude.qname().without_search_path();
+                        from_int.push_ident("of_int");
+                        (from_int, Exp::var("_int"))
+                    }
+                    (_, TyKind::Float(fty)) => {
+                        let mut of_int = float_to_prelude(*fty).qname().without_search_path();
+                        lower.names.import_prelude_module(float_to_prelude(*fty));
+                        of_int.push_ident("of_int");
+                        (of_int, int_ty_to_int(lower.names, source, e))
+                    }
+                    _ => unreachable!(),
+                };
+
+                istmts.push(IntermediateStmt::call(
+                    "_res".into(),
+                    lower.ty(ty),
+                    Expr::Symbol(conv),
+                    vec![Arg::Term(arg)],
+                ));
+
+                Exp::var("_res")
+            }
             RValue::Cast(e, source, target) => {
                 let to_int = match source.kind() {
                     TyKind::Int(ity) => {
@@ -534,6 +750,10 @@ impl<'tcx> RValue<'tcx> {
                         lower.names.import_prelude_module(PreludeModule::Bool);
                         Exp::qvar(QName::from_string("Bool.to_int").unwrap())
                     }
+                    TyKind::Char => {
+                        lower.names.import_prelude_module(PreludeModule::Char);
+                        Exp::qvar(QName::from_string("Char.code").unwrap())
+                    }
                     _ => lower
                         .ctx
                         .crash_and_error(DUMMY_SP, "Non integral casts are currently unsupported"),
@@ -621,6 +841,12 @@ impl<'tcx> RValue<'tcx> {
                 Exp::var("_res")
             }
             RValue::Ghost(t) => lower_pure(lower.ctx, lower.names, &t),
+            // The address of the place is unknown: it is only useful with a permission
+            RValue::Ptr(_) => {
+                let id = lower.fresh_from("__ptr");
+                istmts.push(IntermediateStmt::Any(id.clone(), lower.ty(ty)));
+                Exp::var(id)
+            }
             RValue::Borrow(_, _) => todo!(),
         };
 
@@ -646,7 +872,7 @@ impl<'tcx> RValue<'tcx> {
                 len.invalidated_places(places)
             }
             RValue::Ghost(_) => {}
-            RValue::Borrow(_, _) => {}
+            RValue::Borrow(_, _) | RValue::Ptr(_) => {}
         }
     }
 }
@@ -704,7 +930,7 @@ impl<'tcx> Terminator<'tcx> {
                 }
             },
             Terminator::Return => {}
-            Terminator::Abort(_) => {}
+            Terminator::Abort(_) | Terminator::Panic(_) => {}
         }
     }
 
@@ -723,13 +949,8 @@ impl<'tcx> Terminator<'tcx> {
             Terminator::Return => {
                 (istmts, Expr::Symbol("return".into()).app(vec![Arg::Term(Exp::var("_0"))]))
             }
-            Terminator::Abort(span) => {
-                let mut exp = Exp::mk_false();
-                if let Some(attr) = lower.names.span(span) {
-                    exp = exp.with_attr(attr);
-                };
-                (istmts, Expr::Assert(Box::new(exp), Box::new(Expr::Any)))
-            }
+            Terminator::Abort(span) => (istmts, lower.abort(span)),
+            Terminator::Panic(span) => (istmts, lower.panic(span)),
         }
     }
 }
@@ -858,10 +1079,6 @@ fn mk_switch_branches(discr: Exp, brch: Vec<(Exp, coma::Expr)>) -> Vec<coma::Def
 
 impl<'tcx> Block<'tcx> {
     pub(crate) fn to_why(self, lower: &mut LoweringState<'_, 'tcx>, id: BasicBlock) -> coma::Defn {
-        // for v in self.variant.into_iter() {
-        //     statements.push(coma::Statement::Variant(lower_pure(ctx, names, &v)));
-        // }
-
         let (istmts, terminator) = self.terminator.to_why(lower);
         // let statements =
         //     self.stmts.into_iter().flat_map(|s| s.to_why(ctx, names, locals)).chain(istmts);
@@ -884,6 +1101,9 @@ impl<'tcx> Block<'tcx> {
         statements.push(coma::Defn::simple(format!("s{}", statements.len()), body));
 
         let mut body = Expr::Symbol("s0".into());
+        if let Some(variant) = &self.variant {
+            body = body.assign(variant_ident(id), lower_pure(lower.ctx, lower.names, variant));
+        }
         if !self.invariants.is_empty() {
             body = Expr::BlackBox(Box::new(body));
         }
@@ -1081,10 +1301,18 @@ impl<'tcx> Statement<'tcx> {
 
                 istmts
             }
-            Statement::Call(dest, fun_id, subst, args, _) => {
+            Statement::Call(dest, fun_id, subst, args, span) => {
                 let mut istmts = Vec::new();
 
-                let (fun_exp, args) = func_call_to_why3(lower, fun_id, subst, args, &mut istmts);
+                let (fun_exp, mut args) =
+                    func_call_to_why3(lower, fun_id, subst, args, &mut istmts);
+                if lower.ctx.sig(fun_id).contract.can_panic() {
+                    let panic = match lower.panic(span) {
+                        panic @ Expr::Symbol(_) => panic,
+                        abort => Expr::Lambda(Vec::new(), Box::new(abort)),
+                    };
+                    args.push(Arg::Cont(panic));
+                }
                 let ty = dest.ty(lower.ctx.tcx, lower.locals);
                 let ty = lower.ty(ty);
                 let assign = lower.assignment(&dest, Exp::var("_ret'"));
@@ -1189,6 +1417,122 @@ fn func_call_to_why3<'tcx>(
     (coma::Expr::Symbol(fname), args)
 }
 
+/// The facts relating `res`, a pointer to a trait object, to `src`, the pointer of type `source`
+/// it was unsized from: every logic function of the trait taking the object by value or shared
+/// reference gives the same result on the object and on the value it hides.
+fn unsize_assumptions<'tcx>(
+    lower: &mut LoweringState<'_, 'tcx>,
+    source: Ty<'tcx>,
+    target: Ty<'tcx>,
+    src: Exp,
+    res: Exp,
+) -> Vec<Exp> {
+    let tcx = lower.ctx.tcx;
+    let (source, target) = (tcx.erase_regions(source), tcx.erase_regions(target));
+    let (Some(hidden), Some(object)) = (source.builtin_deref(true), target.builtin_deref(true))
+    else {
+        return Vec::new();
+    };
+    let TyKind::Dynamic(preds, _, _) = object.kind() else { return Vec::new() };
+    let Some(principal) = preds.principal() else { return Vec::new() };
+    let principal = principal.skip_binder();
+
+    let values = match source.kind() {
+        TyKind::Ref(_, _, Mutability::Mut) => vec![
+            (Exp::Current(Box::new(src.clone())), Exp::Current(Box::new(res.clone()))),
+            (Exp::Final(Box::new(src)), Exp::Final(Box::new(res))),
+        ],
+        TyKind::Ref(_, _, Mutability::Not) => vec![(src, res)],
+        _ if source.is_box() => vec![(src, res)],
+        _ => return Vec::new(),
+    };
+
+    // The specifications of closures are given by the `Fn*Ext` traits
+    let mut traits = vec![principal.def_id];
+    if let Some(kind) = tcx.fn_trait_kind_from_def_id(principal.def_id) {
+        for (k, spec) in [
+            (ClosureKind::FnOnce, "fn_once_spec"),
+            (ClosureKind::FnMut, "fn_mut_spec"),
+            (ClosureKind::Fn, "fn_spec"),
+        ] {
+            if kind.extends(k) {
+                traits.extend(tcx.get_diagnostic_item(Symbol::intern(spec)));
+            }
+        }
+    }
+
+    let mut assumptions = Vec::new();
+    for tr in traits {
+        let object_args =
+            tcx.mk_args_from_iter(std::iter::once(object.into()).chain(principal.args.iter()));
+        let hidden_args =
+            tcx.mk_args_from_iter(std::iter::once(hidden.into()).chain(principal.args.iter()));
+
+        for item in tcx.associated_items(tr).in_definition_order() {
+            if item.kind != AssocKind::Fn
+                || !(util::is_logic(tcx, item.def_id) || util::is_predicate(tcx, item.def_id))
+                || util::is_law(tcx, item.def_id)
+                || !tcx.generics_of(item.def_id).own_params.is_empty()
+                || tcx.generics_require_sized_self(item.def_id)
+            {
+                continue;
+            }
+
+            let sig = |args| {
+                let sig = tcx.fn_sig(item.def_id).instantiate(tcx, args);
+                tcx.try_normalize_erasing_regions(
+                    ParamEnv::reveal_all(),
+                    tcx.instantiate_bound_regions_with_erased(sig),
+                )
+            };
+            let (Ok(object_sig), Ok(hidden_sig)) = (sig(object_args), sig(hidden_args)) else {
+                continue;
+            };
+            let takes_self = |self_ty: Ty<'tcx>, sig: FnSig<'tcx>| match sig.inputs().first() {
+                Some(&ty) if ty == self_ty => true,
+                Some(ty) => {
+                    matches!(ty.kind(), TyKind::Ref(_, ty, Mutability::Not) if *ty == self_ty)
+                }
+                None => false,
+            };
+            if !takes_self(object, object_sig)
+                || !takes_self(hidden, hidden_sig)
+                || object_sig.inputs()[1..] != hidden_sig.inputs()[1..]
+            {
+                continue;
+            }
+
+            let binders: Vec<_> = object_sig.inputs()[1..]
+                .iter()
+                .map(|&ty| (lower.fresh_from("x"), lower.ty(ty)))
+                .collect();
+            let mut name = |args| {
+                let (id, args) =
+                    traits::resolve_opt(tcx, ParamEnv::reveal_all(), item.def_id, args)
+                        .unwrap_or((item.def_id, args));
+                lower.ctx.translate(id);
+                Exp::qvar(lower.names.value(id, args))
+            };
+            let object_fn = name(object_args);
+            let hidden_fn = name(hidden_args);
+
+            for (src, res) in &values {
+                let app = |f: &Exp, this: &Exp| {
+                    let args = binders.iter().map(|(x, _)| Exp::var(x.clone()));
+                    f.clone().app(std::iter::once(this.clone()).chain(args).collect())
+                };
+                let eq = app(&object_fn, res).eq(app(&hidden_fn, src));
+                if binders.is_empty() {
+                    assumptions.push(eq)
+                } else {
+                    assumptions.push(Exp::forall(binders.clone(), eq))
+                }
+            }
+        }
+    }
+    assumptions
+}
+
 pub(crate) fn binop_to_binop<'tcx, N: Namer<'tcx>>(names: &mut N, ty: Ty, op: mir::BinOp) -> QName {
     let prelude: PreludeModule = match ty.kind() {
         TyKind::Int(ity) => int_to_prelude(*ity),
@@ -1196,6 +1540,7 @@ pub(crate) fn binop_to_binop<'tcx, N: Namer<'tcx>>(names: &mut N, ty: Ty, op: mi
         TyKind::Float(FloatTy::F32) => PreludeModule::Float32,
         TyKind::Float(FloatTy::F64) => PreludeModule::Float64,
         TyKind::Bool => PreludeModule::Bool,
+        TyKind::Char => PreludeModule::Char,
         _ => unreachable!("non-primitive type for binary operation {op:?} {ty:?}"),
     };
 
@@ -1225,13 +1570,58 @@ pub(crate) fn binop_to_binop<'tcx, N: Namer<'tcx>>(names: &mut N, ty: Ty, op: mi
         BinOp::Ge => module.push_ident("ge"),
         BinOp::Gt => module.push_ident("gt"),
         BinOp::Cmp => todo!(),
-        BinOp::Offset => unimplemented!("pointer offsets are unsupported"),
+        // Rejected when translating statements
+        BinOp::Offset => unreachable!(),
     };
 
     module = module.without_search_path();
     module
 }
 
+pub(crate) fn unop_to_unop<'tcx, N: Namer<'tcx>>(names: &mut N, ty: Ty, op: mir::UnOp) -> QName {
+    let prelude: PreludeModule = match ty.kind() {
+        TyKind::Int(ity) => int_to_prelude(*ity),
+        TyKind::Uint(uty) => uint_to_prelude(*uty),
+        TyKind::Float(fty) => float_to_prelude(*fty),
+        _ => unreachable!("non-primitive type for unary operation {op:?} {ty:?}"),
+    };
+
+    names.import_prelude_module(prelude);
+    let mut module = prelude.qname().without_search_path();
+
+    match op {
+        UnOp::Not => module.push_ident("bw_not"),
+        UnOp::Neg => module.push_ident("neg"),
+    };
+
+    module
+}
+
+/// Convert `e`, of the machine integer type `ty`, to a mathematical integer.
+pub(crate) fn int_ty_to_int<'tcx, N: Namer<'tcx>>(names: &mut N, ty: Ty, e: Exp) -> Exp {
+    let to_int = match ty.kind() {
+        TyKind::Int(ity) => {
+            names.import_prelude_module(int_to_prelude(*ity));
+            int_to_int(ity)
+        }
+        TyKind::Uint(uty) => {
+            names.import_prelude_module(uint_to_prelude(*uty));
+            uint_to_int(uty)
+        }
+        _ => unreachable!("non-integral type {ty:?}"),
+    };
+    to_int.app_to(e)
+}
+
+pub(crate) fn float_to_prelude(fty: FloatTy) -> PreludeModule {
+    match fty {
+        FloatTy::F32 => PreludeModule::Float32,
+        FloatTy::F64 => PreludeModule::Float64,
+        // Rejected when translating statements
+        _ => unreachable!("unsupported float type {fty:?}"),
+    }
+}
+
 pub(crate) fn int_to_prelude(ity: IntTy) -> PreludeModule {
     match ity {
         IntTy::Isize => PreludeModule::Isize,
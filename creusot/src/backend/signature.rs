@@ -90,6 +90,11 @@ fn contract_to_why3<'tcx, N: Namer<'tcx>>(
         out.ensures.push(lower_pure(ctx, names, &term));
     }
 
+    for term in &pre.panics_if {
+        out.panics_if.push(lower_pure(ctx, names, term));
+    }
+    out.may_panic = pre.can_panic();
+
     if let Some(term) = &pre.variant {
         out.variant = vec![lower_pure(ctx, names, &term)];
     }
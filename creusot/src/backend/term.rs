@@ -1,15 +1,20 @@
-use super::{program::borrow_generated_id, Why3Generator};
+use super::{
+    program::{self, borrow_generated_id},
+    Why3Generator,
+};
 use crate::{
     backend::ty::{floatty_to_ty, intty_to_ty, translate_ty, uintty_to_ty},
+    constant,
     ctx::*,
     pearlite::{self, Literal, Pattern, Term, TermKind},
     util,
     util::get_builtin,
 };
 use rustc_hir::{def::DefKind, def_id::DefId};
-use rustc_middle::ty::{EarlyBinder, GenericArgsRef, Ty, TyCtxt, TyKind};
+use rustc_middle::ty::{ConstKind, EarlyBinder, GenericArgsRef, ParamEnv, Ty, TyCtxt, TyKind};
+use rustc_span::DUMMY_SP;
 use why3::{
-    exp::{BinOp, Binder, Constant, Exp, Pattern as Pat},
+    exp::{BinOp, Binder, Constant, Exp, Pattern as Pat, Trigger},
     ty::Type,
     Ident, QName,
 };
@@ -61,6 +66,7 @@ impl<'tcx, N: Namer<'tcx>> Lower<'_, 'tcx, N> {
             }
             TermKind::Var(v) => Exp::var(util::ident_of(*v)),
             TermKind::Binary { op, box lhs, box rhs } => {
+                let (lhs_ty, rhs_ty) = (lhs.ty, rhs.ty);
                 let lhs = self.lower_term(lhs);
                 let rhs = self.lower_term(rhs);
 
@@ -70,11 +76,36 @@ impl<'tcx, N: Namer<'tcx>> Lower<'_, 'tcx, N> {
                 }
 
                 match op {
+                    Add | Sub | Mul | Div | Rem if lhs_ty.is_floating_point() => {
+                        lower_float_binop(self.names, *op, lhs_ty, lhs, rhs)
+                    }
+                    // Characters are ordered by their code point
+                    Le | Ge | Lt | Gt if lhs_ty.is_char() => {
+                        self.names.import_prelude_module(PreludeModule::Char);
+                        let mut code = PreludeModule::Char.qname().without_search_path();
+                        code.push_ident("code");
+                        Exp::BinaryOp(
+                            binop_to_binop(*op),
+                            Box::new(Exp::qvar(code.clone()).app(vec![lhs])),
+                            Box::new(Exp::qvar(code).app(vec![rhs])),
+                        )
+                    }
                     Div => Exp::var("div").app(vec![lhs, rhs]),
                     Rem => Exp::var("mod").app(vec![lhs, rhs]),
+                    BitAnd | BitOr | BitXor | Shl | Shr => {
+                        lower_bitwise(self.names, *op, lhs_ty, rhs_ty, lhs, rhs)
+                    }
                     _ => Exp::BinaryOp(binop_to_binop(*op), Box::new(lhs), Box::new(rhs)),
                 }
             }
+            TermKind::Unary { op, box arg }
+                if arg.ty.is_floating_point()
+                    || (arg.ty.is_integral() && matches!(op, pearlite::UnOp::Not)) =>
+            {
+                let arg_ty = arg.ty;
+                let arg = self.lower_term(arg);
+                lower_prelude_unop(self.names, op, arg_ty, arg)
+            }
             TermKind::Unary { op, box arg } => {
                 let op = match op {
                     pearlite::UnOp::Not => why3::exp::UnOp::Not,
@@ -102,14 +133,15 @@ impl<'tcx, N: Namer<'tcx>> Lower<'_, 'tcx, N> {
                     Exp::qvar(clone).app(args)
                 })
             }
-            TermKind::Forall { binder, box body } => {
-                let ty = self.lower_ty(binder.1);
-
-                Exp::forall(vec![(binder.0.to_string().into(), ty)], self.lower_term(body))
+            TermKind::Forall { .. } => {
+                let (binders, trigger, body) = self.lower_quantifier(term);
+                let body = self.lower_term(body);
+                Exp::forall_trig(binders, trigger, body)
             }
-            TermKind::Exists { binder, box body } => {
-                let ty = self.lower_ty(binder.1);
-                Exp::exists(vec![(binder.0.to_string().into(), ty)], self.lower_term(body))
+            TermKind::Exists { .. } => {
+                let (binders, trigger, body) = self.lower_quantifier(term);
+                let body = self.lower_term(body);
+                Exp::exists_trig(binders, trigger, body)
             }
             TermKind::Constructor { typ, variant, fields } => {
                 self.ctx.translate(*typ);
@@ -118,7 +150,7 @@ impl<'tcx, N: Namer<'tcx>> Lower<'_, 'tcx, N> {
 
                 let ctor = self
                     .names
-                    .constructor(self.ctx.adt_def(typ).variants()[*variant].def_id, subst);
+                    .constructor(util::constructor_did(self.ctx.adt_def(typ), *variant), subst);
                 Exp::Constructor { ctor, args }
             }
             TermKind::Cur { box term } => {
@@ -216,6 +248,48 @@ impl<'tcx, N: Namer<'tcx>> Lower<'_, 'tcx, N> {
         }
     }
 
+    // A trigger must mention every variable bound by its quantifier, so a chain of directly
+    // nested quantifiers ending in one with triggers is lowered as a single quantifier.
+    fn lower_quantifier<'b>(
+        &mut self,
+        term: &'b Term<'tcx>,
+    ) -> (Vec<(Ident, Type)>, Vec<Trigger>, &'b Term<'tcx>) {
+        let is_forall = matches!(term.kind, TermKind::Forall { .. });
+        let quantifier = |t: &'b Term<'tcx>| match &t.kind {
+            TermKind::Forall { binder, trigger, body } if is_forall => {
+                Some((binder, trigger, body))
+            }
+            TermKind::Exists { binder, trigger, body } if !is_forall => {
+                Some((binder, trigger, body))
+            }
+            _ => None,
+        };
+
+        let (binder, mut trigger, outer_body) = quantifier(term).unwrap();
+        let mut binders = vec![binder];
+        let mut body = outer_body;
+        while trigger.is_empty() {
+            let Some((b, t, inner)) = quantifier(body) else {
+                // No triggers: only lower the outermost quantifier.
+                binders.truncate(1);
+                body = outer_body;
+                break;
+            };
+            binders.push(b);
+            (trigger, body) = (t, inner);
+        }
+
+        let binders = binders
+            .into_iter()
+            .map(|(name, ty)| (name.to_string().into(), self.lower_ty(*ty)))
+            .collect();
+        let trigger = trigger
+            .iter()
+            .map(|t| Trigger(t.0.iter().map(|t| self.lower_term(t)).collect()))
+            .collect();
+        (binders, trigger, body)
+    }
+
     fn lower_pat(&mut self, pat: &Pattern<'tcx>) -> Pat {
         match pat {
             Pattern::Constructor { adt, variant: _, fields, substs } => {
@@ -263,7 +337,7 @@ impl<'tcx, N: Namer<'tcx>> Lower<'_, 'tcx, N> {
 }
 
 pub(crate) fn lower_literal<'tcx, N: Namer<'tcx>>(
-    _: &mut TranslationCtx<'tcx>,
+    ctx: &mut TranslationCtx<'tcx>,
     names: &mut N,
     lit: &Literal<'tcx>,
 ) -> Exp {
@@ -289,12 +363,49 @@ pub(crate) fn lower_literal<'tcx, N: Namer<'tcx>>(
             names.value(*id, subst);
             Exp::Tuple(Vec::new())
         }
+        Literal::Float(f, fty) if !f.0.is_finite() => {
+            // NaN and the infinities have no literal syntax in Why3
+            let prelude = program::float_to_prelude(*fty);
+            names.import_prelude_module(prelude);
+            let mut constant = prelude.qname().without_search_path();
+            if f.0.is_nan() {
+                constant.push_ident("nan")
+            } else if f.0.is_sign_positive() {
+                constant.push_ident("infinity")
+            } else {
+                constant.push_ident("neg_infinity")
+            }
+            Exp::qvar(constant)
+        }
         Literal::Float(f, fty) => {
             let why_ty = floatty_to_ty(names, &fty);
             Constant::Float(f.0, Some(why_ty)).into()
         }
+        Literal::Char(c) => {
+            names.import_prelude_module(PreludeModule::Char);
+            let mut chr = PreludeModule::Char.qname().without_search_path();
+            chr.push_ident("chr");
+            Exp::qvar(chr).app(vec![Constant::Int(*c as i128, None).into()])
+        }
         Literal::ZST => Exp::Tuple(Vec::new()),
-        Literal::String(string) => Constant::String(string.clone()).into(),
+        // Strings are sequences of characters
+        Literal::String(string) => {
+            names.import_prelude_module(PreludeModule::Seq);
+            let empty = Exp::qvar(QName::from_string("Seq.empty").unwrap());
+            string.chars().rev().fold(empty, |s, c| {
+                let c = lower_literal(ctx, names, &Literal::Char(c));
+                Exp::qvar(QName::from_string("Seq.cons").unwrap()).app(vec![c, s])
+            })
+        }
+        Literal::Const(c) => match c.kind() {
+            ConstKind::Param(p) => Exp::var(util::ident_of(p.name)),
+            // The constant was instantiated, evaluate it to a literal
+            _ => {
+                let term = constant::from_ty_const(ctx, *c, ParamEnv::reveal_all(), DUMMY_SP);
+                let TermKind::Lit(lit) = term.kind else { unreachable!() };
+                lower_literal(ctx, names, &lit)
+            }
+        },
     }
 }
 
@@ -313,9 +424,85 @@ pub(crate) fn binop_to_binop(op: pearlite::BinOp) -> why3::exp::BinOp {
         pearlite::BinOp::Or => BinOp::LogOr,
         pearlite::BinOp::Div => todo!("Refactor binop_to_binop to support Div"),
         pearlite::BinOp::Rem => todo!("Refactor binop_to_binop to support Rem"),
+        pearlite::BinOp::BitAnd
+        | pearlite::BinOp::BitOr
+        | pearlite::BinOp::BitXor
+        | pearlite::BinOp::Shl
+        | pearlite::BinOp::Shr => unreachable!("bitwise operations are lowered by `lower_bitwise`"),
     }
 }
 
+/// Lower a bitwise operation or a shift.
+///
+/// On booleans these are the usual logical connectives, on machine integers they are the
+/// `bw_*` and shift functions of the corresponding prelude module. The amount of a shift may be
+/// of any integer type, so it is converted to a mathematical integer first.
+pub(crate) fn lower_bitwise<'tcx, N: Namer<'tcx>>(
+    names: &mut N,
+    op: pearlite::BinOp,
+    lhs_ty: Ty<'tcx>,
+    rhs_ty: Ty<'tcx>,
+    lhs: Exp,
+    rhs: Exp,
+) -> Exp {
+    use rustc_middle::mir;
+    if lhs_ty.is_bool() {
+        let op = match op {
+            pearlite::BinOp::BitAnd => BinOp::LogAnd,
+            pearlite::BinOp::BitOr => BinOp::LogOr,
+            pearlite::BinOp::BitXor => BinOp::Ne,
+            _ => unreachable!("non-bitwise operation on booleans {op:?}"),
+        };
+        return Exp::BinaryOp(op, Box::new(lhs), Box::new(rhs));
+    }
+
+    let (op, rhs) = match op {
+        pearlite::BinOp::BitAnd => (mir::BinOp::BitAnd, rhs),
+        pearlite::BinOp::BitOr => (mir::BinOp::BitOr, rhs),
+        pearlite::BinOp::BitXor => (mir::BinOp::BitXor, rhs),
+        pearlite::BinOp::Shl => (mir::BinOp::Shl, program::int_ty_to_int(names, rhs_ty, rhs)),
+        pearlite::BinOp::Shr => (mir::BinOp::Shr, program::int_ty_to_int(names, rhs_ty, rhs)),
+        _ => unreachable!("non-bitwise operation {op:?}"),
+    };
+    Exp::qvar(program::binop_to_binop(names, lhs_ty, op)).app(vec![lhs, rhs])
+}
+
+/// Lower an arithmetic operation on floating-point numbers to the corresponding function of the
+/// prelude, which rounds to nearest like Rust does.
+pub(crate) fn lower_float_binop<'tcx, N: Namer<'tcx>>(
+    names: &mut N,
+    op: pearlite::BinOp,
+    ty: Ty<'tcx>,
+    lhs: Exp,
+    rhs: Exp,
+) -> Exp {
+    use rustc_middle::mir;
+    let op = match op {
+        pearlite::BinOp::Add => mir::BinOp::Add,
+        pearlite::BinOp::Sub => mir::BinOp::Sub,
+        pearlite::BinOp::Mul => mir::BinOp::Mul,
+        pearlite::BinOp::Div => mir::BinOp::Div,
+        pearlite::BinOp::Rem => mir::BinOp::Rem,
+        _ => unreachable!("non-arithmetic operation on floats {op:?}"),
+    };
+    Exp::qvar(program::binop_to_binop(names, ty, op)).app(vec![lhs, rhs])
+}
+
+/// Lower the negation of a float or the bitwise negation of a machine integer.
+pub(crate) fn lower_prelude_unop<'tcx, N: Namer<'tcx>>(
+    names: &mut N,
+    op: &pearlite::UnOp,
+    ty: Ty<'tcx>,
+    arg: Exp,
+) -> Exp {
+    use rustc_middle::mir;
+    let op = match op {
+        pearlite::UnOp::Not => mir::UnOp::Not,
+        pearlite::UnOp::Neg => mir::UnOp::Neg,
+    };
+    Exp::qvar(program::unop_to_unop(names, ty, op)).app_to(arg)
+}
+
 fn is_identity_from<'tcx>(tcx: TyCtxt<'tcx>, id: DefId, subst: GenericArgsRef<'tcx>) -> bool {
     if tcx.def_path_str(id) == "std::convert::From::from" && subst.len() == 1 {
         let out_ty: Ty<'tcx> = tcx.fn_sig(id).no_bound_vars().unwrap().output().skip_binder();
@@ -79,7 +79,7 @@ fn translate_ty_inner<'tcx, N: Namer<'tcx>>(
         }
         Float(flty) => floatty_to_ty(names, flty),
         Adt(def, s) => {
-            if def.is_box() {
+            if def.is_box() || ctx.lang_items().pin_type() == Some(def.did()) {
                 return translate_ty_inner(trans, ctx, names, span, s[0].expect_ty());
             }
 
@@ -147,7 +147,11 @@ fn translate_ty_inner<'tcx, N: Namer<'tcx>>(
                 vec![translate_ty_inner(trans, ctx, names, span, *ty)],
             )
         }
-        Str => MlT::TConstructor("string".into()),
+        Str => {
+            names.import_prelude_module(PreludeModule::Char);
+            names.import_prelude_module(PreludeModule::Str);
+            MlT::TConstructor("str".into())
+        }
         // Slice()
         Never => MlT::Tuple(vec![]),
         RawPtr(_, _) => {
@@ -194,6 +198,12 @@ fn translate_ty_inner<'tcx, N: Namer<'tcx>>(
             MlT::TConstructor(QName::from_string("foreign").unwrap())
         }
         Error(_) => MlT::UNIT,
+        Coroutine(..) | Alias(AliasTyKind::Opaque, _)
+            if util::async_output(ctx.tcx, ty).is_some() =>
+        {
+            let output = util::async_output(ctx.tcx, ty).unwrap();
+            translate_ty_inner(trans, ctx, names, span, output)
+        }
         _ => ctx.crash_and_error(span, &format!("unsupported type {:?}", ty)),
     }
 }
@@ -330,6 +340,40 @@ pub(crate) fn translate_ty_param(p: Symbol) -> Ident {
     Ident::build(&p.to_string().to_lowercase())
 }
 
+/// Translate the type of a const generic parameter, returning the prelude module which defines it.
+/// Const generic parameters are declared before any other dependency of a module, so we cannot
+/// rely on a `Namer` to import the module.
+pub(crate) fn const_param_ty(tcx: TyCtxt, p: &ty::GenericParamDef) -> (Option<PreludeModule>, MlT) {
+    use rustc_middle::ty::{IntTy::*, UintTy::*};
+    match tcx.type_of(p.def_id).instantiate_identity().kind() {
+        Bool => (None, MlT::Bool),
+        Char => (Some(PreludeModule::Char), MlT::Char),
+        Int(ity) => {
+            let ty = match ity {
+                Isize => isize_ty(),
+                I8 => i8_ty(),
+                I16 => i16_ty(),
+                I32 => i32_ty(),
+                I64 => i64_ty(),
+                I128 => i128_ty(),
+            };
+            (Some(super::program::int_to_prelude(*ity)), ty)
+        }
+        Uint(uty) => {
+            let ty = match uty {
+                Usize => usize_ty(),
+                U8 => u8_ty(),
+                U16 => u16_ty(),
+                U32 => u32_ty(),
+                U64 => u64_ty(),
+                U128 => u128_ty(),
+            };
+            (Some(super::program::uint_to_prelude(*uty)), ty)
+        }
+        _ => unreachable!("unsupported type for a const generic parameter"),
+    }
+}
+
 // Translate a Rust type declation to an ML one
 // Rust tuple-like types are translated as one would expect, to product types in WhyML
 // However, Rust struct types are *not* translated to WhyML records, instead we 'forget' the field names
@@ -380,7 +424,12 @@ pub(crate) fn translate_tydecl(
         bg.iter().for_each(|did| {
             let adt_def = ctx.adt_def(*did);
 
-            adt_def.variants().indices().for_each(|vix| {
+            let constructors = if adt_def.is_union() {
+                adt_def.non_enum_variant().fields.len()
+            } else {
+                adt_def.variants().len()
+            };
+            (0..constructors).map(VariantIdx::from_usize).for_each(|vix| {
                 let d = destructor(ctx, &mut names, *did, ctx.type_of(*did).skip_binder(), vix);
 
                 destructors.push(d)
@@ -424,10 +473,14 @@ pub(crate) fn field_names_and_tys<'tcx>(
 ) -> Vec<(Symbol, Ty<'tcx>)> {
     match ty.kind() {
         Adt(def, subst) => {
-            let variant = &def.variants()[variant];
+            // The constructors of a union are its fields
+            let fields = if def.is_union() {
+                &def.non_enum_variant().fields.raw[variant.as_usize()..=variant.as_usize()]
+            } else {
+                &def.variants()[variant].fields.raw[..]
+            };
 
-            let field_tys: Vec<_> = variant
-                .fields
+            let field_tys: Vec<_> = fields
                 .iter()
                 .map(|fld| {
                     let fld_ty = fld.ty(ctx.tcx, subst);
@@ -464,7 +517,7 @@ fn id_of_ty<'tcx>(ty: Ty<'tcx>) -> Option<(DefId, GenericArgsRef<'tcx>)> {
 
 fn variant_id<'tcx>(parent: Ty<'tcx>, variant_ix: VariantIdx) -> DefId {
     match parent.kind() {
-        TyKind::Adt(adt, _) => adt.variants()[variant_ix].def_id,
+        TyKind::Adt(adt, _) => util::constructor_did(*adt, variant_ix),
         TyKind::Closure(id, _) => {
             assert!(variant_ix == 0u32.into());
             *id
@@ -556,18 +609,31 @@ fn build_ty_decl<'tcx>(
     let kind = {
         let mut ml_ty_def = Vec::new();
 
-        for var_def in adt.variants().iter() {
-            let field_tys: Vec<_> = var_def
-                .fields
-                .iter()
-                .map(|f| {
-                    let ty = field_ty(ctx, names, param_env, did, f, substs);
-                    Field { ty, ghost: false }
-                })
-                .collect();
-            let var_name = names.constructor(var_def.def_id, substs);
+        if adt.is_union() {
+            // A union holds exactly one of its fields, the active one
+            for f in adt.non_enum_variant().fields.iter() {
+                let ty = field_ty(ctx, names, param_env, did, f, substs);
+                let var_name = names.constructor(f.did, substs);
 
-            ml_ty_def.push(ConstructorDecl { name: var_name.name, fields: field_tys });
+                ml_ty_def.push(ConstructorDecl {
+                    name: var_name.name,
+                    fields: vec![Field { ty, ghost: false }],
+                });
+            }
+        } else {
+            for var_def in adt.variants().iter() {
+                let field_tys: Vec<_> = var_def
+                    .fields
+                    .iter()
+                    .map(|f| {
+                        let ty = field_ty(ctx, names, param_env, did, f, substs);
+                        Field { ty, ghost: false }
+                    })
+                    .collect();
+                let var_name = names.constructor(var_def.def_id, substs);
+
+                ml_ty_def.push(ConstructorDecl { name: var_name.name, fields: field_tys });
+            }
         }
 
         AdtDecl { ty_name, ty_params: ty_args, constrs: ml_ty_def }
@@ -665,11 +731,19 @@ pub(crate) fn translate_accessor(
     let target_ty =
         field_ty(ctx, &mut names, param_env, adt_did, &variant.fields[ix.into()], substs);
 
-    let variant_arities: Vec<_> = adt_def
-        .variants()
-        .iter()
-        .map(|var| (names.constructor(var.def_id, substs), var.fields.len()))
-        .collect();
+    // Reading a field of a union which is not active gives an unknown value
+    let (variant_ix, ix, variant_arities): (usize, usize, Vec<_>) = if adt_def.is_union() {
+        let arities =
+            variant.fields.iter().map(|f| (names.constructor(f.did, substs), 1)).collect();
+        (ix, 0, arities)
+    } else {
+        let arities = adt_def
+            .variants()
+            .iter()
+            .map(|var| (names.constructor(var.def_id, substs), var.fields.len()))
+            .collect();
+        (variant_ix.into(), ix, arities)
+    };
 
     let this = translate_ty_inner(
         TyTranslation::Declaration(adt_did),
@@ -684,7 +758,7 @@ pub(crate) fn translate_accessor(
     build_accessor(
         this,
         Ident::build(&acc_name),
-        variant_ix.into(),
+        variant_ix,
         &variant_arities,
         (ix, target_ty, false),
         &ctx.ctx,
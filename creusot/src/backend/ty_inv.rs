@@ -15,7 +15,9 @@ use indexmap::IndexSet;
 use rustc_ast::Mutability;
 use rustc_hir::{def::Namespace, def_id::DefId};
 use rustc_macros::{TypeFoldable, TypeVisitable};
-use rustc_middle::ty::{GenericArg, GenericArgsRef, ParamEnv, Ty, TyCtxt, TyKind};
+use rustc_middle::ty::{
+    Const, GenericArg, GenericArgsRef, ParamConst, ParamEnv, Ty, TyCtxt, TyKind,
+};
 use rustc_span::{Symbol, DUMMY_SP};
 use why3::{
     declaration::{Axiom, Decl, TyDecl},
@@ -31,6 +33,7 @@ pub(crate) enum TyInvKind {
     Adt(DefId),
     Tuple(usize),
     Slice,
+    Array,
 }
 
 impl TyInvKind {
@@ -53,6 +56,7 @@ impl TyInvKind {
             }
             TyKind::Tuple(tys) => Some(TyInvKind::Tuple(tys.len())),
             TyKind::Slice(_) => Some(TyInvKind::Slice),
+            TyKind::Array(_, _) => Some(TyInvKind::Array),
             _ => None, // TODO
         }
     }
@@ -74,14 +78,24 @@ impl TyInvKind {
                 (0..arity).map(|i| Ty::new_param(tcx, i as _, Symbol::intern(&format!("T{i}")))),
             ),
             TyInvKind::Slice => Ty::new_slice(tcx, param),
+            TyInvKind::Array => {
+                let len = ParamConst::new(1, Symbol::intern("N"));
+                Ty::new_array_with_const_len(
+                    tcx,
+                    param,
+                    Const::new_param(tcx, len, tcx.types.usize),
+                )
+            }
         }
     }
 
     pub(crate) fn generics(self, ctx: &mut Why3Generator) -> Vec<Ident> {
         match self {
-            TyInvKind::Trivial | TyInvKind::Borrow(_) | TyInvKind::Box | TyInvKind::Slice => {
-                vec!["t".into()]
-            }
+            TyInvKind::Trivial
+            | TyInvKind::Borrow(_)
+            | TyInvKind::Box
+            | TyInvKind::Slice
+            | TyInvKind::Array => vec!["t".into()],
             TyInvKind::Adt(def_id) => ty_params(ctx, def_id).collect(),
             TyInvKind::Tuple(arity) => (0..arity).map(|i| format!["t{i}"].into()).collect(),
         }
@@ -102,6 +116,9 @@ impl TyInvKind {
                 tcx.mk_args_from_iter(tys.iter().map(GenericArg::from))
             }
             (TyInvKind::Slice, TyKind::Adt(_, subst)) => subst,
+            (TyInvKind::Array, TyKind::Array(ty, len)) => {
+                tcx.mk_args(&[GenericArg::from(*ty), GenericArg::from(*len)])
+            }
             a => unreachable!("{a:?}"),
         }
     }
@@ -133,6 +150,8 @@ pub(crate) fn is_tyinv_trivial<'tcx>(
         }
 
         match ty.kind() {
+            // the length of an array is part of its invariant
+            TyKind::Array(_, _) => return false,
             TyKind::Ref(_, ty, _) | TyKind::Slice(ty) => stack.push(*ty),
             TyKind::Tuple(tys) => stack.extend(*tys),
             TyKind::Adt(def, substs) if def.is_box() => stack.push(substs.type_at(0)),
@@ -234,6 +253,13 @@ impl<'tcx> InvariantElaborator<'tcx> {
                 }
             }
             TyInvKind::Slice => self.build_inv_term_seq(ctx, term),
+            TyInvKind::Array => {
+                let TyKind::Array(elt_ty, len) = term.ty.kind() else { unreachable!() };
+                let len_inv = ctx.get_diagnostic_item(Symbol::intern("array_len_inv")).unwrap();
+                let subst = ctx.mk_args(&[GenericArg::from(*elt_ty), GenericArg::from(*len)]);
+                let len_inv = Term::call(ctx.tcx, len_inv, subst, vec![term.clone()]);
+                len_inv.conj(self.build_inv_term_seq(ctx, term))
+            }
         }
     }
 
@@ -299,12 +325,19 @@ impl<'tcx> InvariantElaborator<'tcx> {
 
         let mut arms: Vec<(_, Term<'tcx>)> = vec![];
 
-        for (var_idx, var_def) in adt_def.variants().iter().enumerate() {
-            let tuple_var = var_def.ctor.is_some();
+        // The constructors of a union are its fields, the invariant is the one of the active field
+        let constructors: Vec<_> = if adt_def.is_union() {
+            let fields = &adt_def.non_enum_variant().fields.raw;
+            fields.iter().map(|f| (f.did, false, std::slice::from_ref(f))).collect()
+        } else {
+            let variants = adt_def.variants().iter();
+            variants.map(|v| (v.def_id, v.ctor.is_some(), &v.fields.raw[..])).collect()
+        };
 
+        for (var_idx, (cons_did, tuple_var, fields)) in constructors.into_iter().enumerate() {
             let mut pats: Vec<Pattern<'tcx>> = vec![];
             let mut exp: Term<'tcx> = Term::mk_true(ctx.tcx);
-            for (field_idx, field_def) in var_def.fields.iter().enumerate() {
+            for (field_idx, field_def) in fields.iter().enumerate() {
                 let field_name: Symbol = if tuple_var {
                     Symbol::intern(&format!("a_{field_idx}"))
                 } else {
@@ -321,7 +354,7 @@ impl<'tcx> InvariantElaborator<'tcx> {
 
             arms.push((
                 Pattern::Constructor {
-                    adt: var_def.def_id,
+                    adt: cons_did,
                     substs: subst,
                     variant: var_idx.into(),
                     fields: pats,
@@ -346,6 +379,7 @@ impl<'tcx> InvariantElaborator<'tcx> {
         let seq_len;
         let seq_get;
         let int_ty;
+        let mut len_arg = None;
 
         match term.ty.kind() {
             TyKind::Slice(ty) => {
@@ -355,6 +389,15 @@ impl<'tcx> InvariantElaborator<'tcx> {
 
                 elt_ty = *ty;
             }
+            TyKind::Array(ty, len) => {
+                seq_len = ctx.get_diagnostic_item(Symbol::intern("array_len_logic")).unwrap();
+                seq_get = ctx.get_diagnostic_item(Symbol::intern("array_index_logic")).unwrap();
+                let int_id = ctx.get_diagnostic_item(Symbol::intern("creusot_int")).unwrap();
+                int_ty = ctx.type_of(int_id).skip_binder();
+
+                elt_ty = *ty;
+                len_arg = Some(GenericArg::from(*len));
+            }
             TyKind::Adt(_, subst) => {
                 seq_len = ctx.get_diagnostic_item(Symbol::intern("seq_len")).unwrap();
                 seq_get = ctx.get_diagnostic_item(Symbol::intern("seq_index")).unwrap();
@@ -368,7 +411,7 @@ impl<'tcx> InvariantElaborator<'tcx> {
 
         let index = Term::var(Symbol::intern("i"), int_ty);
 
-        let subst = ctx.mk_args(&[GenericArg::from(elt_ty)]);
+        let subst = ctx.mk_args_from_iter(std::iter::once(GenericArg::from(elt_ty)).chain(len_arg));
 
         let mut index_call = Term::call(ctx.tcx, seq_get, subst, vec![term.clone(), index.clone()]);
         index_call.ty = elt_ty;
@@ -449,6 +492,7 @@ fn axiom_name(ctx: &Why3Generator<'_>, inv_kind: TyInvKind) -> Ident {
         }
         TyInvKind::Tuple(arity) => format!("inv_tuple{arity}").into(),
         TyInvKind::Slice => "inv_slice".into(),
+        TyInvKind::Array => "inv_array".into(),
     }
 }
 
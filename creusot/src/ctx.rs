@@ -195,8 +195,11 @@ impl<'tcx, 'sess> TranslationCtx<'tcx> {
 
     pub(crate) fn body_with_facts(&mut self, def_id: LocalDefId) -> &BodyWithBorrowckFacts<'tcx> {
         if !self.bodies.contains_key(&def_id) {
-            let body = callbacks::get_body(self.tcx, def_id)
-                .unwrap_or_else(|| panic!("did not find body for {def_id:?}"));
+            // An `async fn` is verified through the coroutine implementing its future.
+            let body_id = util::async_fn_coroutine(self.tcx, def_id.to_def_id())
+                .map_or(def_id, |coroutine| coroutine.expect_local());
+            let body = callbacks::get_body(self.tcx, body_id)
+                .unwrap_or_else(|| panic!("did not find body for {body_id:?}"));
 
             // Basic clean up, replace FalseEdges with Gotos. Could potentially also replace other statement with Nops.
             // Investigate if existing MIR passes do this as part of 'post borrowck cleanup'.
@@ -340,15 +343,15 @@ impl<'tcx, 'sess> TranslationCtx<'tcx> {
                 let mut selcx = SelectionContext::new(&infcx);
                 let param_env = self.tcx.param_env(def_id);
                 for pred in es.predicates_for(self.tcx, subst) {
+                    let pred =
+                        self.tcx.try_normalize_erasing_regions(base_env, pred).unwrap_or(pred);
                     let obligation_cause = ObligationCause::dummy();
                     let obligation = Obligation::new(self.tcx, obligation_cause, param_env, pred);
                     if selcx.evaluate_root_obligation(&obligation).map_or(
                         false, // Overflow has occurred, and treat the obligation as possibly holding.
                         |result| !result.may_apply(),
                     ) {
-                        additional_predicates.push(
-                            self.tcx.try_normalize_erasing_regions(base_env, pred).unwrap_or(pred),
-                        )
+                        additional_predicates.push(pred)
                     }
                 }
             }
@@ -6,7 +6,6 @@
 extern crate log;
 extern crate rustc_ast;
 extern crate rustc_ast_ir;
-extern crate rustc_ast_pretty;
 extern crate rustc_borrowck;
 extern crate rustc_data_structures;
 extern crate rustc_driver;
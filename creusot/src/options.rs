@@ -20,6 +20,8 @@ pub struct Why3Command {
     pub config_file: PathBuf,
     pub sub: Why3Sub,
     pub args: String,
+    /// File to write the JSON proof report to
+    pub report: Option<PathBuf>,
 }
 
 #[derive(Clone)]
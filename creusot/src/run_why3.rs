@@ -1,29 +1,31 @@
 use crate::{
     backend::Why3Generator,
     options::{Options, Why3Sub},
+    translation::fmir::{LocalDecl, LocalDecls},
+    util::is_spec,
 };
 use include_dir::{include_dir, Dir};
-use rustc_ast::{
-    mut_visit::DummyAstNode,
-    ptr::P,
-    token::{Lit, LitKind},
-    Block, Expr, ExprKind, Pat, PatKind, PathSegment, Ty, TyKind, DUMMY_NODE_ID,
-};
-use rustc_ast_pretty::pprust::expr_to_string;
+use rustc_ast::Mutability;
+use rustc_hir::def::CtorKind;
+use rustc_middle::ty::{Ty, TyCtxt, TyKind};
 use rustc_span::{
-    def_id::LocalDefId, source_map::dummy_spanned, symbol::Ident, BytePos, Span, Symbol,
-    SyntaxContext, DUMMY_SP,
+    def_id::{DefId, LocalDefId},
+    BytePos, Span, Symbol, SyntaxContext, DUMMY_SP,
 };
+use serde::Serialize;
 use serde_json::Deserializer;
 use std::{
-    collections::{hash_map::Entry, HashMap},
-    fmt::{Display, Formatter, Write},
-    io::BufReader,
-    path::PathBuf,
+    collections::{hash_map::Entry, HashMap, HashSet},
+    fmt::{Display, Formatter},
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::{Path, PathBuf},
     process::{Command, Stdio},
 };
 use tempdir::TempDir;
-use why3::ce_models::{ConcreteTerm, FunLitElt, Goal, Loc, ProverResult, TBool, Term, Why3Span};
+use why3::ce_models::{
+    ConcreteTerm, Float, Goal, Loc, ModelElem, ProverResult, RecordField, Why3Span,
+};
 
 static PRELUDE: Dir<'static> = include_dir!("$CARGO_MANIFEST_DIR/../prelude");
 
@@ -69,12 +71,14 @@ pub(super) fn run_why3<'tcx>(ctx: &Why3Generator<'tcx>, file: Option<PathBuf>) {
     if matches!(why3_cmd.sub, Why3Sub::Prove) {
         command.arg("--json");
         let span_map = &ctx.span_map;
+        let mut report = Report::new(&why3_cmd.args);
         let mut child = command.stdout(Stdio::piped()).spawn().expect("could not run why3");
         let mut stdout = BufReader::new(child.stdout.take().unwrap());
         let de = Deserializer::from_reader(&mut stdout);
         for value in de.into_iter::<Goal>() {
             match value {
                 Ok(x) => {
+                    report.add_goal(ctx.tcx, span_map, &x);
                     let ProverResult { answer, step, time, .. } = &x.prover_result;
                     if answer != "Valid" {
                         let span = span_map.decode_span(&x.term.loc);
@@ -82,20 +86,26 @@ pub(super) fn run_why3<'tcx>(ctx: &Why3Generator<'tcx>, file: Option<PathBuf>) {
                             "Prover reported {answer:?} (time: {time:?}, steps: {step:?}) when trying to solve goal {:?} {:?}",
                             x.term.goal_name, x.term.explanations
                         );
-                        ctx.error(span.unwrap_or_default(), &msg).emit();
+                        let mut diag = ctx.error(span.unwrap_or_default(), &msg);
+                        let function = span.and_then(|span| enclosing_function(ctx.tcx, span));
+                        let mut seen = HashSet::new();
                         for model in x.prover_result.model_elems() {
-                            let span = span_map.decode_span(&model.location);
-                            let mut msg = format!("Model Element for {}\n", model.lsymbol.name);
-                            if span.is_none() {
-                                writeln!(msg, "Span: {:?}", &model.location).unwrap();
+                            let Some(ce) = CounterExample::new(
+                                ctx.tcx,
+                                span_map,
+                                &ctx.local_map,
+                                function,
+                                model,
+                            ) else {
+                                continue;
+                            };
+                            let CounterExample { span: ce_span, name, value } = ce;
+                            if seen.insert((ce_span, name.clone(), value.clone())) {
+                                let msg = format!("counterexample: `{name} = {value}`");
+                                diag.span_note(ce_span.or(span).unwrap_or_default(), msg);
                             }
-                            writeln!(msg, "Type: {:?}", model.value.value_type).unwrap();
-                            let term = term_to_ast(&model.value.value_term);
-                            writeln!(msg, "Term: {}", expr_to_string(&term)).unwrap();
-                            let cterm = cterm_to_ast(&model.value.value_concrete_term);
-                            writeln!(msg, "Concrete Term: {}", expr_to_string(&cterm)).unwrap();
-                            ctx.dcx().span_note(span.unwrap_or_default(), msg)
                         }
+                        diag.emit();
                     }
                 }
                 Err(err) => {
@@ -104,6 +114,11 @@ pub(super) fn run_why3<'tcx>(ctx: &Why3Generator<'tcx>, file: Option<PathBuf>) {
                 }
             }
         }
+        if let Some(path) = &why3_cmd.report {
+            report.write(path).unwrap_or_else(|err| {
+                ctx.crash_and_error(DUMMY_SP, &format!("could not write proof report: {err}"))
+            });
+        }
         if !child.wait().expect("could not close why3").success() {
             ctx.crash_and_error(DUMMY_SP, "why3 did not exit successfully")
         };
@@ -113,6 +128,124 @@ pub(super) fn run_why3<'tcx>(ctx: &Why3Generator<'tcx>, file: Option<PathBuf>) {
     }
 }
 
+/// Machine-readable summary of a `why3 prove` run, written to the file given by `--report`.
+#[derive(Serialize)]
+struct Report {
+    functions: Vec<FunctionReport>,
+    #[serde(skip)]
+    prover: Option<String>,
+    #[serde(skip)]
+    index: HashMap<Option<DefId>, usize>,
+}
+
+/// The goals generated for a single Rust function. Goals which could not be traced back to a
+/// function are grouped in an entry with a `null` name.
+#[derive(Serialize)]
+struct FunctionReport {
+    name: Option<String>,
+    goals: Vec<GoalReport>,
+}
+
+#[derive(Serialize)]
+struct GoalReport {
+    name: String,
+    explanations: Vec<String>,
+    prover: Option<String>,
+    answer: String,
+    time: f32,
+    steps: i32,
+    span: Option<SourceSpan>,
+}
+
+/// A source location, with 1-based lines and columns.
+#[derive(Serialize)]
+struct SourceSpan {
+    file: String,
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+impl Report {
+    fn new(why3_args: &str) -> Self {
+        Report { functions: Vec::new(), prover: requested_prover(why3_args), index: HashMap::new() }
+    }
+
+    fn add_goal(&mut self, tcx: TyCtxt, span_map: &SpanMap, goal: &Goal) {
+        let span = span_map.decode_span(&goal.term.loc).filter(|span| !span.is_dummy());
+        let function = span.and_then(|span| enclosing_function(tcx, span));
+        let index = *self.index.entry(function).or_insert_with(|| {
+            let name = function.map(|def_id| tcx.def_path_str(def_id));
+            self.functions.push(FunctionReport { name, goals: Vec::new() });
+            self.functions.len() - 1
+        });
+        let ProverResult { answer, step, time, .. } = &goal.prover_result;
+        self.functions[index].goals.push(GoalReport {
+            name: goal.term.goal_name.clone(),
+            explanations: goal.term.explanations.clone(),
+            prover: self.prover.clone(),
+            answer: answer.clone(),
+            time: *time,
+            steps: *step,
+            span: span.map(|span| source_span(tcx, span)),
+        })
+    }
+
+    fn write(&self, path: &Path) -> std::io::Result<()> {
+        let file = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(file, self)?;
+        Ok(())
+    }
+}
+
+/// The prover selected in the extra arguments passed to Why3, if there is exactly one.
+fn requested_prover(why3_args: &str) -> Option<String> {
+    let mut provers = Vec::new();
+    let mut args = why3_args.split_ascii_whitespace();
+    while let Some(arg) = args.next() {
+        if arg == "-P" || arg == "--prover" {
+            provers.extend(args.next());
+        } else if let Some(prover) = arg.strip_prefix("--prover=") {
+            provers.push(prover);
+        } else if let Some(prover) = arg.strip_prefix("-P") {
+            provers.push(prover);
+        }
+    }
+    match provers[..] {
+        [prover] => Some(prover.to_owned()),
+        _ => None,
+    }
+}
+
+/// The innermost function of the crate whose body contains `span`. Specification closures are
+/// attributed to the function they specify.
+fn enclosing_function(tcx: TyCtxt, span: Span) -> Option<DefId> {
+    let span = span.source_callsite();
+    let hir = tcx.hir();
+    let body_span = |def_id| hir.span_with_body(tcx.local_def_id_to_hir_id(def_id));
+    let mut def_id = hir
+        .body_owners()
+        .filter(|&def_id| body_span(def_id).contains(span))
+        .min_by_key(|&def_id| {
+            let span = body_span(def_id);
+            span.hi() - span.lo()
+        })?
+        .to_def_id();
+    while is_spec(tcx, def_id) {
+        def_id = tcx.parent(def_id);
+    }
+    Some(def_id)
+}
+
+fn source_span(tcx: TyCtxt, span: Span) -> SourceSpan {
+    let source_map = tcx.sess.source_map();
+    let (file, start_line, start_column, end_line, end_column) =
+        source_map.span_to_location_info(span);
+    let file = file.map(|file| file.name.prefer_local().to_string()).unwrap_or_default();
+    SourceSpan { file, start_line, start_column, end_line, end_column }
+}
+
 pub type SpanData = (SyntaxContext, Option<LocalDefId>);
 
 #[derive(Debug, Default)]
@@ -174,205 +307,542 @@ impl SpanMap {
     }
 }
 
-fn exp(kind: ExprKind) -> Expr {
-    Expr { id: DUMMY_NODE_ID, kind, span: DUMMY_SP, attrs: Default::default(), tokens: None }
+/// The locals of the program functions of the crate, by their name in the generated Coma. This
+/// is used to present counterexamples in terms of the Rust source.
+#[derive(Default)]
+pub struct LocalMap<'tcx> {
+    map: HashMap<DefId, HashMap<Symbol, LocalDecl<'tcx>>>,
 }
 
-fn pat(kind: PatKind) -> Pat {
-    Pat { id: DUMMY_NODE_ID, kind, span: DUMMY_SP, tokens: None }
+impl<'tcx> LocalMap<'tcx> {
+    pub(crate) fn record(&mut self, opts: &Options, def_id: DefId, locals: &LocalDecls<'tcx>) {
+        if let Some(cmd) = &opts.why3_cmd
+            && matches!(cmd.sub, Why3Sub::Prove)
+        {
+            let locals = locals.iter().map(|(name, decl)| (*name, decl.clone())).collect();
+            self.map.insert(def_id, locals);
+        }
+    }
+
+    fn get(&self, function: Option<DefId>, name: &str) -> Option<&LocalDecl<'tcx>> {
+        let locals = self.map.get(&function?)?;
+        let name = if name == "result" { "_0" } else { name };
+        locals
+            .get(&Symbol::intern(name))
+            .or_else(|| locals.get(&Symbol::intern(strip_clone_suffix(name))))
+    }
 }
 
-fn ty(kind: TyKind) -> Ty {
-    Ty { id: DUMMY_NODE_ID, kind, span: DUMMY_SP, tokens: None }
+/// A single value of a counterexample, mapped back to the Rust source.
+struct CounterExample {
+    span: Option<Span>,
+    name: String,
+    value: String,
 }
 
-fn ident(name: &str) -> Ident {
-    Ident { name: Symbol::intern(name), span: DUMMY_SP }
+impl CounterExample {
+    /// Returns `None` for model elements which do not correspond to a variable of the Rust
+    /// source, such as MIR temporaries.
+    fn new<'tcx>(
+        tcx: TyCtxt<'tcx>,
+        span_map: &SpanMap,
+        locals: &LocalMap<'tcx>,
+        function: Option<DefId>,
+        model: &ModelElem,
+    ) -> Option<Self> {
+        let name = &model.lsymbol.name;
+        let (name, ty, decl_span) = match locals.get(function, name) {
+            Some(decl) if decl.name.is_some() => {
+                (decl.name.unwrap().to_string(), Some(decl.ty), Some(decl.span))
+            }
+            Some(decl) if name == "result" || name == "_0" => {
+                ("result".to_string(), Some(decl.ty), None)
+            }
+            Some(_) => return None,
+            None if is_temporary(name) => return None,
+            None => (strip_clone_suffix(name).to_string(), None, None),
+        };
+        let span = span_map.decode_span(&model.location).filter(|span| !span.is_dummy());
+        let value = render(ty.map(|ty| RustTy { tcx, ty }), &model.value.value_concrete_term);
+        Some(CounterExample { span: span.or(decl_span), name, value })
+    }
 }
 
-fn name_to_path(name: &str) -> Expr {
-    let segments = name.split('.').map(|x| PathSegment::from_ident(ident(x))).collect();
-    exp(ExprKind::Path(None, rustc_ast::Path { span: DUMMY_SP, segments, tokens: None }))
+fn is_temporary(name: &str) -> bool {
+    name.strip_prefix('_').is_some_and(|n| !n.is_empty() && n.bytes().all(|c| c.is_ascii_digit()))
 }
 
-fn lit(name: &str, kind: LitKind, suffix: Option<&str>) -> Expr {
-    exp(ExprKind::Lit(Lit {
-        kind,
-        symbol: Symbol::intern(name),
-        suffix: suffix.map(Symbol::intern),
-    }))
+/// Why3 distinguishes the copies of a symbol with a `'N` suffix.
+fn strip_clone_suffix(name: &str) -> &str {
+    match name.rsplit_once('\'') {
+        Some((base, n)) if !n.is_empty() && n.bytes().all(|c| c.is_ascii_digit()) => base,
+        _ => name,
+    }
 }
 
-fn fun<'a>(args: impl IntoIterator<Item = &'a str>, body: Expr) -> Expr {
-    exp(ExprKind::Closure(Box::new(rustc_ast::Closure {
-        binder: rustc_ast::ClosureBinder::NotPresent,
-        capture_clause: rustc_ast::CaptureBy::Ref,
-        constness: rustc_ast::Const::No,
-        movability: rustc_ast::Movability::Movable,
-        fn_decl: P(rustc_ast::FnDecl {
-            inputs: args
-                .into_iter()
-                .map(|x| rustc_ast::Param {
-                    attrs: Default::default(),
-                    ty: P(ty(TyKind::Infer)),
-                    pat: P(pat(PatKind::Ident(rustc_ast::BindingMode::NONE, ident(x), None))),
-                    id: DUMMY_NODE_ID,
-                    span: DUMMY_SP,
-                    is_placeholder: false,
-                })
-                .collect(),
-            output: rustc_ast::FnRetTy::Default(DUMMY_SP),
-        }),
-        body: P(body),
-        fn_decl_span: DUMMY_SP,
-        fn_arg_span: DUMMY_SP,
-        coroutine_kind: None,
-    })))
+/// The Rust name of a Why3 function or constructor symbol: `Option'0.C_Some` becomes `Some`.
+fn rust_name(ls: &str) -> &str {
+    let name = strip_clone_suffix(ls.rsplit('.').next().unwrap_or(ls));
+    name.strip_prefix("C_").unwrap_or(name)
 }
 
-fn app(f: &str, args: impl IntoIterator<Item = Expr>) -> Expr {
-    let mut v: Vec<_> = args.into_iter().map(P).collect();
+/// What rendering a counterexample value needs to know about its type.
+trait TypeView: Copy {
+    /// The type of the value behind a shared reference or a box, which are translated as the
+    /// value they point to.
+    fn pointee(self) -> Self;
 
-    let take = |x: &mut P<Expr>| std::mem::replace(&mut **x, Expr::dummy());
-    match (f, &mut *v) {
-        ("(=)" | "=", [t1, t2]) => binop("=", [t1, t2].map(take)),
-        _ => exp(ExprKind::Call(P(name_to_path(f)), v.into())),
-    }
+    /// The type of the value behind a mutable reference.
+    fn mut_pointee(self) -> Option<Self>;
+
+    /// The type of the elements of a sequence-like type.
+    fn element(self) -> Option<Self>;
+
+    /// The suffix of integer literals of this type.
+    fn int_suffix(self) -> Option<&'static str>;
+
+    /// The suffix of float literals of this type.
+    fn float_suffix(self) -> Option<&'static str>;
+
+    /// The constructor of this type called `name` (the only one for structs), if it has `arity`
+    /// fields.
+    fn ctor(self, name: &str, arity: usize) -> Option<Ctor<Self>>;
+
+    /// The types of the fields of this type, if it is a tuple of size `arity`.
+    fn tuple_fields(self, arity: usize) -> Option<Vec<Self>>;
 }
 
-fn block(exp: Expr) -> Block {
-    let stmt = rustc_ast::Stmt {
-        id: DUMMY_NODE_ID,
-        kind: rustc_ast::StmtKind::Expr(P(exp)),
-        span: DUMMY_SP,
-    };
-    Block {
-        stmts: [stmt].into_iter().collect(),
-        id: DUMMY_NODE_ID,
-        rules: rustc_ast::BlockCheckMode::Default,
-        span: Default::default(),
-        tokens: None,
-        could_be_bare_literal: false,
-    }
-}
-fn ite([ift, then, elset]: [Expr; 3]) -> Expr {
-    let elset = match elset.kind {
-        ExprKind::If(..) => elset,
-        _ => exp(ExprKind::Block(P(block(elset)), None)),
-    };
+struct Ctor<T> {
+    /// The path of the constructor, such as `Option::Some`.
+    path: String,
+    kind: Option<CtorKind>,
+    fields: Vec<(String, T)>,
+}
 
-    exp(ExprKind::If(P(ift), P(block(then)), Some(P(elset))))
-}
-
-fn binop(op: &str, [t1, t2]: [Expr; 2]) -> Expr {
-    use rustc_ast::BinOpKind::*;
-    let op = match op {
-        "+" => Add,
-        "-" => Sub,
-        "*" => Mul,
-        "=" => Eq,
-        "==" => Eq,
-        "/" => Div,
-        "&&" => And,
-        "/\\" => And,
-        "||" => Or,
-        "\\/" => Or,
-        "And" => And,
-        "Tand" => And,
-        "Or" => Or,
-        "Tor" => Or,
-        _ => {
-            warn!("unsupported Binop {op}");
-            BitXor
-        }
-    };
-    exp(ExprKind::Binary(dummy_spanned(op), P(t1), P(t2)))
+#[derive(Clone, Copy)]
+struct RustTy<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    ty: Ty<'tcx>,
 }
 
-fn not(t: Expr) -> Expr {
-    let op = rustc_ast::UnOp::Not;
-    exp(ExprKind::Unary(op, P(t)))
+impl<'tcx> RustTy<'tcx> {
+    fn with(self, ty: Ty<'tcx>) -> Self {
+        RustTy { ty, ..self }
+    }
 }
 
-fn term_to_ast(t: &Term) -> Expr {
-    match t {
-        Term::Var(v) => name_to_path(&v.vs_name),
-        Term::Const { ty: _ty, val } => lit(val, LitKind::Integer, None),
-        Term::App { ls, args } => app(ls, args.iter().map(term_to_ast)),
-        Term::If { ift, then, elset } => ite([ift, then, elset].map(|x| term_to_ast(x))),
-        Term::Eps { vs, t } => app("eps!", [fun([&*vs.vs_name], term_to_ast(t))]),
-        Term::Fun { args, body } => fun(args.iter().map(|x| &*x.vs_name), term_to_ast(body)),
-        Term::Quant { quant, vs, t } => {
-            app(quant, [fun(vs.iter().map(|x| &*x.vs_name), term_to_ast(t))])
+impl TypeView for RustTy<'_> {
+    fn pointee(self) -> Self {
+        match self.ty.kind() {
+            TyKind::Ref(_, ty, Mutability::Not) => self.with(*ty),
+            TyKind::Adt(def, args) if def.is_box() => self.with(args.type_at(0)),
+            _ => self,
+        }
+    }
+
+    fn mut_pointee(self) -> Option<Self> {
+        match self.ty.kind() {
+            TyKind::Ref(_, ty, Mutability::Mut) => Some(self.with(*ty)),
+            _ => None,
         }
-        Term::Binop { binop: op, t1, t2 } => binop(op, [t1, t2].map(|x| term_to_ast(x))),
-        Term::Not(t) => not(term_to_ast(t)),
-        Term::Let(x) => app("let!", [name_to_path(x)]),
-        Term::Case(x) => app("case!", [name_to_path(x)]),
-        Term::Bool(TBool::True) => lit("true", LitKind::Bool, None),
-        Term::Bool(TBool::False) => lit("false", LitKind::Bool, None),
-        _ => lit(&format!("{t:?}"), LitKind::Str, None),
     }
-}
 
-fn fun_lit_elt_to_ast(elt: &FunLitElt) -> P<Expr> {
-    P(exp(ExprKind::Tup(
-        [&elt.indice, &elt.value].into_iter().map(|x| P(cterm_to_ast(x))).collect(),
-    )))
+    /// Arrays, slices, `Vec` or `Seq`.
+    fn element(self) -> Option<Self> {
+        match self.ty.kind() {
+            TyKind::Array(ty, _) | TyKind::Slice(ty) => Some(self.with(*ty)),
+            TyKind::Adt(_, args) => args.types().next().map(|ty| self.with(ty)),
+            _ => None,
+        }
+    }
+
+    fn int_suffix(self) -> Option<&'static str> {
+        match self.ty.kind() {
+            TyKind::Int(ity) => Some(ity.name_str()),
+            TyKind::Uint(uty) => Some(uty.name_str()),
+            _ => None,
+        }
+    }
+
+    fn float_suffix(self) -> Option<&'static str> {
+        match self.ty.kind() {
+            TyKind::Float(fty) => Some(fty.name_str()),
+            _ => None,
+        }
+    }
+
+    fn ctor(self, name: &str, arity: usize) -> Option<Ctor<Self>> {
+        let TyKind::Adt(def, subst) = self.ty.kind() else { return None };
+        if def.is_union() {
+            return None;
+        }
+        let variant = if def.is_struct() {
+            def.non_enum_variant()
+        } else {
+            def.variants().iter().find(|v| v.name.as_str() == name)?
+        };
+        if variant.fields.len() != arity {
+            return None;
+        }
+        let path = if def.is_enum() {
+            format!("{}::{}", self.tcx.item_name(def.did()), variant.name)
+        } else {
+            variant.name.to_string()
+        };
+        let fields = variant
+            .fields
+            .iter()
+            .map(|f| (f.name.to_string(), self.with(f.ty(self.tcx, subst))))
+            .collect();
+        Some(Ctor { path, kind: variant.ctor_kind(), fields })
+    }
+
+    fn tuple_fields(self, arity: usize) -> Option<Vec<Self>> {
+        match self.ty.kind() {
+            TyKind::Tuple(tys) if tys.len() == arity => {
+                Some(tys.iter().map(|ty| self.with(ty)).collect())
+            }
+            _ => None,
+        }
+    }
 }
 
-fn cterm_to_ast(t: &ConcreteTerm) -> Expr {
+/// Renders a counterexample value as a Rust expression. The type of the variable the value
+/// belongs to, when known, is used to recover the names of fields and variants and the suffixes
+/// of literals.
+fn render<T: TypeView>(ty: Option<T>, t: &ConcreteTerm) -> String {
+    let ty = ty.map(T::pointee);
+    if let Some(inner) = ty.and_then(T::mut_pointee)
+        && let Some((cur, fin)) = borrow_parts(t)
+    {
+        let [cur, fin] = [cur, fin].map(|t| render(Some(inner), t));
+        return format!("&mut {{ current: {cur}, final: {fin} }}");
+    }
+    if let Some(elts) = sequence(t) {
+        let elt_ty = ty.and_then(T::element);
+        let elts: Vec<_> = elts.into_iter().map(|t| render(elt_ty, t)).collect();
+        return format!("[{}]", elts.join(", "));
+    }
+    let untyped = |t| render::<T>(None, t);
     match t {
-        ConcreteTerm::Var(v) => name_to_path(v),
-        ConcreteTerm::Integer(n) => lit(&n.int_value, LitKind::Integer, None),
-        ConcreteTerm::Boolean(b) => lit(&b.to_string(), LitKind::Bool, None),
-        ConcreteTerm::App { ls, args } => app(ls, args.iter().map(cterm_to_ast)),
-        ConcreteTerm::If { ift, then, elset } => ite([ift, then, elset].map(|x| cterm_to_ast(x))),
-        ConcreteTerm::String(s) => lit(&format!("{s:?}"), LitKind::Str, None),
-        ConcreteTerm::Eps { var, t } => app("eps!", [fun([&**var], cterm_to_ast(t))]),
-        ConcreteTerm::Fun { args, body } => fun(args.iter().map(|x| &**x), cterm_to_ast(body)),
+        ConcreteTerm::Var(v) => render_ctor(ty, v, &[]),
+        ConcreteTerm::Boolean(b) => b.to_string(),
+        ConcreteTerm::String(s) => format!("{s:?}"),
+        ConcreteTerm::Integer(n) => {
+            let suffix = ty.and_then(T::int_suffix).unwrap_or("");
+            format!("{}{suffix}", n.int_value)
+        }
+        ConcreteTerm::Real(r) => r.real_verbatim.clone(),
+        ConcreteTerm::BitVector(bv) => {
+            let suffix = ty.and_then(T::int_suffix).unwrap_or("");
+            format!("{}{suffix}", bv.bv_value_as_decimal)
+        }
+        ConcreteTerm::Fraction { verbatim, .. } => verbatim.clone(),
+        ConcreteTerm::Float(f) => render_float(f, ty.and_then(T::float_suffix)),
+        ConcreteTerm::Proj { name, value } => match (ty, &**value) {
+            (None, ConcreteTerm::Integer(n)) => {
+                format!("{}{}", n.int_value, proj_suffix(name).unwrap_or_default())
+            }
+            _ => render(ty, value),
+        },
+        ConcreteTerm::App { ls, args } => render_ctor(ty, ls, args),
+        ConcreteTerm::Record(fields) => {
+            let fields: Vec<_> =
+                fields.iter().map(|f| format!("{}: {}", f.field, untyped(&f.value))).collect();
+            format!("{{ {} }}", fields.join(", "))
+        }
+        ConcreteTerm::If { ift, then, elset } => {
+            let [ift, then, elset] = [ift, then, elset].map(|t| untyped(t));
+            format!("if {ift} {{ {then} }} else {{ {elset} }}")
+        }
+        ConcreteTerm::Eps { .. } => "_".into(),
+        ConcreteTerm::Fun { args, body } => {
+            let args: Vec<_> = args.iter().map(|a| strip_clone_suffix(a)).collect();
+            format!("|{}| {}", args.join(", "), untyped(body))
+        }
         ConcreteTerm::Quant { quant, vs, t } => {
-            app(quant, [fun(vs.iter().map(|x| &**x), cterm_to_ast(t))])
+            let vs: Vec<_> = vs.iter().map(|v| strip_clone_suffix(v)).collect();
+            format!("{}<{}> {}", quant.to_lowercase(), vs.join(", "), untyped(t))
+        }
+        ConcreteTerm::Binop { binop, t1, t2 } => {
+            let op = match &**binop {
+                "And" => "&&",
+                "Or" => "||",
+                "Implies" => "==>",
+                "Iff" => "==",
+                op => op,
+            };
+            let [t1, t2] = [t1, t2].map(|t| untyped(t));
+            format!("{t1} {op} {t2}")
         }
-        ConcreteTerm::Binop { binop: op, t1, t2 } => binop(op, [t1, t2].map(|x| cterm_to_ast(x))),
-        ConcreteTerm::Not(t) => not(cterm_to_ast(t)),
+        ConcreteTerm::Not(t) => format!("!{}", untyped(t)),
         ConcreteTerm::FunctionLiteral { elts, other } => {
-            let arr = exp(ExprKind::Array(elts.iter().map(fun_lit_elt_to_ast).collect()));
-            app("funlit!", [arr, cterm_to_ast(other)])
+            let arg_ty = ty.and_then(T::element);
+            let mut arms: Vec<_> = elts
+                .iter()
+                .map(|e| format!("{} => {}", untyped(&e.indice), render(arg_ty, &e.value)))
+                .collect();
+            arms.push(format!("_ => {}", render(arg_ty, other)));
+            format!("|x| match x {{ {} }}", arms.join(", "))
         }
-        ConcreteTerm::Proj { name, value } => match (&**name, &**value) {
-            ("prelude.prelude.UInt8.uint8'int", ConcreteTerm::Integer(n)) => {
-                lit(&n.int_value, LitKind::Integer, Some("u8"))
-            }
-            ("prelude.prelude.UInt16.uint16'int", ConcreteTerm::Integer(n)) => {
-                lit(&n.int_value, LitKind::Integer, Some("u16"))
-            }
-            ("mach.int.UInt32Gen.uint32'int", ConcreteTerm::Integer(n)) => {
-                lit(&n.int_value, LitKind::Integer, Some("u32"))
-            }
-            ("mach.int.UInt64Gen.uint64'int", ConcreteTerm::Integer(n)) => {
-                lit(&n.int_value, LitKind::Integer, Some("u64"))
-            }
-            ("prelude.prelude.UInt128.uint16'int", ConcreteTerm::Integer(n)) => {
-                lit(&n.int_value, LitKind::Integer, Some("u128"))
-            }
-            ("prelude.prelude.Int8.int8'int", ConcreteTerm::Integer(n)) => {
-                lit(&n.int_value, LitKind::Integer, Some("i8"))
-            }
-            ("prelude.prelude.Int16.int16'int", ConcreteTerm::Integer(n)) => {
-                lit(&n.int_value, LitKind::Integer, Some("i16"))
-            }
-            ("mach.int.Int32.int32'int", ConcreteTerm::Integer(n)) => {
-                lit(&n.int_value, LitKind::Integer, Some("i32"))
-            }
-            ("mach.int.Int64.int64'int", ConcreteTerm::Integer(n)) => {
-                lit(&n.int_value, LitKind::Integer, Some("i64"))
+        ConcreteTerm::Unknown(json) => json.to_string(),
+    }
+}
+
+/// Renders the application of a Why3 symbol, as a Rust constructor when `ty` is an ADT or a
+/// tuple.
+fn render_ctor<T: TypeView>(ty: Option<T>, ls: &str, args: &[ConcreteTerm]) -> String {
+    let name = rust_name(ls);
+    if let Some(ctor) = ty.and_then(|ty| ty.ctor(name, args.len())) {
+        let fields = ctor.fields.into_iter().zip(args);
+        return match ctor.kind {
+            Some(CtorKind::Const) => ctor.path,
+            Some(CtorKind::Fn) => {
+                let args: Vec<_> = fields.map(|((_, ty), a)| render(Some(ty), a)).collect();
+                format!("{}({})", ctor.path, args.join(", "))
             }
-            ("prelude.prelude.Int128.int128'int", ConcreteTerm::Integer(n)) => {
-                lit(&n.int_value, LitKind::Integer, Some("i128"))
+            None => {
+                let fields: Vec<_> = fields
+                    .map(|((name, ty), a)| format!("{name}: {}", render(Some(ty), a)))
+                    .collect();
+                format!("{} {{ {} }}", ctor.path, fields.join(", "))
             }
-            _ => app("proj!", [name_to_path(name), cterm_to_ast(value)]),
+        };
+    }
+    if let Some(tys) = ty.and_then(|ty| ty.tuple_fields(args.len())) {
+        let args: Vec<_> = tys.into_iter().zip(args).map(|(ty, a)| render(Some(ty), a)).collect();
+        return if args.len() == 1 {
+            format!("({},)", args[0])
+        } else {
+            format!("({})", args.join(", "))
+        };
+    }
+    let args: Vec<_> = args.iter().map(|a| render::<T>(None, a)).collect();
+    match (name.strip_prefix("infix "), &args[..]) {
+        (Some(op), [a, b]) => format!("{a} {op} {b}"),
+        _ if args.is_empty() => name.to_string(),
+        _ => format!("{name}({})", args.join(", ")),
+    }
+}
+
+/// Renders a float as a decimal Rust literal, with the given suffix if any.
+fn render_float(f: &Float, suffix: Option<&str>) -> String {
+    let value = match f {
+        Float::Infinity => return format!("{}::INFINITY", suffix.unwrap_or("f64")),
+        Float::PlusZero => 0.0,
+        Float::MinusZero => -0.0,
+        Float::Value { float_hex } => match parse_hex_float(float_hex) {
+            Some(value) => value,
+            None => return float_hex.clone(),
         },
-        _ => lit(&format!("{t:?}"), LitKind::Str, None),
+    };
+    match suffix {
+        Some("f32") => format!("{:?}f32", value as f32),
+        Some(suffix) => format!("{value:?}{suffix}"),
+        None => format!("{value:?}"),
+    }
+}
+
+/// Parses a hexadecimal float such as `-0x1.8p+1`, as printed by Why3.
+fn parse_hex_float(s: &str) -> Option<f64> {
+    let (neg, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let s = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X"))?;
+    let (mantissa, exp) = s.split_once(['p', 'P']).unwrap_or((s, "0"));
+    let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let mantissa = u64::from_str_radix(&format!("{int}{frac}"), 16).ok()?;
+    let exp = exp.parse::<i32>().ok()? - 4 * frac.len() as i32;
+    // Scale in two steps, so that the powers of two are representable even for subnormals
+    let value = mantissa as f64 * 2f64.powi(exp / 2) * 2f64.powi(exp - exp / 2);
+    Some(if neg { -value } else { value })
+}
+
+/// The `current` and `final` values of a mutable borrow.
+fn borrow_parts(t: &ConcreteTerm) -> Option<(&ConcreteTerm, &ConcreteTerm)> {
+    match t {
+        ConcreteTerm::Record(fields) => {
+            let field = |name| fields.iter().find(|f| f.field == name).map(|f| &f.value);
+            Some((field("current")?, field("final")?))
+        }
+        ConcreteTerm::App { args, .. } if args.len() == 3 => Some((&args[0], &args[1])),
+        _ => None,
+    }
+}
+
+/// The elements of a sequence, when the model gives them as a function literal defined
+/// exactly on the indices `0..n`. Slices are records wrapping such a sequence.
+fn sequence(t: &ConcreteTerm) -> Option<Vec<&ConcreteTerm>> {
+    match t {
+        ConcreteTerm::FunctionLiteral { elts, .. } if !elts.is_empty() => {
+            let mut elts: Vec<_> = elts
+                .iter()
+                .map(|e| match &e.indice {
+                    ConcreteTerm::Integer(n) => {
+                        Some((n.int_value.parse::<usize>().ok()?, &e.value))
+                    }
+                    _ => None,
+                })
+                .collect::<Option<_>>()?;
+            elts.sort_by_key(|(i, _)| *i);
+            elts.iter()
+                .enumerate()
+                .all(|(i, (j, _))| i == *j)
+                .then(|| elts.into_iter().map(|(_, v)| v).collect())
+        }
+        ConcreteTerm::Record(fields) => match &fields[..] {
+            [RecordField { field, value }] if field == "elts" => sequence(value),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The suffix of an integer literal of the type projected to `int` by the Why3 symbol `name`,
+/// such as `mach.int.UInt32Gen.uint32'int`.
+fn proj_suffix(name: &str) -> Option<String> {
+    let ty = name.rsplit('.').next()?.strip_suffix("'int")?;
+    match ty {
+        "usize" | "isize" => Some(ty.to_string()),
+        _ => {
+            let (sign, width) = match ty.strip_prefix("uint") {
+                Some(width) => ("u", width),
+                None => ("i", ty.strip_prefix("int")?),
+            };
+            width.parse::<u8>().ok().map(|_| format!("{sign}{width}"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The types of the values of the model below: `u32`, `f64`, `struct Point { x: u32, y: f64 }`,
+    /// `enum Shape { Empty, Dot(Point) }`, `&mut u32` and `Vec<u32>`.
+    #[derive(Clone, Copy)]
+    enum TestTy {
+        U32,
+        F64,
+        Point,
+        Shape,
+        MutU32,
+        VecU32,
+    }
+
+    impl TypeView for TestTy {
+        fn pointee(self) -> Self {
+            self
+        }
+
+        fn mut_pointee(self) -> Option<Self> {
+            matches!(self, TestTy::MutU32).then_some(TestTy::U32)
+        }
+
+        fn element(self) -> Option<Self> {
+            matches!(self, TestTy::VecU32).then_some(TestTy::U32)
+        }
+
+        fn int_suffix(self) -> Option<&'static str> {
+            matches!(self, TestTy::U32).then_some("u32")
+        }
+
+        fn float_suffix(self) -> Option<&'static str> {
+            matches!(self, TestTy::F64).then_some("f64")
+        }
+
+        fn ctor(self, name: &str, arity: usize) -> Option<Ctor<Self>> {
+            let (path, kind, fields) = match (self, name) {
+                (TestTy::Point, _) => (
+                    "Point",
+                    None,
+                    vec![("x".to_string(), TestTy::U32), ("y".to_string(), TestTy::F64)],
+                ),
+                (TestTy::Shape, "Empty") => ("Shape::Empty", Some(CtorKind::Const), vec![]),
+                (TestTy::Shape, "Dot") => {
+                    ("Shape::Dot", Some(CtorKind::Fn), vec![("0".to_string(), TestTy::Point)])
+                }
+                _ => return None,
+            };
+            (fields.len() == arity).then(|| Ctor { path: path.to_string(), kind, fields })
+        }
+
+        fn tuple_fields(self, _: usize) -> Option<Vec<Self>> {
+            None
+        }
+    }
+
+    /// A counterexample model, as printed by `why3 prove --json`.
+    const MODEL: &str = r#"[
+        { "value": { "value_concrete_term": { "type": "Apply", "val": {
+            "app_ls": "Shape'0.C_Dot",
+            "app_args": [ { "type": "Apply", "val": {
+                "app_ls": "Point'0.C_Point",
+                "app_args": [
+                    { "type": "Integer", "val": { "int_value": "3", "int_verbatim": "3" } },
+                    { "type": "Float", "val": { "Float_value": { "float_hex": "-0x1.4p-2" } } }
+                ]
+            } } ]
+        } } } },
+        { "value": { "value_concrete_term": { "type": "Var", "val": "Shape'0.C_Empty" } } },
+        { "value": { "value_concrete_term": { "type": "Record", "val": [
+            { "field": "current",
+              "value": { "type": "Integer", "val": { "int_value": "1", "int_verbatim": "1" } } },
+            { "field": "final",
+              "value": { "type": "Integer", "val": { "int_value": "2", "int_verbatim": "2" } } }
+        ] } } },
+        { "value": { "value_concrete_term": { "type": "FunctionLiteral", "val": {
+            "funliteral_elts": [
+                { "indice": { "type": "Integer", "val": { "int_value": "1", "int_verbatim": "1" } },
+                  "value": { "type": "Integer", "val": { "int_value": "20", "int_verbatim": "20" } } },
+                { "indice": { "type": "Integer", "val": { "int_value": "0", "int_verbatim": "0" } },
+                  "value": { "type": "Integer", "val": { "int_value": "10", "int_verbatim": "10" } } }
+            ],
+            "funliteral_others": { "type": "Integer", "val": { "int_value": "0", "int_verbatim": "0" } }
+        } } } },
+        { "value": { "value_concrete_term": { "type": "Float", "val": "Infinity" } } }
+    ]"#;
+
+    #[derive(serde::Deserialize)]
+    struct Elem {
+        value: Value,
+    }
+
+    #[derive(serde::Deserialize)]
+    struct Value {
+        value_concrete_term: ConcreteTerm,
+    }
+
+    #[test]
+    fn render_model() {
+        let model: Vec<Elem> = serde_json::from_str(MODEL).unwrap();
+        let tys = [TestTy::Shape, TestTy::Shape, TestTy::MutU32, TestTy::VecU32, TestTy::F64];
+        let rendered: Vec<_> = model
+            .iter()
+            .zip(tys)
+            .map(|(elem, ty)| render(Some(ty), &elem.value.value_concrete_term))
+            .collect();
+        assert_eq!(
+            rendered,
+            [
+                "Shape::Dot(Point { x: 3u32, y: -0.3125f64 })",
+                "Shape::Empty",
+                "&mut { current: 1u32, final: 2u32 }",
+                "[10u32, 20u32]",
+                "f64::INFINITY",
+            ]
+        );
+    }
+
+    #[test]
+    fn hex_floats() {
+        assert_eq!(parse_hex_float("0x1.8p+1"), Some(3.0));
+        assert_eq!(parse_hex_float("-0x1p-1074"), Some(-5e-324));
+        assert_eq!(parse_hex_float("0x1.fffffffffffffp+1023"), Some(f64::MAX));
+        assert_eq!(
+            render_float(&Float::Value { float_hex: "0x1.99999ap-4".into() }, Some("f32")),
+            "0.1f32"
+        );
     }
 }
@@ -14,7 +14,9 @@ use crate::{
     error::InternalError,
     metadata,
     options::OutputFile,
-    validate::{validate_impls, validate_opacity, validate_traits},
+    validate::{
+        validate_impls, validate_inductive, validate_may_panic, validate_opacity, validate_traits,
+    },
 };
 use ctx::TranslationCtx;
 use heck::ToUpperCamelCase;
@@ -37,6 +39,11 @@ pub(crate) fn before_analysis(ctx: &mut TranslationCtx) -> Result<(), Box<dyn Er
         {
             let _ = ctx.term(def_id);
             validate_opacity(ctx, def_id);
+            if crate::util::inductive_kind(ctx.tcx, def_id).is_some() {
+                validate_inductive(ctx, def_id);
+            }
+        } else if matches!(ctx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn) {
+            validate_may_panic(ctx, def_id);
         }
     }
     crate::validate_terminates::validate_terminates(ctx);
@@ -67,6 +74,11 @@ pub(crate) fn after_analysis(ctx: TranslationCtx) -> Result<(), Box<dyn Error>>
             continue;
         }
 
+        // Coroutines are verified as part of the `async fn` they implement.
+        if why3.tcx.is_coroutine(def_id) {
+            continue;
+        }
+
         info!("Translating body {:?}", def_id);
         why3.translate(def_id);
     }
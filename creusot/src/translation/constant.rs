@@ -5,12 +5,17 @@ use crate::{
     translation::pearlite::Literal,
     util::get_builtin,
 };
+use rustc_hir::{def::DefKind, def_id::DefId};
 use rustc_middle::{
-    mir::{self, interpret::AllocRange, ConstValue, UnevaluatedConst},
-    ty::{Const, ConstKind, ParamEnv, Ty, TyCtxt},
+    mir::{
+        self,
+        interpret::{alloc_range, GlobalAlloc, Scalar},
+        ConstValue, UnevaluatedConst,
+    },
+    ty::{self, Const, ConstKind, GenericArgsRef, ParamEnv, Ty, TyCtxt, TyKind},
 };
-use rustc_span::Span;
-use rustc_target::abi::Size;
+use rustc_span::{Span, Symbol};
+use rustc_target::abi::{Size, FIRST_VARIANT};
 
 use super::pearlite::{Term, TermKind};
 
@@ -43,20 +48,10 @@ fn from_mir_constant_kind<'tcx>(
     // let ck = ck.normalize(ctx.tcx, env);
 
     if ck.ty().peel_refs().is_str() {
-        if let mir::Const::Val(ConstValue::Slice { data, meta }, _) = ck {
-            let start = Size::from_bytes(0);
-            let size = Size::from_bytes(meta);
-            let bytes = data
-                .inner()
-                .get_bytes_strip_provenance(&ctx.tcx, AllocRange { start, size })
-                .unwrap();
-            let string = std::str::from_utf8(bytes).unwrap();
-
-            return Operand::Constant(Term {
-                kind: TermKind::Lit(Literal::String(string.into())),
-                ty: ck.ty(),
-                span,
-            });
+        if let mir::Const::Val(val, _) = ck
+            && let Some(lit) = str_literal(ctx.tcx, val)
+        {
+            return Operand::Constant(Term { kind: TermKind::Lit(lit), ty: ck.ty(), span });
         }
     }
 
@@ -64,6 +59,21 @@ fn from_mir_constant_kind<'tcx>(
         return Operand::Promoted(p, ck.ty());
     }
 
+    if !is_scalar(ck.ty()) {
+        // Named constants are shared with the logic, rather than being inlined
+        if let mir::Const::Unevaluated(UnevaluatedConst { def, args, .. }, _) = ck
+            && matches!(ctx.def_kind(def), DefKind::Const | DefKind::AssocConst)
+        {
+            return Operand::Constant(Term { kind: TermKind::Item(def, args), ty: ck.ty(), span });
+        }
+
+        let val = match ck.eval(ctx.tcx, env, span) {
+            Ok(val) => val,
+            Err(_) => ctx.fatal_error(span, "could not evaluate constant").emit(),
+        };
+        return Operand::Constant(from_const_value(ctx, val, ck.ty(), env, span));
+    }
+
     Operand::Constant(Term {
         kind: TermKind::Lit(try_to_bits(ctx, env, ck.ty(), span, ck)),
         ty: ck.ty(),
@@ -85,13 +95,189 @@ pub(crate) fn from_ty_const<'tcx>(
         return Term { kind: TermKind::Lit(Literal::Function(u.def, u.args)), ty: c.ty(), span };
     };
 
+    // Const generic parameters are kept symbolic until the term is instantiated
     if let ConstKind::Param(_) = c.kind() {
-        ctx.crash_and_error(span, "const generic parameters are not yet supported");
+        return Term { kind: TermKind::Lit(Literal::Const(c)), ty: c.ty(), span };
+    }
+
+    if !is_scalar(c.ty()) {
+        let ck = match c.kind() {
+            ConstKind::Unevaluated(u) => mir::Const::Unevaluated(
+                UnevaluatedConst { def: u.def, args: u.args, promoted: None },
+                c.ty(),
+            ),
+            _ => mir::Const::Ty(c),
+        };
+        let val = match ck.eval(ctx.tcx, env, span) {
+            Ok(val) => val,
+            Err(_) => ctx.fatal_error(span, "could not evaluate constant").emit(),
+        };
+        return from_const_value(ctx, val, c.ty(), env, span);
     }
 
     return Term { kind: TermKind::Lit(try_to_bits(ctx, env, c.ty(), span, c)), ty: c.ty(), span };
 }
 
+/// Translates the value of the constant or immutable static `def_id`.
+pub(crate) fn from_item<'tcx>(
+    ctx: &mut TranslationCtx<'tcx>,
+    def_id: DefId,
+    subst: GenericArgsRef<'tcx>,
+    env: ParamEnv<'tcx>,
+    span: Span,
+) -> Term<'tcx> {
+    let ty = ctx.type_of(def_id).instantiate(ctx.tcx, subst);
+
+    if ctx.is_static(def_id) {
+        if !ty.is_freeze(ctx.tcx, env) {
+            ctx.fatal_error(span, "statics with interior mutability are not supported").emit()
+        }
+        let Ok(alloc) = ctx.eval_static_initializer(def_id) else {
+            ctx.fatal_error(span, "could not evaluate static").emit()
+        };
+        let alloc_id = ctx.reserve_and_set_memory_alloc(alloc);
+        let val = ConstValue::Indirect { alloc_id, offset: Size::ZERO };
+        return from_const_value(ctx, val, ty, env, span);
+    }
+
+    let uneval = ty::UnevaluatedConst::new(def_id, subst);
+    let constant = Const::new(ctx.tcx, ConstKind::Unevaluated(uneval), ty);
+    from_ty_const(ctx, constant, env, span)
+}
+
+/// Types whose constants are translated to literals
+fn is_scalar(ty: Ty) -> bool {
+    ty.is_unit()
+        || matches!(
+            ty.kind(),
+            TyKind::Bool
+                | TyKind::Char
+                | TyKind::Int(_)
+                | TyKind::Uint(_)
+                | TyKind::Float(_)
+                | TyKind::FnDef(_, _)
+        )
+}
+
+fn str_literal<'tcx>(tcx: TyCtxt<'tcx>, val: ConstValue<'tcx>) -> Option<Literal<'tcx>> {
+    if !matches!(val, ConstValue::Slice { .. } | ConstValue::Indirect { .. }) {
+        return None;
+    }
+    let string = std::str::from_utf8(val.try_get_slice_bytes_for_diagnostics(tcx)?).ok()?;
+    Some(Literal::String(string.into()))
+}
+
+/// Translates an evaluated constant of type `ty`, by destructuring it into its fields.
+fn from_const_value<'tcx>(
+    ctx: &mut TranslationCtx<'tcx>,
+    val: ConstValue<'tcx>,
+    ty: Ty<'tcx>,
+    env: ParamEnv<'tcx>,
+    span: Span,
+) -> Term<'tcx> {
+    if is_scalar(ty) {
+        // Scalars stored in memory, such as statics, are loaded first
+        let val = match val {
+            ConstValue::Indirect { alloc_id, offset } if ty.is_primitive() => {
+                let size = ctx.layout_of(env.and(ty)).map_or(Size::ZERO, |l| l.size);
+                let alloc = ctx.global_alloc(alloc_id).unwrap_memory();
+                match alloc.inner().read_scalar(&ctx.tcx, alloc_range(offset, size), false) {
+                    Ok(scalar) => ConstValue::Scalar(scalar),
+                    Err(_) => ctx.crash_and_error(span, "unsupported constant expression"),
+                }
+            }
+            _ => val,
+        };
+        let lit = try_to_bits(ctx, env, ty, span, mir::Const::Val(val, ty));
+        return Term { kind: TermKind::Lit(lit), ty, span };
+    }
+
+    match ty.kind() {
+        TyKind::Ref(_, inner, ty::Mutability::Not) if inner.is_str() => {
+            if let Some(lit) = str_literal(ctx.tcx, val) {
+                return Term { kind: TermKind::Lit(lit), ty, span };
+            }
+        }
+        TyKind::Ref(_, inner, ty::Mutability::Not) => {
+            if let Some(Scalar::Ptr(ptr, _)) = val.try_to_scalar() {
+                let (prov, offset) = ptr.into_parts();
+                let alloc_id = prov.alloc_id();
+                // References to statics are translated to the static itself
+                if let GlobalAlloc::Static(def_id) = ctx.global_alloc(alloc_id) {
+                    let kind = TermKind::Item(def_id, ty::List::empty());
+                    return Term { kind, ty, span };
+                }
+                let val = ConstValue::Indirect { alloc_id, offset };
+                return Term { ty, ..from_const_value(ctx, val, *inner, env, span) };
+            }
+        }
+        TyKind::Adt(def, _) if !def.is_union() => {
+            if let Some(fields) = destructure(ctx, val, ty, env, span) {
+                let (variant, fields) = fields;
+                let kind = TermKind::Constructor {
+                    typ: def.did(),
+                    variant: variant.unwrap_or(FIRST_VARIANT),
+                    fields,
+                };
+                return Term { kind, ty, span };
+            }
+        }
+        TyKind::Tuple(_) => {
+            if let Some((_, fields)) = destructure(ctx, val, ty, env, span) {
+                return Term { kind: TermKind::Tuple { fields }, ty, span };
+            }
+        }
+        TyKind::Array(elem, len) => {
+            if let Some((_, elems)) = destructure(ctx, val, ty, env, span) {
+                return array_term(ctx, *elem, *len, elems, span);
+            }
+        }
+        _ => {}
+    }
+
+    ctx.crash_and_error(span, "unsupported constant expression")
+}
+
+fn destructure<'tcx>(
+    ctx: &mut TranslationCtx<'tcx>,
+    val: ConstValue<'tcx>,
+    ty: Ty<'tcx>,
+    env: ParamEnv<'tcx>,
+    span: Span,
+) -> Option<(Option<rustc_target::abi::VariantIdx>, Vec<Term<'tcx>>)> {
+    let destructured = ctx.try_destructure_mir_constant_for_user_output(val, ty)?;
+    let fields = destructured
+        .fields
+        .iter()
+        .map(|&(val, ty)| from_const_value(ctx, val, ty, env, span))
+        .collect();
+    Some((destructured.variant, fields))
+}
+
+/// Builds the array of elements `elems` from the sequence of its elements.
+fn array_term<'tcx>(
+    ctx: &mut TranslationCtx<'tcx>,
+    elem: Ty<'tcx>,
+    len: Const<'tcx>,
+    elems: Vec<Term<'tcx>>,
+    span: Span,
+) -> Term<'tcx> {
+    let empty = ctx.get_diagnostic_item(Symbol::intern("seq_empty")).unwrap();
+    let push = ctx.get_diagnostic_item(Symbol::intern("seq_push")).unwrap();
+    let from_seq = ctx.get_diagnostic_item(Symbol::intern("array_from_seq")).unwrap();
+
+    let seq_subst = ctx.mk_args(&[elem.into()]);
+    let seq_ty = ctx.type_of(empty).instantiate(ctx.tcx, seq_subst);
+    let seq = elems
+        .into_iter()
+        .fold(Term { kind: TermKind::Item(empty, seq_subst), ty: seq_ty, span }, |seq, elem| {
+            Term { span, ..Term::call(ctx.tcx, push, seq_subst, vec![seq, elem]) }
+        });
+
+    let array_subst = ctx.mk_args(&[elem.into(), len.into()]);
+    Term { span, ..Term::call(ctx.tcx, from_seq, array_subst, vec![seq]) }
+}
+
 fn try_to_bits<'tcx, C: ToBits<'tcx> + std::fmt::Debug>(
     ctx: &mut TranslationCtx<'tcx>,
     // names: &mut CloneMap<'tcx>,
@@ -100,12 +286,31 @@ fn try_to_bits<'tcx, C: ToBits<'tcx> + std::fmt::Debug>(
     span: Span,
     c: C,
 ) -> Literal<'tcx> {
-    use rustc_middle::ty::{FloatTy, IntTy, UintTy};
-    use rustc_type_ir::TyKind::{Bool, Float, FnDef, Int, Uint};
+    use rustc_type_ir::TyKind::FnDef;
     let Some(bits) = c.get_bits(ctx.tcx, env, ty) else {
         ctx.fatal_error(span, &format!("Could determine value of constant. Creusot currently does not support generic associated constants.")).emit()
     };
+    if let Some(lit) = scalar_literal(ty, bits) {
+        return lit;
+    }
     match ty.kind() {
+        _ if ty.is_unit() => Literal::ZST,
+        FnDef(def_id, subst) => {
+            let method =
+                resolve_assoc_item_opt(ctx.tcx, env, *def_id, subst).unwrap_or((*def_id, subst));
+            Literal::Function(method.0, method.1)
+        }
+        _ => {
+            ctx.crash_and_error(span, "unsupported constant expression");
+        }
+    }
+}
+
+/// Interprets the bits of a constant of scalar type `ty`.
+pub(crate) fn scalar_literal(ty: Ty<'_>, bits: u128) -> Option<Literal<'_>> {
+    use rustc_middle::ty::{FloatTy, IntTy, UintTy};
+    use rustc_type_ir::TyKind::{Bool, Char, Float, Int, Uint};
+    let lit = match ty.kind() {
         Int(ity) => {
             let bits: i128 = match *ity {
                 IntTy::I128 => bits as i128,
@@ -129,32 +334,18 @@ fn try_to_bits<'tcx, C: ToBits<'tcx> + std::fmt::Debug>(
             Literal::MachUnsigned(bits, *uty)
         }
         Bool => Literal::Bool(bits == 1),
+        Char => Literal::Char(char::from_u32(bits as u32)?),
         Float(FloatTy::F32) => {
             let float = f32::from_bits(bits as u32);
-            if float.is_nan() {
-                ctx.crash_and_error(span, "NaN is not yet supported")
-            } else {
-                Literal::Float((float as f64).into(), FloatTy::F32)
-            }
+            Literal::Float((float as f64).into(), FloatTy::F32)
         }
         Float(FloatTy::F64) => {
             let float = f64::from_bits(bits as u64);
-            if float.is_nan() {
-                ctx.crash_and_error(span, "NaN is not yet supported")
-            } else {
-                Literal::Float(float.into(), FloatTy::F64)
-            }
+            Literal::Float(float.into(), FloatTy::F64)
         }
-        _ if ty.is_unit() => Literal::ZST,
-        FnDef(def_id, subst) => {
-            let method =
-                resolve_assoc_item_opt(ctx.tcx, env, *def_id, subst).unwrap_or((*def_id, subst));
-            Literal::Function(method.0, method.1)
-        }
-        _ => {
-            ctx.crash_and_error(span, &format!("unsupported constant expression"));
-        }
-    }
+        _ => return None,
+    };
+    Some(lit)
 }
 
 trait ToBits<'tcx> {
@@ -62,6 +62,8 @@ pub enum BorrowKind {
 pub enum RValue<'tcx> {
     Ghost(Term<'tcx>),
     Borrow(BorrowKind, Place<'tcx>),
+    /// A raw pointer to a place, created by `&raw const` or `&raw mut`
+    Ptr(Place<'tcx>),
     Operand(Operand<'tcx>),
     BinOp(BinOp, Operand<'tcx>, Operand<'tcx>),
     UnaryOp(UnOp, Operand<'tcx>),
@@ -78,9 +80,19 @@ impl<'tcx> RValue<'tcx> {
     pub fn is_pure(&self) -> bool {
         match &self {
             RValue::Operand(_) => true,
-            RValue::BinOp(BinOp::Add | BinOp::Mul | BinOp::Rem | BinOp::Div | BinOp::Sub, _, _) => {
-                false
-            }
+            RValue::BinOp(
+                BinOp::Add
+                | BinOp::Mul
+                | BinOp::Rem
+                | BinOp::Div
+                | BinOp::Sub
+                | BinOp::Shl
+                | BinOp::ShlUnchecked
+                | BinOp::Shr
+                | BinOp::ShrUnchecked,
+                _,
+                _,
+            ) => false,
             RValue::BinOp(_, _, _) => true,
             RValue::UnaryOp(UnOp::Neg, _) => false,
             RValue::UnaryOp(_, _) => true,
@@ -92,6 +104,7 @@ impl<'tcx> RValue<'tcx> {
             RValue::Repeat(_, _) => true,
             RValue::Ghost(_) => true,
             RValue::Borrow(_, _) => false,
+            RValue::Ptr(_) => true,
         }
     }
 }
@@ -129,6 +142,9 @@ pub enum Terminator<'tcx> {
     Switch(self::Operand<'tcx>, Branches<'tcx>),
     Return,
     Abort(Span),
+    /// Exit the function by panicking, for functions whose contract allows it.
+    /// Otherwise, this is the same as `Abort`.
+    Panic(Span),
 }
 
 impl<'tcx> Terminator<'tcx> {
@@ -150,7 +166,9 @@ impl<'tcx> Terminator<'tcx> {
                 }
             },
             Terminator::Return => Box::new(empty()) as Box<dyn Iterator<Item = BasicBlock>>,
-            Terminator::Abort(_) => Box::new(empty()) as Box<dyn Iterator<Item = BasicBlock>>,
+            Terminator::Abort(_) | Terminator::Panic(_) => {
+                Box::new(empty()) as Box<dyn Iterator<Item = BasicBlock>>
+            }
         }
     }
 }
@@ -205,6 +223,8 @@ pub type LocalDecls<'tcx> = IndexMap<Symbol, LocalDecl<'tcx>>;
 pub struct LocalDecl<'tcx> {
     // Original MIR local
     pub(crate) span: Span,
+    // Name of the local in the Rust source, for user variables
+    pub(crate) name: Option<Symbol>,
     pub(crate) ty: Ty<'tcx>,
     // Is this a MIR temporary?
     pub(crate) temp: bool,
@@ -326,7 +346,7 @@ pub(crate) fn super_visit_terminator<'tcx, V: FmirVisitor<'tcx>>(
             visitor.visit_operand(op);
         }
         Terminator::Return => (),
-        Terminator::Abort(_) => (),
+        Terminator::Abort(_) | Terminator::Panic(_) => (),
     }
 }
 
@@ -335,7 +355,7 @@ pub(crate) fn super_visit_rvalue<'tcx, V: FmirVisitor<'tcx>>(visitor: &mut V, rv
         RValue::Ghost(term) => {
             visitor.visit_term(term);
         }
-        RValue::Borrow(_, place) => {
+        RValue::Borrow(_, place) | RValue::Ptr(place) => {
             visitor.visit_place(place);
         }
         RValue::Operand(op) => {
@@ -5,7 +5,7 @@ use super::{
 };
 use crate::{
     analysis::NotFinalPlaces,
-    backend::ty::closure_accessors,
+    backend::ty::{closure_accessors, is_int},
     constant::from_mir_constant,
     ctx::*,
     fmir,
@@ -25,7 +25,12 @@ use rustc_hir::def_id::DefId;
 use rustc_index::bit_set::BitSet;
 
 use rustc_middle::{
-    mir::{self, traversal::reverse_postorder, BasicBlock, Body, Local, Location, Operand, Place},
+    mir::{
+        self,
+        traversal::reverse_postorder,
+        visit::{MutatingUseContext, PlaceContext, Visitor},
+        BasicBlock, Body, Local, Location, Operand, Place,
+    },
     ty::{
         ClosureKind::*, EarlyBinder, GenericArg, GenericArgsRef, ParamEnv, Ty, TyCtxt, TyKind,
         UpvarCapture,
@@ -85,6 +90,9 @@ pub struct BodyTranslator<'body, 'tcx> {
     // Translated locals
     locals: HashMap<Local, Symbol>,
 
+    /// For the coroutine of an `async fn`, the locals standing for its captures.
+    upvars: Option<Vec<Symbol>>,
+
     vars: LocalDecls<'tcx>,
 }
 
@@ -123,7 +131,23 @@ impl<'body, 'tcx> BodyTranslator<'body, 'tcx> {
             Some(_) => (None, None),
         };
 
-        let (vars, locals) = translate_vars(&body, &erased_locals);
+        // The coroutine of an `async fn` captures the arguments of the function, which we use
+        // in place of its environment.
+        let upvars: Option<Vec<_>> =
+            match body.local_decls.get(Local::from_u32(1)).map(|d| d.ty.kind()) {
+                Some(TyKind::Coroutine(_, args)) if body_id.promoted.is_none() => {
+                    erased_locals.insert(Local::from_u32(1));
+                    let inputs = &ctx.sig(body_id.def_id()).inputs;
+                    Some(
+                        iter::zip(inputs, args.as_coroutine().upvar_tys())
+                            .map(|(&(name, span, _), ty)| (name, span, ty))
+                            .collect(),
+                    )
+                }
+                _ => None,
+            };
+
+        let (vars, locals) = translate_vars(body, &erased_locals, upvars.as_deref());
 
         BodyTranslator {
             tcx,
@@ -131,6 +155,12 @@ impl<'body, 'tcx> BodyTranslator<'body, 'tcx> {
             body_id,
             resolver,
             locals,
+            upvars: upvars.map(|upvars| {
+                upvars
+                    .into_iter()
+                    .map(|(name, _, _)| LocalIdent::dbg_raw(Local::from_u32(1), name).symbol())
+                    .collect()
+            }),
             vars,
             erased_locals,
             current_block: (Vec::new(), None),
@@ -147,7 +177,7 @@ impl<'body, 'tcx> BodyTranslator<'body, 'tcx> {
     fn translate(mut self) -> fmir::Body<'tcx> {
         self.translate_body();
 
-        let arg_count = self.body.arg_count;
+        let arg_count = self.upvars.as_ref().map_or(self.body.arg_count, Vec::len);
 
         assert!(self.assertions.is_empty(), "unused assertions");
         assert!(self.snapshots.is_empty(), "unused snapshots");
@@ -157,6 +187,9 @@ impl<'body, 'tcx> BodyTranslator<'body, 'tcx> {
     }
 
     fn translate_body(&mut self) {
+        check_raw_pointer_derefs(self.ctx, self.body);
+        check_repr_c_union_reads(self.ctx, self.body);
+
         let mut not_final_places = NotFinalPlaces::new(self.tcx, self.body)
             .into_engine(self.tcx, self.body)
             .iterate_to_fixpoint()
@@ -186,6 +219,22 @@ impl<'body, 'tcx> BodyTranslator<'body, 'tcx> {
                                 "Only one variant can be provided for each loop",
                             );
                         }
+                        // The variant is compared at each iteration: we compare the integers that
+                        // it refers to.
+                        while body.ty.is_ref() || body.ty.is_box() {
+                            body = body.cur();
+                        }
+                        if !(body.ty.is_integral() || is_int(self.tcx, body.ty)) {
+                            self.ctx
+                                .error(
+                                    body.span,
+                                    &format!(
+                                        "Unsupported loop variant type {}, only integers and references to integers are supported",
+                                        body.ty
+                                    ),
+                                )
+                                .emit();
+                        }
                         variant = Some(body);
                     }
                     LoopSpecKind::Invariant => {
@@ -358,9 +407,17 @@ impl<'body, 'tcx> BodyTranslator<'body, 'tcx> {
     }
 
     fn translate_place(&self, _pl: mir::Place<'tcx>) -> fmir::Place<'tcx> {
-        let projection = _pl
-            .projection
-            .into_iter()
+        let (local, projection) = match (&self.upvars, &_pl.projection[..]) {
+            (Some(upvars), [mir::ProjectionElem::Field(ix, _), projection @ ..])
+                if _pl.local == Local::from_u32(1) =>
+            {
+                (upvars[ix.as_usize()], projection)
+            }
+            _ => (self.locals[&_pl.local], &_pl.projection[..]),
+        };
+        let projection = projection
+            .iter()
+            .copied()
             .map(|p| match p {
                 mir::ProjectionElem::Deref => mir::ProjectionElem::Deref,
                 mir::ProjectionElem::Field(ix, ty) => mir::ProjectionElem::Field(ix, ty),
@@ -376,7 +433,7 @@ impl<'body, 'tcx> BodyTranslator<'body, 'tcx> {
                 mir::ProjectionElem::Subtype(ty) => mir::ProjectionElem::Subtype(ty),
             })
             .collect();
-        fmir::Place { local: self.locals[&_pl.local], projection }
+        fmir::Place { local, projection }
     }
 
     fn check_ghost_term(&mut self, term: &Term<'tcx>, location: Location) {
@@ -393,9 +450,80 @@ impl<'body, 'tcx> BodyTranslator<'body, 'tcx> {
     }
 }
 
+/// Raw pointers may only be dereferenced through the permissions of `creusot_contracts::ptr_own`,
+/// which carry the value they point to.
+fn check_raw_pointer_derefs<'tcx>(ctx: &TranslationCtx<'tcx>, body: &Body<'tcx>) {
+    struct RawDerefs<'a, 'tcx> {
+        ctx: &'a TranslationCtx<'tcx>,
+        body: &'a Body<'tcx>,
+    }
+
+    impl<'tcx> Visitor<'tcx> for RawDerefs<'_, 'tcx> {
+        fn visit_place(&mut self, place: &Place<'tcx>, _: PlaceContext, location: Location) {
+            let raw_deref = place.iter_projections().any(|(base, elem)| {
+                elem == mir::ProjectionElem::Deref
+                    && base.ty(self.body, self.ctx.tcx).ty.is_unsafe_ptr()
+            });
+            if raw_deref {
+                self.ctx
+                    .error(
+                        self.body.source_info(location).span,
+                        "raw pointers can only be dereferenced through a permission",
+                    )
+                    .with_note("use the methods of `creusot_contracts::ptr_own::PtrOwn` instead")
+                    .emit();
+            }
+        }
+    }
+
+    RawDerefs { ctx, body }.visit_body(body);
+}
+
+/// Reading a field of a `#[repr(C)]` union may reinterpret the bytes of another field, which we
+/// cannot model: such reads are only allowed in trusted code.
+fn check_repr_c_union_reads<'tcx>(ctx: &TranslationCtx<'tcx>, body: &Body<'tcx>) {
+    struct UnionReads<'a, 'tcx> {
+        ctx: &'a TranslationCtx<'tcx>,
+        body: &'a Body<'tcx>,
+    }
+
+    impl<'tcx> Visitor<'tcx> for UnionReads<'_, 'tcx> {
+        fn visit_place(&mut self, place: &Place<'tcx>, context: PlaceContext, location: Location) {
+            if let PlaceContext::NonUse(_) = context {
+                return;
+            }
+            let write = matches!(
+                context,
+                PlaceContext::MutatingUse(MutatingUseContext::Store | MutatingUseContext::Call)
+            );
+            let union_read = place.iter_projections().any(|(base, elem)| {
+                matches!(elem, mir::ProjectionElem::Field(..))
+                    && base
+                        .ty(self.body, self.ctx.tcx)
+                        .ty
+                        .ty_adt_def()
+                        .is_some_and(|adt| adt.is_union() && adt.repr().c())
+                    && !(write && base.projection.len() + 1 == place.projection.len())
+            });
+            if union_read {
+                self.ctx
+                    .error(
+                        self.body.source_info(location).span,
+                        "fields of `#[repr(C)]` unions can only be read in trusted code",
+                    )
+                    .with_note("the read could reinterpret the bytes of another field")
+                    .emit();
+            }
+        }
+    }
+
+    UnionReads { ctx, body }.visit_body(body);
+}
+
 fn translate_vars<'tcx>(
     body: &Body<'tcx>,
     erased_locals: &BitSet<Local>,
+    upvars: Option<&[(Symbol, Span, Ty<'tcx>)]>,
 ) -> (LocalDecls<'tcx>, HashMap<Local, Symbol>) {
     let mut vars = LocalDecls::with_capacity(body.local_decls.len());
     let mut locals = HashMap::new();
@@ -407,8 +535,8 @@ fn translate_vars<'tcx>(
         if erased_locals.contains(loc) {
             continue;
         }
-        let sym = if !d.is_user_variable() {
-            LocalIdent::anon(loc)
+        let (sym, name) = if !d.is_user_variable() {
+            (LocalIdent::anon(loc), None)
         } else {
             let x = body.var_debug_info.iter().find(|var_info| match var_info.value {
                 Place(p) => p.as_local().map(|l| l == loc).unwrap_or(false),
@@ -428,7 +556,7 @@ fn translate_vars<'tcx>(
             let sym = LocalIdent::dbg_raw(loc, sym);
 
             *cnt += 1;
-            sym
+            (sym, Some(debug_info.name))
         };
 
         locals.insert(loc, sym.symbol());
@@ -436,11 +564,22 @@ fn translate_vars<'tcx>(
             sym.symbol(),
             LocalDecl {
                 span: d.source_info.span,
+                name,
                 ty: d.ty,
                 temp: !d.is_user_variable(),
-                arg: 0 < loc.index() && loc.index() <= body.arg_count,
+                arg: upvars.is_none() && 0 < loc.index() && loc.index() <= body.arg_count,
             },
         );
+
+        if let Some(upvars) = upvars
+            && loc == mir::RETURN_PLACE
+        {
+            for &(name, span, ty) in upvars {
+                *names.entry(name).or_insert(0) += 1;
+                let sym = LocalIdent::dbg_raw(loc, name).symbol();
+                vars.insert(sym, LocalDecl { span, name: Some(name), ty, temp: false, arg: true });
+            }
+        }
     }
     (vars, locals)
 }
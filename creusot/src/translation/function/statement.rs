@@ -14,7 +14,7 @@ use rustc_middle::{
         BinOp, BorrowKind::*, CastKind, Location, Operand::*, Place, Rvalue, SourceInfo, Statement,
         StatementKind,
     },
-    ty::adjustment::PointerCoercion,
+    ty::{adjustment::PointerCoercion, FloatTy, TyKind},
 };
 use rustc_mir_dataflow::ResultsCursor;
 
@@ -75,8 +75,24 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
         rvalue: &'_ Rvalue<'tcx>,
         loc: Location,
     ) {
-        let _ty = rvalue.ty(self.body, self.tcx);
+        let ty = rvalue.ty(self.body, self.tcx);
         let span = si.span;
+
+        // `f16` and `f128` have no model in the prelude
+        let operand_tys = match rvalue {
+            Rvalue::Cast(_, op, _) | Rvalue::UnaryOp(_, op) => vec![op.ty(self.body, self.tcx)],
+            Rvalue::BinaryOp(_, box (l, r)) => {
+                vec![l.ty(self.body, self.tcx), r.ty(self.body, self.tcx)]
+            }
+            _ => Vec::new(),
+        };
+        if let Some(fty) = std::iter::once(ty).chain(operand_tys).find_map(|ty| match ty.kind() {
+            TyKind::Float(fty @ (FloatTy::F16 | FloatTy::F128)) => Some(*fty),
+            _ => None,
+        }) {
+            self.ctx.crash_and_error(span, &format!("unsupported float type {fty:?}"))
+        }
+
         let rval: RValue<'tcx> = match rvalue {
             Rvalue::Use(op) => match op {
                 Move(_pl) | Copy(_pl) => RValue::Operand(self.translate_operand(op)),
@@ -107,8 +123,9 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
                 }
             },
             Rvalue::Discriminant(_) => return,
-            Rvalue::BinaryOp(BinOp::BitAnd, box (l, _)) if !l.ty(self.body, self.tcx).is_bool() => {
-                self.ctx.crash_and_error(si.span, "bitwise operations are currently unsupported")
+            // Pointer arithmetic must go through the specified methods of raw pointers
+            Rvalue::BinaryOp(BinOp::Offset, _) => {
+                self.ctx.crash_and_error(span, "the `offset` intrinsic is not supported")
             }
             Rvalue::BinaryOp(op, box (l, r)) | Rvalue::CheckedBinaryOp(op, box (l, r)) => {
                 RValue::BinOp(*op, self.translate_operand(l), self.translate_operand(r))
@@ -120,9 +137,13 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
 
                 match kind {
                     Tuple => RValue::Tuple(fields),
-                    Adt(adt, varix, subst, _, _) => {
+                    Adt(adt, varix, subst, _, active_field) => {
                         // self.ctx.translate(*adt);
-                        let variant = self.tcx.adt_def(*adt).variant(*varix).def_id;
+                        let adt = self.tcx.adt_def(*adt);
+                        let variant = match active_field {
+                            Some(field) => adt.non_enum_variant().fields[*field].did,
+                            None => adt.variant(*varix).def_id,
+                        };
 
                         RValue::Constructor(variant, subst, fields)
                     }
@@ -160,7 +181,15 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
                 let e = Operand::Copy(self.translate_place(*pl));
                 RValue::Len(e)
             }
-            Rvalue::Cast(CastKind::IntToInt | CastKind::PtrToPtr, op, cast_ty) => {
+            Rvalue::Cast(
+                CastKind::IntToInt
+                | CastKind::IntToFloat
+                | CastKind::FloatToInt
+                | CastKind::FloatToFloat
+                | CastKind::PtrToPtr,
+                op,
+                cast_ty,
+            ) => {
                 let op_ty = op.ty(self.body, self.tcx);
                 RValue::Cast(self.translate_operand(op), op_ty, *cast_ty)
             }
@@ -181,19 +210,26 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
                     // treat &[T; N] to &[T] casts as normal assignments
                     RValue::Operand(self.translate_operand(op))
                 } else {
-                    // TODO: Since we don't do anything with casts into `dyn` objects, just ignore them
-                    return;
+                    // Casts into `dyn` objects
+                    let op_ty = op.ty(self.body, self.tcx);
+                    RValue::Cast(self.translate_operand(op), op_ty, *ty)
                 }
             }
+            // Raw pointers are untyped addresses
+            Rvalue::Cast(
+                CastKind::PointerCoercion(
+                    PointerCoercion::MutToConstPointer | PointerCoercion::ArrayToPointer,
+                ),
+                op,
+                _,
+            ) => RValue::Operand(self.translate_operand(op)),
+            Rvalue::AddressOf(_, pl) => RValue::Ptr(self.translate_place(*pl)),
             Rvalue::Cast(
                 CastKind::PointerCoercion(_)
                 | CastKind::PointerExposeProvenance
                 | CastKind::PointerWithExposedProvenance
                 | CastKind::DynStar
-                | CastKind::IntToFloat
-                | CastKind::FloatToInt
                 | CastKind::FnPtrToPtr
-                | CastKind::FloatToFloat
                 | CastKind::Transmute,
                 _,
                 _,
@@ -204,8 +240,7 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
             Rvalue::CopyForDeref(_)
             | Rvalue::ShallowInitBox(_, _)
             | Rvalue::NullaryOp(_, _)
-            | Rvalue::ThreadLocalRef(_)
-            | Rvalue::AddressOf(_, _) => self.ctx.crash_and_error(
+            | Rvalue::ThreadLocalRef(_) => self.ctx.crash_and_error(
                 si.span,
                 &format!("MIR code used an unsupported Rvalue {:?}", rvalue),
             ),
@@ -8,21 +8,22 @@ use crate::{
         specification::inv_subst,
         traits,
     },
+    util,
 };
 use itertools::Itertools;
-use rustc_hir::def_id::DefId;
+use rustc_hir::{def_id::DefId, LangItem};
 use rustc_infer::{
     infer::{InferCtxt, TyCtxtInferExt},
     traits::{FulfillmentError, Obligation, ObligationCause, TraitEngine},
 };
 use rustc_middle::{
     mir::{
-        self, AssertKind, BasicBlock, BasicBlockData, Location, Operand, Place, Rvalue, SourceInfo,
+        self, AssertKind, BasicBlock, BasicBlockData, Location, Operand, Place, Rvalue,
         StatementKind, SwitchTargets, TerminatorKind, TerminatorKind::*,
     },
     ty::{self, GenericArgKind, GenericArgsRef, ParamEnv, Predicate, Ty, TyKind},
 };
-use rustc_span::{Span, Symbol};
+use rustc_span::{sym, Span, Symbol};
 use rustc_trait_selection::traits::{error_reporting::TypeErrCtxtExt, TraitEngineExt};
 use std::collections::HashMap;
 
@@ -33,6 +34,15 @@ use std::collections::HashMap;
 // patterns in match expressions.
 
 impl<'tcx> BodyTranslator<'_, 'tcx> {
+    // A diverging call panics if it goes through the panic machinery of `core` and `std`
+    // (`panic!`, `assert!`, `unreachable!`, ...), or if its contract allows it to panic.
+    fn is_panic(&mut self, def_id: DefId) -> bool {
+        let krate = self.tcx.crate_name(def_id.krate);
+        let module = self.tcx.def_path(def_id).data.first().and_then(|d| d.data.get_opt_name());
+        ((krate == sym::core || krate == sym::std) && module == Some(Symbol::intern("panicking")))
+            || self.ctx.sig(def_id).contract.can_panic()
+    }
+
     pub(crate) fn translate_terminator(
         &mut self,
         terminator: &mir::Terminator<'tcx>,
@@ -49,7 +59,6 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
                 let discriminant = self.translate_operand(&real_discr);
                 let switch = make_switch(
                     self.ctx,
-                    terminator.source_info,
                     real_discr.ty(self.body, self.tcx),
                     targets,
                     discriminant,
@@ -65,7 +74,13 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
             Call { func, args, destination, target, .. } => {
                 if target.is_none() {
                     // If we have no target block after the call, then we cannot move past it.
-                    self.emit_terminator(Terminator::Abort(terminator.source_info.span));
+                    let span = terminator.source_info.span;
+                    match func_defid(func) {
+                        Some((fun_def_id, _)) if self.is_panic(fun_def_id) => {
+                            self.emit_terminator(Terminator::Panic(span))
+                        }
+                        _ => self.emit_terminator(Terminator::Abort(span)),
+                    }
                     return;
                 }
 
@@ -81,11 +96,18 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
                     return;
                 }
 
-                let predicates = self
+                let mut predicates = self
                     .ctx
                     .extern_spec(fun_def_id)
                     .map(|p| p.predicates_for(self.tcx, subst))
                     .unwrap_or_else(Vec::new);
+                // The spec of a trait method assumes a `Sized` `Self`, even when called through a
+                // trait object
+                let sized = self.tcx.lang_items().sized_trait();
+                predicates.retain(|p| {
+                    !matches!(p.kind().skip_binder(), ty::PredicateKind::Clause(ty::ClauseKind::Trait(t))
+                        if Some(t.def_id()) == sized && t.self_ty().is_trait())
+                });
 
                 let infcx = self.tcx.infer_ctxt().ignoring_regions().build();
                 let res =
@@ -108,10 +130,29 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
                 }
                 let (loc, bb) = (destination, target.unwrap());
 
-                if self.is_box_new(fun_def_id) {
+                // We model the future of an `async fn` by its output, as if the function ran
+                // to completion when called. This is only correct if the future is awaited.
+                if !self.is_await_identity(fun_def_id, subst)
+                    && util::async_output(self.tcx, loc.ty(self.body, self.tcx).ty).is_some()
+                    && !self.is_awaited(*loc, bb)
+                {
+                    self.ctx
+                        .error(span, "the future of an `async fn` must be awaited immediately")
+                        .emit();
+                }
+
+                if self.is_box_new(fun_def_id) || self.is_await_identity(fun_def_id, subst) {
                     assert_eq!(func_args.len(), 1);
 
                     self.emit_assignment(&loc, RValue::Operand(func_args.remove(0)), span);
+                } else if self.tcx.lang_items().get(LangItem::FuturePoll) == Some(fun_def_id)
+                    && util::async_output(self.tcx, subst.type_at(0)).is_some()
+                {
+                    // Polling the future of an `async fn` always gives back the value it
+                    // resolves to, so that awaiting it never yields.
+                    let ready =
+                        self.poll_ready(&args[0].node, loc.ty(self.body, self.tcx).ty, span);
+                    self.emit_ghost_assign(*loc, ready, span);
                 } else {
                     let (fun_def_id, subst) =
                         resolve_function(self.ctx, self.param_env(), fun_def_id, subst, span);
@@ -167,7 +208,12 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
             FalseUnwind { real_target, .. } => {
                 self.emit_terminator(mk_goto(*real_target));
             }
-            CoroutineDrop | UnwindResume | Yield { .. } | InlineAsm { .. } => {
+            // We only support the coroutines of `async fn`, which yield when awaiting a future
+            // which is not ready. Awaited futures are always ready, so these are unreachable.
+            Yield { .. } | CoroutineDrop => {
+                self.emit_terminator(Terminator::Abort(terminator.source_info.span))
+            }
+            UnwindResume | InlineAsm { .. } => {
                 unreachable!("{:?}", terminator.kind)
             }
         }
@@ -177,6 +223,42 @@ impl<'tcx> BodyTranslator<'_, 'tcx> {
         self.tcx.def_path_str(def_id) == "std::boxed::Box::<T>::new"
     }
 
+    // The calls of the desugaring of `.await` which do not change our model of futures:
+    // `Pin` is transparent and the future of an `async fn` is its own `IntoFuture`.
+    fn is_await_identity(&self, def_id: DefId, subst: GenericArgsRef<'tcx>) -> bool {
+        self.tcx.lang_items().get(LangItem::PinNewUnchecked) == Some(def_id)
+            || (self.tcx.lang_items().get(LangItem::IntoFutureIntoFuture) == Some(def_id)
+                && util::async_output(self.tcx, subst.type_at(0)).is_some())
+    }
+
+    // Whether `future` is awaited as soon as control reaches `bb`.
+    fn is_awaited(&self, future: Place<'tcx>, bb: BasicBlock) -> bool {
+        let Call { func: func @ Operand::Constant(_), args, .. } = &self.body[bb].terminator().kind
+        else {
+            return false;
+        };
+        func_defid(func).map(|(id, _)| id)
+            == self.tcx.lang_items().get(LangItem::IntoFutureIntoFuture)
+            && matches!(&args[..], [arg] if arg.node == Operand::Move(future))
+    }
+
+    // `Poll::Ready(*fut)`, where `fut: Pin<&mut F>` is the future being polled.
+    fn poll_ready(&self, fut: &Operand<'tcx>, poll_ty: Ty<'tcx>, span: Span) -> Term<'tcx> {
+        let Operand::Move(fut) = fut else { unreachable!("polled future is not a local") };
+        let TyKind::Adt(_, pin_args) = fut.ty(self.body, self.tcx).ty.kind() else {
+            unreachable!()
+        };
+        let fut = Term::var(self.translate_place(*fut).local, pin_args.type_at(0)).cur();
+        let ready = self.tcx.require_lang_item(LangItem::PollReady, Some(span));
+        let poll = self.tcx.parent(ready);
+        let variant = self.tcx.adt_def(poll).variant_index_with_id(ready);
+        Term {
+            ty: poll_ty,
+            span,
+            kind: TermKind::Constructor { typ: poll, variant, fields: vec![fut] },
+        }
+    }
+
     fn get_explanation(&mut self, msg: &mir::AssertKind<Operand<'tcx>>) -> String {
         match msg {
             AssertKind::BoundsCheck { len: _, index: _ } => format!("index in bounds"),
@@ -277,7 +359,6 @@ pub(crate) fn discriminator_for_switch<'tcx>(bbd: &BasicBlockData<'tcx>) -> Opti
 
 pub(crate) fn make_switch<'tcx>(
     ctx: &TranslationCtx<'tcx>,
-    si: SourceInfo,
     switch_ty: Ty<'tcx>,
     targets: &SwitchTargets,
     discr: fmir::Operand<'tcx>,
@@ -307,9 +388,8 @@ pub(crate) fn make_switch<'tcx>(
 
             Terminator::Switch(discr, Branches::Bool(branches.0, branches.1))
         }
-        TyKind::Float(_) => {
-            ctx.crash_and_error(si.span, "Float patterns are currently unsupported")
-        }
+        // Float patterns are lowered to comparisons by rustc, which never switches on floats.
+        TyKind::Float(_) => unreachable!("switch on a floating-point value"),
         TyKind::Uint(_) => {
             let branches: Vec<(_, BasicBlock)> =
                 targets.iter().map(|(val, tgt)| (val, tgt)).collect();
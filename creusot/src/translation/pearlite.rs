@@ -6,12 +6,14 @@
 // The `lower` module then transforms a `Term` into a WhyML expression.
 
 use std::{
+    cell::Cell,
     collections::HashSet,
     fmt::{Display, Formatter},
     unreachable,
 };
 
 use crate::{
+    constant,
     error::{CreusotResult, Error, InternalError},
     projection_vec::{visit_projections, visit_projections_mut, ProjectionVec},
     translation::TranslationCtx,
@@ -22,24 +24,25 @@ use log::*;
 use rustc_ast::{visit::VisitorResult, LitIntType, LitKind};
 use rustc_hir::{
     def_id::{DefId, LocalDefId},
-    HirId, OwnerId,
+    HirId, OwnerId, RangeEnd,
 };
 use rustc_macros::{TyDecodable, TyEncodable, TypeFoldable, TypeVisitable};
 pub(crate) use rustc_middle::thir;
 use rustc_middle::{
-    mir::{BorrowKind, Mutability::*, ProjectionElem},
+    mir::{self, BorrowKind, Mutability::*, ProjectionElem},
     thir::{
-        AdtExpr, ArmId, Block, ClosureExpr, ExprId, ExprKind, Pat, PatKind, StmtId, StmtKind, Thir,
+        AdtExpr, ArmId, Block, ClosureExpr, ExprId, ExprKind, Pat, PatKind, PatRange,
+        PatRangeBoundary, StmtId, StmtKind, Thir,
     },
     ty::{
-        int_ty, uint_ty, CanonicalUserType, GenericArg, GenericArgs, GenericArgsRef, Ty, TyCtxt,
-        TyKind, TypeFoldable, TypeVisitable, TypeVisitableExt, UpvarArgs, UserType,
+        int_ty, uint_ty, CanonicalUserType, Const, GenericArg, GenericArgs, GenericArgsRef, Ty,
+        TyCtxt, TyKind, TypeFoldable, TypeVisitable, TypeVisitableExt, UpvarArgs, UserType,
     },
 };
 use rustc_serialize::{Decodable, Decoder, Encodable, Encoder};
 use rustc_span::{Span, Symbol, DUMMY_SP};
 use rustc_target::abi::{FieldIdx, VariantIdx};
-use rustc_type_ir::{FloatTy, IntTy, Interner, UintTy};
+use rustc_type_ir::{FloatTy, IntTy, UintTy};
 
 mod normalize;
 
@@ -60,6 +63,11 @@ pub enum BinOp {
     Ne,
     And,
     Or,
+    BitAnd,
+    BitOr,
+    BitXor,
+    Shl,
+    Shr,
 }
 
 #[derive(Clone, Debug, TyDecodable, TyEncodable, TypeFoldable, TypeVisitable)]
@@ -94,10 +102,12 @@ pub enum TermKind<'tcx> {
     },
     Forall {
         binder: (Symbol, Ty<'tcx>),
+        trigger: Vec<Trigger<'tcx>>,
         body: Box<Term<'tcx>>,
     },
     Exists {
         binder: (Symbol, Ty<'tcx>),
+        trigger: Vec<Trigger<'tcx>>,
         body: Box<Term<'tcx>>,
     },
     // TODO: Get rid of (id, subst).
@@ -106,6 +116,7 @@ pub enum TermKind<'tcx> {
         subst: GenericArgsRef<'tcx>,
         args: Vec<Term<'tcx>>,
     },
+    /// For unions, `variant` is the index of the active field.
     Constructor {
         typ: DefId,
         variant: VariantIdx,
@@ -188,18 +199,27 @@ impl<'tcx> TermKind<'tcx> {
     }
 }
 
-impl<'tcx, I: Interner> TypeFoldable<I> for Literal<'tcx> {
-    fn try_fold_with<F: rustc_middle::ty::FallibleTypeFolder<I>>(
+impl<'tcx> TypeFoldable<TyCtxt<'tcx>> for Literal<'tcx> {
+    fn try_fold_with<F: rustc_middle::ty::FallibleTypeFolder<TyCtxt<'tcx>>>(
         self,
-        _: &mut F,
+        folder: &mut F,
     ) -> Result<Self, F::Error> {
-        Ok(self)
+        match self {
+            Literal::Const(c) => Ok(Literal::Const(c.try_fold_with(folder)?)),
+            _ => Ok(self),
+        }
     }
 }
 
-impl<'tcx, I: Interner> TypeVisitable<I> for Literal<'tcx> {
-    fn visit_with<V: rustc_middle::ty::TypeVisitor<I>>(&self, _: &mut V) -> V::Result {
-        V::Result::output()
+impl<'tcx> TypeVisitable<TyCtxt<'tcx>> for Literal<'tcx> {
+    fn visit_with<V: rustc_middle::ty::TypeVisitor<TyCtxt<'tcx>>>(
+        &self,
+        visitor: &mut V,
+    ) -> V::Result {
+        match self {
+            Literal::Const(c) => c.visit_with(visitor),
+            _ => V::Result::output(),
+        }
     }
 }
 
@@ -233,11 +253,18 @@ pub enum Literal<'tcx> {
     MachSigned(i128, IntTy),
     MachUnsigned(u128, UintTy),
     Float(Float, FloatTy),
+    Char(char),
     String(String),
     ZST,
     Function(DefId, GenericArgsRef<'tcx>),
+    /// A constant which mentions const generic parameters, it is evaluated once instantiated
+    Const(Const<'tcx>),
 }
 
+/// A set of terms which instantiate a quantifier when they all appear in the context.
+#[derive(Clone, Debug, TyDecodable, TyEncodable, TypeFoldable, TypeVisitable)]
+pub struct Trigger<'tcx>(pub Vec<Term<'tcx>>);
+
 #[derive(Clone, Debug, TyDecodable, TyEncodable, TypeFoldable, TypeVisitable)]
 pub enum Pattern<'tcx> {
     Constructor {
@@ -262,7 +289,7 @@ pub(crate) fn pearlite<'tcx>(
         return Err(Error::new(ctx.def_span(id), "type checking failed"));
     };
 
-    let lower = ThirTerm { ctx, item_id: id, thir: &thir };
+    let lower = ThirTerm { ctx, item_id: id, thir: &thir, fresh: Cell::new(0) };
 
     lower.body_term(expr)
 }
@@ -271,6 +298,8 @@ struct ThirTerm<'a, 'tcx> {
     ctx: &'a TranslationCtx<'tcx>,
     item_id: LocalDefId,
     thir: &'a Thir<'tcx>,
+    /// Counter used to generate fresh variable names
+    fresh: Cell<u32>,
 }
 
 // TODO: Ensure that types are correct during this translation, in particular
@@ -333,36 +362,11 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
                     mir::BinOp::Mul | mir::BinOp::MulUnchecked => BinOp::Mul,
                     mir::BinOp::Div => BinOp::Div,
                     mir::BinOp::Rem => BinOp::Rem,
-                    mir::BinOp::BitXor => {
-                        return Err(Error::new(
-                            self.thir[expr].span,
-                            "bitwise-xors are currently unsupported",
-                        ))
-                    }
-                    mir::BinOp::BitAnd => {
-                        return Err(Error::new(
-                            self.thir[expr].span,
-                            "bitwise-ands are currently unsupported",
-                        ))
-                    }
-                    mir::BinOp::BitOr => {
-                        return Err(Error::new(
-                            self.thir[expr].span,
-                            "bitwise-ors are currently unsupported",
-                        ))
-                    }
-                    mir::BinOp::Shl | mir::BinOp::ShlUnchecked => {
-                        return Err(Error::new(
-                            self.thir[expr].span,
-                            "shifts are currently unsupported",
-                        ))
-                    }
-                    mir::BinOp::Shr | mir::BinOp::ShrUnchecked => {
-                        return Err(Error::new(
-                            self.thir[expr].span,
-                            "shifts are currently unsupported",
-                        ))
-                    }
+                    mir::BinOp::BitXor => BinOp::BitXor,
+                    mir::BinOp::BitAnd => BinOp::BitAnd,
+                    mir::BinOp::BitOr => BinOp::BitOr,
+                    mir::BinOp::Shl | mir::BinOp::ShlUnchecked => BinOp::Shl,
+                    mir::BinOp::Shr | mir::BinOp::ShrUnchecked => BinOp::Shr,
                     mir::BinOp::Lt => BinOp::Lt,
                     mir::BinOp::Le => BinOp::Le,
                     mir::BinOp::Ge => BinOp::Ge,
@@ -433,6 +437,20 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
                             },
                         }
                     }
+                    LitKind::Float(f, _) => {
+                        let TyKind::Float(fty) = ty.kind() else { unreachable!() };
+                        let digits = f.as_str().replace('_', "");
+                        let val = match fty {
+                            FloatTy::F32 => digits.parse::<f32>().map(|f| f as f64),
+                            _ => digits.parse::<f64>(),
+                        };
+                        let Ok(val) = val else {
+                            return Err(Error::new(span, "unsupported float literal"));
+                        };
+                        Literal::Float((if neg { -val } else { val }).into(), *fty)
+                    }
+                    LitKind::Char(c) => Literal::Char(c),
+                    LitKind::Str(s, _) => Literal::String(s.to_string()),
                     _ => unimplemented!("Unsupported literal"),
                 };
                 Ok(Term { ty, span, kind: TermKind::Lit(lit) })
@@ -441,7 +459,8 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
                 use Stub::*;
                 match pearlite_stub(self.ctx.tcx, f_ty) {
                     Some(Forall) => {
-                        let (binder, body) = self.quant_term(args[0])?;
+                        let (name, binder_ty, trigger, body) = self.quant_term(args[0])?;
+                        let binder = (name, binder_ty);
                         if let Some(inv_term) = type_invariant_term(
                             self.ctx,
                             self.item_id.to_def_id(),
@@ -449,13 +468,14 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
                             span,
                             binder.1.tuple_fields()[0],
                         ) {
-                            Ok(body.guarded_forall(binder, inv_term).span(span))
+                            Ok(body.guarded_forall(binder, trigger, inv_term).span(span))
                         } else {
-                            Ok(body.forall(binder).span(span))
+                            Ok(body.forall_trig(binder, trigger).span(span))
                         }
                     }
                     Some(Exists) => {
-                        let (binder, body) = self.quant_term(args[0])?;
+                        let (name, binder_ty, trigger, body) = self.quant_term(args[0])?;
+                        let binder = (name, binder_ty);
                         if let Some(inv_term) = type_invariant_term(
                             self.ctx,
                             self.item_id.to_def_id(),
@@ -463,9 +483,9 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
                             span,
                             binder.1.tuple_fields()[0],
                         ) {
-                            Ok(body.guarded_exists(binder, inv_term).span(span))
+                            Ok(body.guarded_exists(binder, trigger, inv_term).span(span))
                         } else {
-                            Ok(body.exists(binder).span(span))
+                            Ok(body.exists_trig(binder, trigger).span(span))
                         }
                     }
                     Some(Fin) => {
@@ -521,6 +541,10 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
                         Ok(Term { ty, span, kind: TermKind::Tuple { fields: vec![] } })
                     }
                     Some(Absurd) => Ok(Term { ty, span, kind: TermKind::Absurd }),
+                    Some(Stub::Trigger) => Err(Error::new(
+                        span,
+                        "triggers can only be used at the start of a quantifier body",
+                    )),
                     None => {
                         let args = args
                             .iter()
@@ -581,15 +605,15 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
 
                 fields.sort_by_key(|f| f.0);
 
+                // A union literal initializes a single field, which becomes active
+                let variant =
+                    if adt_def.is_union() { fields[0].0.as_usize().into() } else { variant_index };
+
                 let fields = fields.into_iter().map(|f| f.1).collect();
                 Ok(Term {
                     ty,
                     span,
-                    kind: TermKind::Constructor {
-                        typ: adt_def.did(),
-                        variant: variant_index,
-                        fields,
-                    },
+                    kind: TermKind::Constructor { typ: adt_def.did(), variant, fields },
                 })
             }
             // TODO: If we deref a shared borrow this should be erased?
@@ -605,6 +629,9 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
             }
             ExprKind::Match { scrutinee, ref arms, .. } => {
                 let scrutinee = self.expr_term(scrutinee)?;
+                if !arms.iter().all(|arm| self.is_simple_arm(*arm)) {
+                    return self.match_term(scrutinee, arms, ty, span);
+                }
                 let arms = arms.iter().map(|arm| self.arm_term(*arm)).collect::<Result<_, _>>()?;
 
                 Ok(Term {
@@ -621,14 +648,7 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
                 } else {
                     Term { span, ty: self.ctx.types.unit, kind: TermKind::Tuple { fields: vec![] } }
                 };
-                Ok(Term {
-                    ty,
-                    span,
-                    kind: TermKind::Match {
-                        scrutinee: Box::new(cond),
-                        arms: vec![(Pattern::Boolean(true), then), (Pattern::Boolean(false), els)],
-                    },
-                })
+                Ok(Term { ty, span, ..cond.ite(then, els) })
             }
             ExprKind::Field { lhs, name, .. } => {
                 let lhs = self.expr_term(lhs)?;
@@ -640,6 +660,15 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
                 Ok(Term { ty, span, kind: TermKind::Tuple { fields } })
             }
             ExprKind::Use { source } => self.expr_term(source),
+            // Raw pointers are untyped addresses
+            ExprKind::Cast { source } | ExprKind::PointerCoercion { source, .. }
+                if ty.is_unsafe_ptr() && self.thir[source].ty.is_unsafe_ptr() =>
+            {
+                self.expr_term(source)
+            }
+            ExprKind::Cast { .. } => {
+                Err(Error::new(span, "only casts between raw pointers are supported in logic"))
+            }
             ExprKind::NeverToAny { .. } => Ok(Term { ty, span, kind: TermKind::Absurd }),
             ExprKind::ValueTypeAscription { source, .. } => self.expr_term(source),
             ExprKind::Box { value } => self.expr_term(value),
@@ -653,6 +682,17 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
             ExprKind::NamedConst { def_id, args, ref user_ty, .. } => {
                 Ok(Term { ty, span, kind: TermKind::item(def_id, args, user_ty, self.ctx.tcx) })
             }
+            ExprKind::StaticRef { def_id, .. } => {
+                if self.ctx.static_mutability(def_id) == Some(Mut) {
+                    return Err(Error::new(span, "mutable statics cannot be used in logic"));
+                }
+                Ok(Term { ty, span, kind: TermKind::Item(def_id, GenericArgs::empty()) })
+            }
+            ExprKind::ConstParam { param, .. } => Ok(Term {
+                ty,
+                span,
+                kind: TermKind::Lit(Literal::Const(Const::new_param(self.ctx.tcx, param, ty))),
+            }),
             ExprKind::ZstLiteral { ref user_ty, .. } => match ty.kind() {
                 TyKind::FnDef(def_id, subst) => Ok(Term {
                     ty,
@@ -678,25 +718,335 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
     fn arm_term(&self, arm: ArmId) -> CreusotResult<(Pattern<'tcx>, Term<'tcx>)> {
         let arm = &self.thir[arm];
 
-        if arm.guard.is_some() {
-            return Err(Error::new(arm.span, "match guards are unsupported"));
-        }
-
         let pattern = self.pattern_term(&arm.pattern)?;
         let body = self.expr_term(arm.body)?;
 
         Ok((pattern, body))
     }
 
+    // Whether an arm can be translated directly to a Why3 match arm
+    fn is_simple_arm(&self, arm: ArmId) -> bool {
+        let arm = &self.thir[arm];
+        arm.guard.is_none() && is_simple_pattern(&arm.pattern)
+    }
+
+    // Matches with guards, or with patterns Why3 cannot express (literals, ranges and slices), are
+    // translated to a chain of matches and conditionals trying each arm in turn.
+    fn match_term(
+        &self,
+        scrutinee: Term<'tcx>,
+        arms: &[ArmId],
+        ty: Ty<'tcx>,
+        span: Span,
+    ) -> CreusotResult<Term<'tcx>> {
+        if let TermKind::Var(_) = scrutinee.kind {
+            return Ok(self.arms_term(&scrutinee, arms, ty)?.span(span));
+        }
+
+        let name = self.fresh_symbol("scrutinee");
+        let body = self.arms_term(&Term::var(name, scrutinee.ty), arms, ty)?;
+        Ok(Term {
+            ty,
+            span,
+            kind: TermKind::Let {
+                pattern: Pattern::Binder(name),
+                arg: Box::new(scrutinee),
+                body: Box::new(body),
+            },
+        })
+    }
+
+    fn arms_term(
+        &self,
+        scrutinee: &Term<'tcx>,
+        arms: &[ArmId],
+        ty: Ty<'tcx>,
+    ) -> CreusotResult<Term<'tcx>> {
+        let simple = arms.iter().take_while(|arm| self.is_simple_arm(**arm)).count();
+        if simple > 0 {
+            let mut match_arms = arms[..simple]
+                .iter()
+                .map(|arm| self.arm_term(*arm))
+                .collect::<Result<Vec<_>, _>>()?;
+            // The remaining arms are tried next. If there are none, the value is not matched by any
+            // previous arm either, but Why3 does not know it.
+            if simple < arms.len() {
+                match_arms
+                    .push((Pattern::Wildcard, self.arms_term(scrutinee, &arms[simple..], ty)?));
+            } else if !matches!(
+                match_arms.last().unwrap().0,
+                Pattern::Wildcard | Pattern::Binder(_)
+            ) {
+                match_arms.push((Pattern::Wildcard, self.unreachable_term(ty)));
+            }
+
+            return Ok(Term {
+                ty,
+                span: DUMMY_SP,
+                kind: TermKind::Match { scrutinee: Box::new(scrutinee.clone()), arms: match_arms },
+            });
+        }
+
+        let arm = &self.thir[arms[0]];
+        let body = self.expr_term(arm.body)?;
+        // By exhaustiveness, the last arm matches every value reaching it, so we do not check it.
+        if arms.len() == 1 {
+            let fallback = self.unreachable_term(ty);
+            return self.pattern_check(
+                vec![(scrutinee.clone(), &*arm.pattern)],
+                None,
+                body,
+                &fallback,
+                true,
+            );
+        }
+
+        let guard = arm.guard.map(|guard| self.expr_term(guard)).transpose()?;
+        // The other arms are bound outside of the arm so they do not capture its variables.
+        let rest = self.fresh_symbol("rest");
+        let check = self.pattern_check(
+            vec![(scrutinee.clone(), &*arm.pattern)],
+            guard,
+            body,
+            &Term::var(rest, ty),
+            false,
+        )?;
+        let others = self.arms_term(scrutinee, &arms[1..], ty)?;
+        Ok(Term {
+            ty,
+            span: DUMMY_SP,
+            kind: TermKind::Let {
+                pattern: Pattern::Binder(rest),
+                arg: Box::new(others),
+                body: Box::new(check),
+            },
+        })
+    }
+
+    // Builds a term which evaluates to `body` if each term of `tests` matches the associated pattern
+    // and `guard` holds, and to `fallback` otherwise. The tests are performed from the end.
+    // When `irrefutable` is set, the value is known to match and only the bindings are performed.
+    fn pattern_check(
+        &self,
+        mut tests: Vec<(Term<'tcx>, &'a Pat<'tcx>)>,
+        guard: Option<Term<'tcx>>,
+        body: Term<'tcx>,
+        fallback: &Term<'tcx>,
+        irrefutable: bool,
+    ) -> CreusotResult<Term<'tcx>> {
+        let tcx = self.ctx.tcx;
+        let Some((scrutinee, pat)) = tests.pop() else {
+            return Ok(match guard {
+                Some(guard) => guard.ite(body, fallback.clone()),
+                None => body,
+            });
+        };
+
+        let cond = match &pat.kind {
+            PatKind::Wild => None,
+            PatKind::AscribeUserType { subpattern, .. }
+            | PatKind::InlineConstant { subpattern, .. } => {
+                tests.push((scrutinee, &**subpattern));
+                None
+            }
+            PatKind::Deref { subpattern } => {
+                if !(pat.ty.is_box() || pat.ty.ref_mutability() == Some(Not)) {
+                    return Err(Error::new(
+                        pat.span,
+                        "only deref patterns for box and & are supported",
+                    ));
+                }
+                tests.push((Term { ty: subpattern.ty, ..scrutinee }, &**subpattern));
+                None
+            }
+            PatKind::Binding { name, ty, subpattern, .. } => {
+                if let Some(subpattern) = subpattern {
+                    tests.push((Term::var(*name, *ty), &**subpattern));
+                }
+                let body = self.pattern_check(tests, guard, body, fallback, irrefutable)?;
+                return Ok(Term {
+                    ty: body.ty,
+                    span: DUMMY_SP,
+                    kind: TermKind::Let {
+                        pattern: Pattern::Binder(*name),
+                        arg: Box::new(scrutinee),
+                        body: Box::new(body),
+                    },
+                });
+            }
+            PatKind::Variant { .. } | PatKind::Leaf { .. } => {
+                // Subpatterns which Why3 cannot express are bound to fresh variables and tested
+                // once the constructor matched.
+                let mut sub_tests = Vec::new();
+                let pattern = self.constructor_pattern(pat, &mut |sub| match &sub.kind {
+                    PatKind::Wild => Ok(Pattern::Wildcard),
+                    PatKind::Binding { name, subpattern: None, .. } => Ok(Pattern::Binder(*name)),
+                    _ => {
+                        let name = self.fresh_symbol("pat");
+                        sub_tests.push((Term::var(name, sub.ty), sub));
+                        Ok(Pattern::Binder(name))
+                    }
+                })?;
+                tests.extend(sub_tests.into_iter().rev());
+                let body = self.pattern_check(tests, guard, body, fallback, irrefutable)?;
+
+                let mut arms = vec![(pattern, body)];
+                if matches!(&pat.kind, PatKind::Variant { adt_def, .. } if adt_def.variants().len() > 1)
+                {
+                    arms.push((Pattern::Wildcard, fallback.clone()));
+                }
+                return Ok(Term {
+                    ty: arms[0].1.ty,
+                    span: DUMMY_SP,
+                    kind: TermKind::Match { scrutinee: Box::new(scrutinee), arms },
+                });
+            }
+            PatKind::Constant { value } => {
+                let lit = self.pattern_literal(*value, pat)?;
+                Some(Term::eq(tcx, scrutinee, lit))
+            }
+            PatKind::Range(box PatRange { lo, hi, end, .. }) => {
+                let lo = match lo {
+                    PatRangeBoundary::Finite(lo) => {
+                        let lo = self.pattern_literal(*lo, pat)?;
+                        lo.bin_op(tcx, BinOp::Le, scrutinee.clone())
+                    }
+                    _ => Term::mk_true(tcx),
+                };
+                let hi = match hi {
+                    PatRangeBoundary::Finite(hi) => {
+                        let op = if *end == RangeEnd::Included { BinOp::Le } else { BinOp::Lt };
+                        let hi = self.pattern_literal(*hi, pat)?;
+                        scrutinee.bin_op(tcx, op, hi)
+                    }
+                    _ => Term::mk_true(tcx),
+                };
+                Some(lo.conj(hi))
+            }
+            PatKind::Slice { prefix, slice, suffix } | PatKind::Array { prefix, slice, suffix } => {
+                if slice.as_ref().is_some_and(|slice| !matches!(slice.kind, PatKind::Wild)) {
+                    return Err(Error::new(pat.span, "subslice bindings are unsupported"));
+                }
+                let (len, index) = match scrutinee.ty.kind() {
+                    TyKind::Slice(_) => ("slice_len_logic", "slice_index_logic"),
+                    TyKind::Array(..) => ("array_len_logic", "array_index_logic"),
+                    _ => unreachable!("slice pattern on non-slice type"),
+                };
+                let len = tcx.get_diagnostic_item(Symbol::intern(len)).unwrap();
+                let index = tcx.get_diagnostic_item(Symbol::intern(index)).unwrap();
+                let (elt_ty, subst) = match scrutinee.ty.kind() {
+                    TyKind::Array(ty, len) => (*ty, tcx.mk_args(&[(*ty).into(), (*len).into()])),
+                    TyKind::Slice(ty) => (*ty, tcx.mk_args(&[(*ty).into()])),
+                    _ => unreachable!(),
+                };
+                let len = Term::call(tcx, len, subst, vec![scrutinee.clone()]);
+                let int_ty = len.ty;
+
+                let indices = (0..prefix.len()).map(|i| Term::int(tcx, i as i128)).chain(
+                    (0..suffix.len()).map(|i| Term {
+                        ty: int_ty,
+                        span: DUMMY_SP,
+                        kind: TermKind::Binary {
+                            op: BinOp::Sub,
+                            lhs: Box::new(len.clone()),
+                            rhs: Box::new(Term::int(tcx, (suffix.len() - i) as i128)),
+                        },
+                    }),
+                );
+                let elements = indices.zip(prefix.iter().chain(suffix.iter())).map(|(ix, p)| {
+                    let mut elt = Term::call(tcx, index, subst, vec![scrutinee.clone(), ix]);
+                    elt.ty = elt_ty;
+                    (elt, &**p)
+                });
+                tests.extend(elements.collect::<Vec<_>>().into_iter().rev());
+
+                let min_len = Term::int(tcx, (prefix.len() + suffix.len()) as i128);
+                match (scrutinee.ty.kind(), slice) {
+                    // The length of arrays is known statically
+                    (TyKind::Array(..), _) => None,
+                    (_, Some(_)) => Some(len.bin_op(tcx, BinOp::Ge, min_len)),
+                    (_, None) => Some(Term::eq(tcx, len, min_len)),
+                }
+            }
+            _ => return Err(Error::new(pat.span, "unsupported pattern in logic")),
+        };
+
+        let body = self.pattern_check(tests, guard, body, fallback, irrefutable)?;
+        match cond {
+            Some(cond) if !irrefutable => Ok(cond.ite(body, fallback.clone())),
+            _ => Ok(body),
+        }
+    }
+
+    fn pattern_literal(
+        &self,
+        value: mir::Const<'tcx>,
+        pat: &Pat<'tcx>,
+    ) -> CreusotResult<Term<'tcx>> {
+        let param_env = self.ctx.param_env(self.item_id.to_def_id());
+        let lit = value
+            .try_eval_bits(self.ctx.tcx, param_env)
+            .and_then(|bits| constant::scalar_literal(value.ty(), bits))
+            .ok_or_else(|| Error::new(pat.span, "unsupported constant pattern"))?;
+        Ok(Term { ty: value.ty(), span: pat.span, kind: TermKind::Lit(lit) })
+    }
+
+    fn unreachable_term(&self, ty: Ty<'tcx>) -> Term<'tcx> {
+        let tcx = self.ctx.tcx;
+        let unreachable = tcx.get_diagnostic_item(Symbol::intern("unreachable_logic")).unwrap();
+        Term::call(tcx, unreachable, tcx.mk_args(&[ty.into()]), Vec::new())
+    }
+
+    // Generated names end with a quote so they never clash with Rust identifiers
+    fn fresh_symbol(&self, prefix: &str) -> Symbol {
+        let n = self.fresh.get();
+        self.fresh.set(n + 1);
+        Symbol::intern(&format!("{prefix}{n}'"))
+    }
+
     fn pattern_term(&self, pat: &Pat<'tcx>) -> CreusotResult<Pattern<'tcx>> {
         trace!("{:?}", pat);
         match &pat.kind {
             PatKind::Wild => Ok(Pattern::Wildcard),
             PatKind::Binding { name, .. } => Ok(Pattern::Binder(*name)),
+            PatKind::Variant { .. } | PatKind::Leaf { .. } => {
+                self.constructor_pattern(pat, &mut |sub| self.pattern_term(sub))
+            }
+            PatKind::AscribeUserType { subpattern, .. } => self.pattern_term(subpattern),
+            PatKind::Deref { subpattern } => {
+                if !(pat.ty.is_box() || pat.ty.ref_mutability() == Some(Not)) {
+                    return Err(Error::new(
+                        pat.span,
+                        "only deref patterns for box and & are supported",
+                    ));
+                }
+
+                self.pattern_term(subpattern)
+            }
+            PatKind::Constant { value } => {
+                if !pat.ty.is_bool() {
+                    return Err(Error::new(
+                        pat.span,
+                        "non-boolean constant patterns are unsupported",
+                    ));
+                }
+                Ok(Pattern::Boolean(value.try_to_bool().unwrap()))
+            }
+            _ => Err(Error::new(pat.span, "unsupported pattern")),
+        }
+    }
+
+    // Translates a constructor pattern, using `sub` to translate its subpatterns
+    fn constructor_pattern<'p>(
+        &self,
+        pat: &'p Pat<'tcx>,
+        sub: &mut dyn FnMut(&'p Pat<'tcx>) -> CreusotResult<Pattern<'tcx>>,
+    ) -> CreusotResult<Pattern<'tcx>> {
+        match &pat.kind {
             PatKind::Variant { subpatterns, adt_def, variant_index, args, .. } => {
                 let mut fields: Vec<_> = subpatterns
                     .iter()
-                    .map(|pat| Ok((pat.field, self.pattern_term(&pat.pattern)?)))
+                    .map(|pat| Ok((pat.field, sub(&pat.pattern)?)))
                     .collect::<Result<_, Error>>()?;
                 fields.sort_by_key(|f| f.0);
 
@@ -718,7 +1068,7 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
             PatKind::Leaf { subpatterns } => {
                 let mut fields: Vec<_> = subpatterns
                     .iter()
-                    .map(|pat| Ok((pat.field, self.pattern_term(&pat.pattern)?)))
+                    .map(|pat| Ok((pat.field, sub(&pat.pattern)?)))
                     .collect::<Result<_, Error>>()?;
                 fields.sort_by_key(|f| f.0);
 
@@ -732,6 +1082,10 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
                         unreachable!()
                     };
 
+                    if adt_def.is_union() {
+                        return Err(Error::new(pat.span, "patterns on unions are not supported"));
+                    }
+
                     let field_count = adt_def.variants()[0usize.into()].fields.len();
                     let defaults = (0..field_count).map(|i| (i.into(), Pattern::Wildcard));
 
@@ -747,26 +1101,7 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
                     })
                 }
             }
-            PatKind::Deref { subpattern } => {
-                if !(pat.ty.is_box() || pat.ty.ref_mutability() == Some(Not)) {
-                    return Err(Error::new(
-                        pat.span,
-                        "only deref patterns for box and & are supported",
-                    ));
-                }
-
-                self.pattern_term(subpattern)
-            }
-            PatKind::Constant { value } => {
-                if !pat.ty.is_bool() {
-                    return Err(Error::new(
-                        pat.span,
-                        "non-boolean constant patterns are unsupported",
-                    ));
-                }
-                Ok(Pattern::Boolean(value.try_to_bool().unwrap()))
-            }
-            ref pk => todo!("lower_pattern: unsupported pattern kind {:?}", pk),
+            _ => unreachable!("expected constructor pattern"),
         }
     }
 
@@ -817,7 +1152,10 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
         }
     }
 
-    fn quant_term(&self, body: ExprId) -> Result<((Symbol, Ty<'tcx>), Term<'tcx>), Error> {
+    fn quant_term(
+        &self,
+        body: ExprId,
+    ) -> Result<(Symbol, Ty<'tcx>, Vec<Trigger<'tcx>>, Term<'tcx>), Error> {
         trace!("{:?}", self.thir[body].kind);
         match self.thir[body].kind {
             ExprKind::Scope { value, .. } => self.quant_term(value),
@@ -830,12 +1168,58 @@ impl<'a, 'tcx> ThirTerm<'a, 'tcx> {
                 let name = self.ctx.fn_arg_names(closure_id)[0];
                 let ty = sig.input(0).skip_binder();
 
-                Ok(((name.name, ty), pearlite(self.ctx, closure_id)?))
+                let (thir, expr) = self
+                    .ctx
+                    .thir_body(closure_id)
+                    .map_err(|_| InternalError("Cannot fetch THIR body"))?;
+                let thir = thir.borrow();
+                let lower = ThirTerm {
+                    ctx: self.ctx,
+                    item_id: closure_id,
+                    thir: &thir,
+                    fresh: Cell::new(0),
+                };
+                let (trigger, body) = lower.trigger_term(expr)?;
+
+                Ok((name.name, ty, trigger, body))
             }
             _ => Err(Error::new(self.thir[body].span, "unexpected error in quantifier")),
         }
     }
 
+    // Splits the body of a quantifier into its triggers and the actual body.
+    // The triggers are encoded as nested calls `trigger((t1, t2), body)`.
+    fn trigger_term(&self, expr: ExprId) -> CreusotResult<(Vec<Trigger<'tcx>>, Term<'tcx>)> {
+        match self.thir[expr].kind {
+            ExprKind::Scope { value, .. } => self.trigger_term(value),
+            ExprKind::Block { block } if self.thir[block].stmts.is_empty() => {
+                match self.thir[block].expr {
+                    Some(e) => self.trigger_term(e),
+                    None => Ok((Vec::new(), self.expr_term(expr)?)),
+                }
+            }
+            ExprKind::Call { ty, ref args, .. }
+                if matches!(pearlite_stub(self.ctx.tcx, ty), Some(Stub::Trigger)) =>
+            {
+                let mut terms = Vec::new();
+                let mut tuple = args[0];
+                while let ExprKind::Scope { value, .. } = self.thir[tuple].kind {
+                    tuple = value
+                }
+                let ExprKind::Tuple { ref fields } = self.thir[tuple].kind else {
+                    return Err(Error::new(self.thir[tuple].span, "malformed trigger"));
+                };
+                for f in fields.iter() {
+                    terms.push(self.expr_term(*f)?);
+                }
+                let (mut trigger, body) = self.trigger_term(args[1])?;
+                trigger.insert(0, Trigger(terms));
+                Ok((trigger, body))
+            }
+            _ => Ok((Vec::new(), self.expr_term(expr)?)),
+        }
+    }
+
     // Creates a 'logical' reborrow of a mutable borrow.
     // The idea is that the expression `&mut ** X` for `X : &mut &mut T` should produces a pearlite value of type `&mut T`.
     //
@@ -1071,6 +1455,7 @@ pub(crate) enum Stub {
     Old,
     ResultCheck,
     Absurd,
+    Trigger,
 }
 
 pub(crate) fn pearlite_stub<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> Option<Stub> {
@@ -1102,6 +1487,9 @@ pub(crate) fn pearlite_stub<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> Option<Stu
         if Some(*id) == tcx.get_diagnostic_item(Symbol::intern("absurd")) {
             return Some(Stub::Absurd);
         }
+        if Some(*id) == tcx.get_diagnostic_item(Symbol::intern("trigger")) {
+            return Some(Stub::Trigger);
+        }
         if Some(*id) == tcx.get_diagnostic_item(Symbol::intern("closure_result_constraint")) {
             return Some(Stub::ResultCheck);
         }
@@ -1111,6 +1499,21 @@ pub(crate) fn pearlite_stub<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> Option<Stu
     }
 }
 
+// Whether a pattern can be translated directly to a Why3 pattern
+fn is_simple_pattern(pat: &Pat) -> bool {
+    match &pat.kind {
+        PatKind::Wild | PatKind::Binding { subpattern: None, .. } => true,
+        PatKind::Variant { subpatterns, .. } | PatKind::Leaf { subpatterns } => {
+            subpatterns.iter().all(|p| is_simple_pattern(&p.pattern))
+        }
+        PatKind::Deref { subpattern } | PatKind::AscribeUserType { subpattern, .. } => {
+            is_simple_pattern(subpattern)
+        }
+        PatKind::Constant { .. } => pat.ty.is_bool(),
+        _ => false,
+    }
+}
+
 fn field_pattern(ty: Ty, field: FieldIdx) -> Option<Pattern> {
     match ty.kind() {
         TyKind::Tuple(fields) => {
@@ -1119,6 +1522,12 @@ fn field_pattern(ty: Ty, field: FieldIdx) -> Option<Pattern> {
 
             Some(Pattern::Tuple(fields))
         }
+        TyKind::Adt(ref adt, substs) if adt.is_union() => Some(Pattern::Constructor {
+            adt: adt.non_enum_variant().fields[field].did,
+            substs,
+            variant: field.as_usize().into(),
+            fields: vec![Pattern::Binder(Symbol::intern("a"))],
+        }),
         TyKind::Adt(ref adt, substs) => {
             assert!(adt.is_struct(), "can only access fields of struct types");
             assert_eq!(adt.variants().len(), 1, "expected a single variant");
@@ -1192,8 +1601,11 @@ pub fn super_visit_term<'tcx, V: TermVisitor<'tcx>>(term: &Term<'tcx>, visitor:
             visitor.visit_term(&*rhs);
         }
         TermKind::Unary { op: _, arg } => visitor.visit_term(&*arg),
-        TermKind::Forall { binder: _, body } => visitor.visit_term(&*body),
-        TermKind::Exists { binder: _, body } => visitor.visit_term(&*body),
+        TermKind::Forall { binder: _, trigger, body }
+        | TermKind::Exists { binder: _, trigger, body } => {
+            trigger.iter().flat_map(|t| &t.0).for_each(|t| visitor.visit_term(t));
+            visitor.visit_term(body)
+        }
         TermKind::Call { id: _, subst: _, args } => {
             args.iter().for_each(|a| visitor.visit_term(&*a))
         }
@@ -1246,8 +1658,11 @@ pub(crate) fn super_visit_mut_term<'tcx, V: TermVisitorMut<'tcx>>(
             visitor.visit_mut_term(&mut *rhs);
         }
         TermKind::Unary { op: _, arg } => visitor.visit_mut_term(&mut *arg),
-        TermKind::Forall { binder: _, body } => visitor.visit_mut_term(&mut *body),
-        TermKind::Exists { binder: _, body } => visitor.visit_mut_term(&mut *body),
+        TermKind::Forall { binder: _, trigger, body }
+        | TermKind::Exists { binder: _, trigger, body } => {
+            trigger.iter_mut().flat_map(|t| &mut t.0).for_each(|t| visitor.visit_mut_term(t));
+            visitor.visit_mut_term(&mut *body)
+        }
         TermKind::Call { id: _, subst: _, args } => {
             args.iter_mut().for_each(|a| visitor.visit_mut_term(&mut *a))
         }
@@ -1354,6 +1769,18 @@ impl<'tcx> Term<'tcx> {
         }
     }
 
+    /// Creates a term like `if self { then } else { els }`.
+    pub(crate) fn ite(self, then: Self, els: Self) -> Self {
+        Term {
+            ty: then.ty,
+            kind: TermKind::Match {
+                scrutinee: Box::new(self),
+                arms: vec![(Pattern::Boolean(true), then), (Pattern::Boolean(false), els)],
+            },
+            span: DUMMY_SP,
+        }
+    }
+
     pub(crate) fn bin_op(self, tcx: TyCtxt<'tcx>, op: BinOp, rhs: Self) -> Self {
         Term {
             ty: tcx.types.bool,
@@ -1395,6 +1822,14 @@ impl<'tcx> Term<'tcx> {
     }
 
     pub(crate) fn forall(self, binder: (Symbol, Ty<'tcx>)) -> Self {
+        self.forall_trig(binder, Vec::new())
+    }
+
+    pub(crate) fn forall_trig(
+        self,
+        binder: (Symbol, Ty<'tcx>),
+        trigger: Vec<Trigger<'tcx>>,
+    ) -> Self {
         assert!(self.ty.is_bool());
 
         // ∀ x . ⟙ = ⟙
@@ -1404,13 +1839,18 @@ impl<'tcx> Term<'tcx> {
 
         Term {
             ty: self.ty,
-            kind: TermKind::Forall { binder, body: Box::new(self) },
+            kind: TermKind::Forall { binder, trigger, body: Box::new(self) },
             span: DUMMY_SP,
         }
     }
 
     /// Creates a term like `forall<binder> guard ==> self`.
-    pub(crate) fn guarded_forall(mut self, binder: (Symbol, Ty<'tcx>), guard: Self) -> Self {
+    pub(crate) fn guarded_forall(
+        mut self,
+        binder: (Symbol, Ty<'tcx>),
+        trigger: Vec<Trigger<'tcx>>,
+        guard: Self,
+    ) -> Self {
         assert!(self.ty.is_bool() && guard.ty.is_bool());
 
         let mut inner = &mut self;
@@ -1420,10 +1860,14 @@ impl<'tcx> Term<'tcx> {
         }
 
         *inner = guard.implies(inner.clone());
-        self.forall(binder)
+        self.forall_trig(binder, trigger)
     }
 
-    pub(crate) fn exists(self, binder: (Symbol, Ty<'tcx>)) -> Self {
+    pub(crate) fn exists_trig(
+        self,
+        binder: (Symbol, Ty<'tcx>),
+        trigger: Vec<Trigger<'tcx>>,
+    ) -> Self {
         assert!(self.ty.is_bool());
 
         // ∃ x . ⟘ = ⟘
@@ -1433,13 +1877,18 @@ impl<'tcx> Term<'tcx> {
 
         Term {
             ty: self.ty,
-            kind: TermKind::Exists { binder, body: Box::new(self) },
+            kind: TermKind::Exists { binder, trigger, body: Box::new(self) },
             span: DUMMY_SP,
         }
     }
 
     /// Creates a term like `exists<binder> guard && self`.
-    pub(crate) fn guarded_exists(mut self, binder: (Symbol, Ty<'tcx>), guard: Self) -> Self {
+    pub(crate) fn guarded_exists(
+        mut self,
+        binder: (Symbol, Ty<'tcx>),
+        trigger: Vec<Trigger<'tcx>>,
+        guard: Self,
+    ) -> Self {
         assert!(self.ty.is_bool() && guard.ty.is_bool());
 
         let mut inner = &mut self;
@@ -1449,7 +1898,7 @@ impl<'tcx> Term<'tcx> {
         }
 
         *inner = guard.conj(inner.clone());
-        self.exists(binder)
+        self.exists_trig(binder, trigger)
     }
 
     pub(crate) fn span(mut self, sp: Span) -> Self {
@@ -1487,16 +1936,24 @@ impl<'tcx> Term<'tcx> {
                 rhs.subst_with_inner(bound, inv_subst)
             }
             TermKind::Unary { arg, .. } => arg.subst_with_inner(bound, inv_subst),
-            TermKind::Forall { binder, body } => {
+            TermKind::Forall { binder, trigger, body } => {
                 let mut bound = bound.clone();
                 bound.insert(binder.0);
 
+                trigger
+                    .iter_mut()
+                    .flat_map(|t| &mut t.0)
+                    .for_each(|t| t.subst_with_inner(&bound, inv_subst));
                 body.subst_with_inner(&bound, inv_subst);
             }
-            TermKind::Exists { binder, body } => {
+            TermKind::Exists { binder, trigger, body } => {
                 let mut bound = bound.clone();
                 bound.insert(binder.0);
 
+                trigger
+                    .iter_mut()
+                    .flat_map(|t| &mut t.0)
+                    .for_each(|t| t.subst_with_inner(&bound, inv_subst));
                 body.subst_with_inner(&bound, inv_subst);
             }
             TermKind::Call { args, .. } => {
@@ -1565,16 +2022,18 @@ impl<'tcx> Term<'tcx> {
                 rhs.free_vars_inner(bound, free)
             }
             TermKind::Unary { arg, .. } => arg.free_vars_inner(bound, free),
-            TermKind::Forall { binder, body } => {
+            TermKind::Forall { binder, trigger, body } => {
                 let mut bound = bound.clone();
                 bound.insert(binder.0);
 
+                trigger.iter().flat_map(|t| &t.0).for_each(|t| t.free_vars_inner(&bound, free));
                 body.free_vars_inner(&bound, free);
             }
-            TermKind::Exists { binder, body } => {
+            TermKind::Exists { binder, trigger, body } => {
                 let mut bound = bound.clone();
                 bound.insert(binder.0);
 
+                trigger.iter().flat_map(|t| &t.0).for_each(|t| t.free_vars_inner(&bound, free));
                 body.free_vars_inner(&bound, free);
             }
             TermKind::Call { args, .. } => {
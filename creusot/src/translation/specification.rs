@@ -1,4 +1,4 @@
-use super::pearlite::{normalize, pearlite_stub, Literal, Stub, Term, TermKind};
+use super::pearlite::{normalize, pearlite_stub, BinOp, Literal, Stub, Term, TermKind};
 use crate::{
     ctx::*,
     error::{Error, InternalError},
@@ -8,7 +8,7 @@ use rustc_ast::{
     ast::{AttrArgs, AttrArgsEq},
     AttrItem,
 };
-use rustc_hir::def_id::DefId;
+use rustc_hir::{def_id::DefId, LangItem, Unsafety};
 use rustc_macros::{TyDecodable, TyEncodable, TypeFoldable, TypeVisitable};
 use rustc_middle::{
     mir::{Body, Local, SourceInfo, SourceScope, OUTERMOST_SOURCE_SCOPE},
@@ -23,7 +23,9 @@ pub struct PreContract<'tcx> {
     pub(crate) variant: Option<Term<'tcx>>,
     pub(crate) requires: Vec<Term<'tcx>>,
     pub(crate) ensures: Vec<Term<'tcx>>,
+    pub(crate) panics_if: Vec<Term<'tcx>>,
     pub(crate) no_panic: bool,
+    pub(crate) may_panic: bool,
     pub(crate) terminates: bool,
 }
 
@@ -48,16 +50,33 @@ impl<'tcx> PreContract<'tcx> {
     }
 
     pub(crate) fn is_empty(&self) -> bool {
-        self.requires.is_empty() && self.ensures.is_empty() && self.variant.is_none()
+        self.requires.is_empty()
+            && self.ensures.is_empty()
+            && self.variant.is_none()
+            && !self.can_panic()
+    }
+
+    /// Whether panicking is an allowed exit of the function, through `#[may_panic]`
+    /// or `#[panics_if]`.
+    pub(crate) fn can_panic(&self) -> bool {
+        self.may_panic || !self.panics_if.is_empty()
     }
 
     #[allow(dead_code)]
     pub(crate) fn terms(&self) -> impl Iterator<Item = &Term<'tcx>> {
-        self.requires.iter().chain(self.ensures.iter()).chain(self.variant.iter())
+        self.requires
+            .iter()
+            .chain(self.ensures.iter())
+            .chain(self.panics_if.iter())
+            .chain(self.variant.iter())
     }
 
     fn terms_mut(&mut self) -> impl Iterator<Item = &mut Term<'tcx>> {
-        self.requires.iter_mut().chain(self.ensures.iter_mut()).chain(self.variant.iter_mut())
+        self.requires
+            .iter_mut()
+            .chain(self.ensures.iter_mut())
+            .chain(self.panics_if.iter_mut())
+            .chain(self.variant.iter_mut())
     }
 
     pub(crate) fn ensures_conj(&self, tcx: TyCtxt<'tcx>) -> Term<'tcx> {
@@ -68,6 +87,17 @@ impl<'tcx> PreContract<'tcx> {
         postcond
     }
 
+    /// The condition under which the function may panic: the disjunction of its `panics_if`
+    /// clauses, `true` for a `#[may_panic]` function without any, `false` otherwise.
+    pub(crate) fn panics_disj(&self, tcx: TyCtxt<'tcx>) -> Term<'tcx> {
+        let mut panics = self.panics_if.clone();
+
+        let Some(cond) = panics.pop() else {
+            return if self.may_panic { Term::mk_true(tcx) } else { Term::mk_false(tcx) };
+        };
+        panics.into_iter().rfold(cond, |acc, p| p.bin_op(tcx, BinOp::Or, acc))
+    }
+
     pub(crate) fn requires_conj(&self, tcx: TyCtxt<'tcx>) -> Term<'tcx> {
         let mut requires = self.requires.clone();
 
@@ -82,7 +112,9 @@ pub struct ContractClauses {
     variant: Option<DefId>,
     requires: Vec<DefId>,
     ensures: Vec<DefId>,
+    panics_if: Vec<DefId>,
     pub(crate) no_panic: bool,
+    pub(crate) may_panic: bool,
     pub(crate) terminates: bool,
 }
 
@@ -92,7 +124,9 @@ impl ContractClauses {
             variant: None,
             requires: Vec::new(),
             ensures: Vec::new(),
+            panics_if: Vec::new(),
             no_panic: false,
+            may_panic: false,
             terminates: false,
         }
     }
@@ -111,6 +145,12 @@ impl ContractClauses {
             out.ensures.push(term);
         }
 
+        for panic_id in self.panics_if {
+            log::trace!("panics_if clause {:?}", panic_id);
+            let term = ctx.term(panic_id).unwrap().clone();
+            out.panics_if.push(term);
+        }
+
         if let Some(var_id) = self.variant {
             log::trace!("variant clause {:?}", var_id);
             let term = ctx.term(var_id).unwrap().clone();
@@ -118,13 +158,20 @@ impl ContractClauses {
         };
         log::trace!("no_panic: {}", self.no_panic);
         out.no_panic = self.no_panic;
+        log::trace!("may_panic: {}", self.may_panic);
+        out.may_panic = self.may_panic;
         log::trace!("terminates: {}", self.terminates);
         out.terminates = self.terminates;
         EarlyBinder::bind(out)
     }
 
     pub(crate) fn iter_ids(&self) -> impl Iterator<Item = DefId> + '_ {
-        self.requires.iter().chain(self.ensures.iter()).chain(self.variant.iter()).cloned()
+        self.requires
+            .iter()
+            .chain(self.ensures.iter())
+            .chain(self.panics_if.iter())
+            .chain(self.variant.iter())
+            .cloned()
     }
 }
 
@@ -137,8 +184,10 @@ impl ScopeTree {
         let mut scope_tree: HashMap<SourceScope, (HashSet<_>, Option<_>)> = Default::default();
 
         for var_info in &body.var_debug_info {
-            // All variables in the DebugVarInfo should be user variables and thus be just locals
+            // All variables in the DebugVarInfo should be user variables and thus be just locals,
+            // except for the captures of coroutines, which are fields of their environment.
             let loc = match var_info.value {
+                Place(p) if p.local == Local::from_u32(1) && p.as_local().is_none() => continue,
                 Place(p) => p.as_local().unwrap(),
                 _ => panic!(),
             };
@@ -249,12 +298,16 @@ pub(crate) fn contract_clauses_of(
             contract.requires.push(get_creusot_item()?)
         } else if attributes_matches(attr, &["creusot", "clause", "ensures"]) {
             contract.ensures.push(get_creusot_item()?);
+        } else if attributes_matches(attr, &["creusot", "clause", "panics_if"]) {
+            contract.panics_if.push(get_creusot_item()?);
         } else if attributes_matches(attr, &["creusot", "clause", "variant"]) {
             contract.variant = Some(get_creusot_item()?);
         } else if attributes_matches(attr, &["creusot", "clause", "terminates"]) {
             contract.terminates = true;
         } else if attributes_matches(attr, &["creusot", "clause", "no_panic"]) {
             contract.no_panic = true;
+        } else if attributes_matches(attr, &["creusot", "clause", "may_panic"]) {
+            contract.may_panic = true;
         }
     }
 
@@ -285,13 +338,15 @@ pub(crate) fn inherited_extern_spec<'tcx>(
         }
 
         let assoc = ctx.opt_associated_item(def_id)?;
-        let trait_ref = ctx.impl_trait_ref(assoc.container_id(ctx.tcx))?;
+        let impl_id = assoc.container_id(ctx.tcx);
+        let trait_ref = ctx.impl_trait_ref(impl_id)?;
         let id = assoc.trait_item_def_id?;
 
         if ctx.extern_spec(id).is_none() {
             return None;
         }
-        (id, trait_ref.instantiate(ctx.tcx, subst).args)
+        // Keep the generic parameters of the method itself, after those of the trait
+        (id, subst.rebase_onto(ctx.tcx, impl_id, trait_ref.instantiate(ctx.tcx, subst).args))
     }
 }
 
@@ -314,8 +369,10 @@ pub(crate) fn contract_of<'tcx>(
         let mut contract =
             contract_clauses_of(ctx, def_id).unwrap().get_pre(ctx).instantiate(ctx.tcx, subst);
 
+        // Unsafe functions without a specification cannot be called: the conditions for
+        // their safety are unknown
         if contract.is_empty()
-            && ctx.externs.get(def_id.krate).is_some()
+            && (ctx.externs.get(def_id.krate).is_some() || is_unsafe_extern(ctx.tcx, def_id))
             && util::item_type(ctx.tcx, def_id) == ItemType::Program
         {
             contract.requires.push(Term::mk_false(ctx.tcx));
@@ -325,6 +382,14 @@ pub(crate) fn contract_of<'tcx>(
     }
 }
 
+fn is_unsafe_extern(tcx: TyCtxt, def_id: DefId) -> bool {
+    !def_id.is_local()
+        && tcx.def_kind(def_id).is_fn_like()
+        && tcx.fn_sig(def_id).skip_binder().unsafety() == Unsafety::Unsafe
+        // Used by the desugaring of `.await`
+        && tcx.lang_items().get(LangItem::GetContext) != Some(def_id)
+}
+
 // These methods are allowed to cheat the purity restrictions because they are lang items we cannot redefine
 pub(crate) fn is_overloaded_item(tcx: TyCtxt, def_id: DefId) -> bool {
     let def_path = tcx.def_path_str(def_id);
@@ -339,6 +404,8 @@ pub(crate) fn is_overloaded_item(tcx: TyCtxt, def_id: DefId) -> bool {
         || def_path.ends_with("::ops::Deref::deref")
         || def_path.ends_with("::ops::DerefMut::deref_mut")
         || def_path.ends_with("Snapshot::<T>::from_fn")
+        // Snapshots are ghost code, which always terminates
+        || tcx.is_diagnostic_item(Symbol::intern("snapshot_from_fn"), def_id)
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -57,6 +57,13 @@ impl<'tcx> TranslationCtx<'tcx> {
         let implementor_map =
             self.with_stable_hashing_context(|hcx| implementor_map.to_sorted(&hcx, true));
         for (&trait_item, &impl_item) in implementor_map {
+            // Items requiring `Self: Sized` can never be used on a trait object
+            if trait_ref.skip_binder().self_ty().is_trait()
+                && self.generics_require_sized_self(trait_item)
+            {
+                continue;
+            }
+
             if is_law(self.tcx, trait_item) {
                 laws.push(impl_item);
             }
@@ -145,12 +152,22 @@ fn logic_refinement_term<'tcx>(
     impl_postcond.subst(&subst);
     let trait_postcond = trait_sig.contract.ensures_conj(ctx.tcx);
 
+    // The implementation may only panic when the trait allows it. When the trait states when the
+    // method panics, callers rely on it returning normally otherwise, so both conditions agree.
+    let mut impl_panics = impl_sig.contract.panics_disj(ctx.tcx);
+    impl_panics.subst(&subst);
+    let trait_panics = trait_sig.contract.panics_disj(ctx.tcx);
+    let mut panics_refn = impl_panics.clone().implies(trait_panics.clone());
+    if !trait_sig.contract.panics_if.is_empty() {
+        panics_refn = panics_refn.conj(trait_panics.implies(impl_panics));
+    }
+
     let retty = impl_sig.output;
 
     let post_refn =
         impl_postcond.implies(trait_postcond).forall((Symbol::intern("result"), retty)).span(span);
 
-    let mut refn = trait_precond.implies(impl_precond.conj(post_refn));
+    let mut refn = trait_precond.implies(impl_precond.conj(panics_refn).conj(post_refn));
     refn = if args.is_empty() {
         refn
     } else {
@@ -298,6 +315,9 @@ pub(crate) fn resolve_assoc_item_opt<'tcx>(
             rustc_middle::ty::Closure(closure_def_id, closure_substs) => {
                 Some((closure_def_id, closure_substs))
             }
+            // The implementation hidden in a trait object is unknown: use the trait's item, whose
+            // contract holds for every implementation.
+            rustc_middle::ty::Dynamic(..) => Some((def_id, substs)),
             _ => unimplemented!(),
         },
     }
@@ -23,6 +23,7 @@ use rustc_middle::ty::{
     TyCtxt, TyKind, UpvarCapture,
 };
 use rustc_span::{symbol, symbol::kw, Span, Symbol, DUMMY_SP};
+use rustc_target::abi::VariantIdx;
 use std::{
     collections::{HashMap, HashSet},
     fmt::{Display, Formatter},
@@ -30,7 +31,7 @@ use std::{
 };
 use why3::{
     declaration,
-    declaration::{LetKind, Signature, ValDecl},
+    declaration::{IndKind, LetKind, Signature, ValDecl},
     Ident,
 };
 
@@ -113,6 +114,18 @@ pub(crate) fn is_law(tcx: TyCtxt, def_id: DefId) -> bool {
     get_attr(tcx.get_attrs_unchecked(def_id), &["creusot", "decl", "law"]).is_some()
 }
 
+/// Whether `def_id` is an `#[inductive]` or `#[coinductive]` predicate
+pub(crate) fn inductive_kind(tcx: TyCtxt, def_id: DefId) -> Option<IndKind> {
+    let attrs = tcx.get_attrs_unchecked(def_id);
+    if get_attr(attrs, &["creusot", "decl", "inductive"]).is_some() {
+        Some(IndKind::Inductive)
+    } else if get_attr(attrs, &["creusot", "decl", "coinductive"]).is_some() {
+        Some(IndKind::Coinductive)
+    } else {
+        None
+    }
+}
+
 pub(crate) fn should_replace_trigger(tcx: TyCtxt, def_id: DefId) -> bool {
     get_attr(tcx.get_attrs_unchecked(def_id), &["creusot", "decl", "no_trigger"]).is_none()
 }
@@ -197,6 +210,36 @@ pub(crate) fn has_body(ctx: &mut TranslationCtx, def_id: DefId) -> bool {
     }
 }
 
+/// The coroutine implementing the future returned by an `async fn`.
+pub(crate) fn async_fn_coroutine(tcx: TyCtxt, def_id: DefId) -> Option<DefId> {
+    if !matches!(tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn)
+        || !tcx.asyncness(def_id).is_async()
+    {
+        return None;
+    }
+    let output = tcx.fn_sig(def_id).instantiate_identity().output().skip_binder();
+    let TyKind::Alias(ty::Opaque, opaque) = output.kind() else { return None };
+    match tcx.type_of(opaque.def_id).instantiate_identity().kind() {
+        TyKind::Coroutine(coroutine, _) => Some(*coroutine),
+        _ => None,
+    }
+}
+
+/// The output of `ty`, if it is the future of an `async fn` or `async` block.
+///
+/// Such futures are modelled by the value they resolve to.
+pub(crate) fn async_output<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> Option<Ty<'tcx>> {
+    match ty.kind() {
+        TyKind::Coroutine(def_id, args) if tcx.coroutine_is_async(*def_id) => {
+            Some(args.as_coroutine().return_ty())
+        }
+        TyKind::Alias(ty::Opaque, opaque) => {
+            async_output(tcx, tcx.type_of(opaque.def_id).instantiate(tcx, opaque.args))
+        }
+        _ => None,
+    }
+}
+
 pub(crate) fn get_builtin(tcx: TyCtxt, def_id: DefId) -> Option<Symbol> {
     get_attr(tcx.get_attrs_unchecked(def_id), &["creusot", "builtins"]).and_then(|a| {
         match &a.args {
@@ -254,6 +297,7 @@ pub(crate) fn inv_module_name(tcx: TyCtxt, kind: TyInvKind) -> Ident {
         TyInvKind::Adt(adt_did) => format!("{}_Inv", ident_path(tcx, adt_did)).into(),
         TyInvKind::Tuple(arity) => format!("TyInv_Tuple{arity}").into(),
         TyInvKind::Slice => format!("TyInv_Slice").into(),
+        TyInvKind::Array => "TyInv_Array".into(),
     }
 }
 
@@ -384,6 +428,7 @@ pub(crate) fn item_type(tcx: TyCtxt<'_>, def_id: DefId) -> ItemType {
             }
         }
         DefKind::AssocConst | DefKind::Const => ItemType::Constant,
+        DefKind::Static { mutability: Mutability::Not, .. } => ItemType::Constant,
         DefKind::Closure => ItemType::Closure,
         DefKind::Struct | DefKind::Enum | DefKind::Union => ItemType::Type,
         DefKind::AssocTy => ItemType::AssocTy,
@@ -394,6 +439,18 @@ pub(crate) fn item_type(tcx: TyCtxt<'_>, def_id: DefId) -> ItemType {
     }
 }
 
+/// The definition naming the constructor `variant` of `adt` in Why3.
+///
+/// Unions are translated to a sum type with one constructor per field, which records the active
+/// field of the union: for them, `variant` is the index of that field.
+pub(crate) fn constructor_did(adt: ty::AdtDef, variant: VariantIdx) -> DefId {
+    if adt.is_union() {
+        adt.non_enum_variant().fields[FieldIdx::from_usize(variant.as_usize())].did
+    } else {
+        adt.variant(variant).def_id
+    }
+}
+
 pub(crate) fn inputs_and_output<'tcx>(
     tcx: TyCtxt<'tcx>,
     def_id: DefId,
@@ -5,8 +5,8 @@ use rustc_span::Span;
 use crate::{
     ctx::{parent_module, TranslationCtx},
     translation::{
-        pearlite::{super_visit_term, TermKind, TermVisitor},
-        specification::is_overloaded_item,
+        pearlite::{super_visit_term, BinOp, Term, TermKind, TermVisitor},
+        specification::{contract_of, is_overloaded_item},
     },
     util::{self, is_law},
 };
@@ -77,6 +77,82 @@ pub(crate) fn validate_opacity(ctx: &mut TranslationCtx, item: DefId) -> Option<
     Some(())
 }
 
+// Validate that an inductive predicate has no contract, and only occurs strictly positively in
+// its own body, so that its introduction rules define a least (or greatest) fixpoint.
+pub(crate) fn validate_inductive(ctx: &mut TranslationCtx, item: DefId) -> Option<()> {
+    struct NonPositive<'a, 'tcx> {
+        ctx: &'a TranslationCtx<'tcx>,
+        item: DefId,
+    }
+
+    impl<'a, 'tcx> NonPositive<'a, 'tcx> {
+        // Visit a term in a strictly positive position
+        fn visit_positive(&mut self, term: &Term<'tcx>) {
+            match &term.kind {
+                TermKind::Binary { op: BinOp::And | BinOp::Or, lhs, rhs } => {
+                    self.visit_positive(lhs);
+                    self.visit_positive(rhs);
+                }
+                TermKind::Impl { lhs, rhs } => {
+                    self.visit_term(lhs);
+                    self.visit_positive(rhs);
+                }
+                TermKind::Forall { body, .. } | TermKind::Exists { body, .. } => {
+                    self.visit_positive(body)
+                }
+                TermKind::Let { arg, body, .. } => {
+                    self.visit_term(arg);
+                    self.visit_positive(body);
+                }
+                TermKind::Match { scrutinee, arms } => {
+                    self.visit_term(scrutinee);
+                    arms.iter().for_each(|(_, arm)| self.visit_positive(arm));
+                }
+                TermKind::Call { id, args, .. } if *id == self.item => {
+                    args.iter().for_each(|arg| self.visit_term(arg))
+                }
+                _ => self.visit_term(term),
+            }
+        }
+    }
+
+    impl<'a, 'tcx> TermVisitor<'tcx> for NonPositive<'a, 'tcx> {
+        fn visit_term(&mut self, term: &Term<'tcx>) {
+            match &term.kind {
+                TermKind::Item(id, _) | TermKind::Call { id, .. } if *id == self.item => {
+                    self.ctx
+                        .error(
+                            term.span,
+                            &format!(
+                                "`{}` occurs in a non-positive position of its own definition",
+                                self.ctx.def_path_str(self.item)
+                            ),
+                        )
+                        .emit();
+                }
+                _ => super_visit_term(term, self),
+            }
+        }
+    }
+
+    if !contract_of(ctx, item).is_empty() {
+        ctx.error(ctx.def_span(item), "Inductive predicates cannot have a contract").emit();
+    }
+
+    let term = ctx.term(item)?.clone();
+    NonPositive { ctx, item }.visit_positive(&term);
+    Some(())
+}
+
+/// A `#[pure]` function cannot panic, except under a `#[panics_if]` condition which its callers
+/// must then rule out.
+pub(crate) fn validate_may_panic(ctx: &mut TranslationCtx, item: DefId) {
+    let contract = contract_of(ctx, item);
+    if contract.no_panic && contract.may_panic {
+        ctx.error(ctx.def_span(item), "`#[pure]` functions cannot be `#[may_panic]`").emit();
+    }
+}
+
 // Validate that laws have no additional generic parameters.
 //  TODO(xavier): Why was this necessary?
 pub(crate) fn validate_traits(ctx: &mut TranslationCtx) {
@@ -84,6 +160,11 @@ pub(crate) fn validate_traits(ctx: &mut TranslationCtx) {
 
     for trait_item_id in ctx.hir_crate_items(()).trait_items() {
         let trait_item = ctx.hir().trait_item(trait_item_id);
+        let def_id = trait_item.owner_id.def_id.to_def_id();
+
+        if ctx.def_kind(def_id) == DefKind::AssocFn && ctx.asyncness(def_id).is_async() {
+            ctx.crash_and_error(trait_item.span, "`async fn` is not supported in traits")
+        }
 
         if is_law(ctx.tcx, trait_item.owner_id.def_id.to_def_id())
             && !ctx.generics_of(trait_item.owner_id.def_id).own_params.is_empty()
@@ -165,6 +246,27 @@ pub(crate) fn validate_impls(ctx: &mut TranslationCtx) {
                         ),
                     )
                     .emit();
+                } else if item_contract.can_panic() && !trait_contract.can_panic() {
+                    ctx.error(
+                        ctx.def_span(impl_item),
+                        &format!(
+                            "Expected `{}` not to panic as specified by the trait declaration",
+                            ctx.item_name(*impl_item),
+                        ),
+                    )
+                    .emit();
+                } else if !trait_contract.panics_if.is_empty() && item_contract.panics_if.is_empty()
+                {
+                    // Callers through the trait assume that the condition does not hold when the
+                    // call returns, so the implementation must panic exactly when it holds.
+                    ctx.error(
+                        ctx.def_span(impl_item),
+                        &format!(
+                            "Expected `{}` to have a `#[panics_if]` condition as specified by the trait declaration",
+                            ctx.item_name(*impl_item),
+                        ),
+                    )
+                    .emit();
                 }
             }
         }
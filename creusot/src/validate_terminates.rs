@@ -52,7 +52,8 @@ struct AdditionalData {
     /// For now, mutually recursive functions are never allowed, so this only matter for
     /// the simple recursion check.
     has_variant: bool,
-    /// `Some` if the function contains a loop construct (contains the location of the loop).
+    /// `Some` if the function contains a loop construct without a `#[variant]` (contains the
+    /// location of the loop).
     ///
     /// The body of external function are not visited, so this field will be `false`.
     has_loops: Option<Span>,
@@ -109,8 +110,7 @@ impl<'tcx> ToVisit<'tcx> {
 }
 
 /// Validate that a `#[terminates]` function cannot loop indefinitely. This includes:
-/// - forbidding program function from using loops of any kind (this should be relaxed
-/// later).
+/// - forbidding program function from using loops without a `#[variant]`.
 /// - forbidding (mutual) recursive calls, especially when traits are involved.
 ///
 /// Note that for logical functions, these are relaxed: we don't check loops, nor simple
@@ -155,6 +155,7 @@ pub(crate) fn validate_terminates(ctx: &mut TranslationCtx) {
                         param_env,
                         calls: IndexSet::new(),
                         has_loops: None,
+                        pending_variant: false,
                     };
                     <FunctionCalls as thir::visit::Visitor>::visit_expr(&mut visitor, &thir[expr]);
                     let (visited_calls, pearlite_func, has_loops) =
@@ -257,7 +258,10 @@ pub(crate) fn validate_terminates(ctx: &mut TranslationCtx) {
         };
         if let Some(loop_span) = additional_data[&fun_index].has_loops {
             let fun_span = ctx.tcx.def_span(def_id);
-            let mut error = ctx.error(fun_span, "`#[terminates]` function must not contain loops.");
+            let mut error = ctx.error(
+                fun_span,
+                "`#[terminates]` function must not contain loops without a `#[variant]`.",
+            );
             error.span_note(loop_span, "looping occurs here");
             error.emit();
         }
@@ -361,8 +365,11 @@ struct FunctionCalls<'thir, 'tcx> {
     /// - The span of the call (for error messages)
     /// - The generic arguments instantiating the call
     calls: IndexSet<(DefId, Span, &'tcx GenericArgs<'tcx>)>,
-    /// `true` if the function contains a loop construct.
+    /// `Some` if the function contains a loop construct without a variant.
     has_loops: Option<Span>,
+    /// `true` if we just saw the variant of a loop: it is placed right before the loop it
+    /// belongs to.
+    pending_variant: bool,
 }
 
 impl<'thir, 'tcx> thir::visit::Visitor<'thir, 'tcx> for FunctionCalls<'thir, 'tcx> {
@@ -390,6 +397,11 @@ impl<'thir, 'tcx> thir::visit::Visitor<'thir, 'tcx> for FunctionCalls<'thir, 'tc
                     self.calls.insert((def_id, fn_span, args));
                 }
             }
+            thir::ExprKind::Closure(box thir::ClosureExpr { closure_id, .. })
+                if util::is_loop_variant(self.tcx, closure_id.to_def_id()) =>
+            {
+                self.pending_variant = true;
+            }
             thir::ExprKind::Closure(box thir::ClosureExpr { closure_id, .. }) => {
                 let (thir, expr) = self.tcx.thir_body(closure_id).unwrap_or_else(|_| {
                     crate::error::Error::from(crate::error::InternalError("Cannot fetch THIR body"))
@@ -404,11 +416,16 @@ impl<'thir, 'tcx> thir::visit::Visitor<'thir, 'tcx> for FunctionCalls<'thir, 'tc
                     param_env: self.param_env,
                     calls: std::mem::take(&mut self.calls),
                     has_loops: None,
+                    pending_variant: false,
                 };
                 thir::visit::walk_expr(&mut closure_visitor, &thir[expr]);
                 self.calls = closure_visitor.calls;
             }
-            thir::ExprKind::Loop { .. } => self.has_loops = Some(expr.span),
+            thir::ExprKind::Loop { .. } => {
+                if !std::mem::take(&mut self.pending_variant) {
+                    self.has_loops = Some(expr.span)
+                }
+            }
             _ => {}
         }
         thir::visit::walk_expr(self, expr);
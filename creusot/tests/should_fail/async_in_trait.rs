@@ -0,0 +1,6 @@
+// EDITION=2021
+extern crate creusot_contracts;
+
+pub trait Service {
+    async fn call(&self) -> u32;
+}
@@ -0,0 +1,19 @@
+// EDITION=2021
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[ensures((^x)@ == x@ + 1)]
+pub async fn incr(x: &mut u32) {
+    *x += 1;
+}
+
+pub async fn later() {
+    let mut x = 0;
+    let f = incr(&mut x);
+    f.await;
+}
+
+pub fn dropped() {
+    let mut x = 0;
+    let _ = incr(&mut x);
+}
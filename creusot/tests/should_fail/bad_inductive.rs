@@ -0,0 +1,18 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[inductive]
+fn not_positive(n: Int) -> bool {
+    pearlite! { n == 0 || !not_positive(n - 1) }
+}
+
+#[inductive]
+fn under_implication(n: Int) -> bool {
+    pearlite! { under_implication(n + 1) ==> n == 0 }
+}
+
+#[inductive]
+#[ensures(result ==> n >= 0)]
+fn with_contract(n: Int) -> bool {
+    pearlite! { n == 0 || with_contract(n - 1) }
+}
@@ -0,0 +1,22 @@
+extern crate creusot_contracts;
+use creusot_contracts::{std::hash::DeepModelHash, *};
+use std::hash::{Hash, Hasher};
+
+pub struct Key(u32);
+
+impl DeepModel for Key {
+    type DeepModelTy = Int;
+
+    #[logic]
+    #[open]
+    fn deep_model(self) -> Int {
+        pearlite! { self.0@ }
+    }
+}
+
+impl Hash for Key {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+// Consistency of `Hash` with the deep model cannot be checked, so it must be trusted
+impl DeepModelHash for Key {}
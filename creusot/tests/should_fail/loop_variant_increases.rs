@@ -0,0 +1,10 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[terminates]
+pub fn increases(mut n: i32) {
+    #[variant(n)]
+    while n < 10 {
+        n += 1;
+    }
+}
@@ -0,0 +1,15 @@
+extern crate creusot_contracts;
+use creusot_contracts::{well_founded::WellFounded, *};
+
+pub struct Measure(pub u32);
+
+#[trusted]
+impl WellFounded for Measure {}
+
+// `WellFounded` gives no order to check the decrease of the variant with
+pub fn count_down(mut n: u32) {
+    #[variant(Measure(n))]
+    while n > 0 {
+        n -= 1;
+    }
+}
@@ -0,0 +1,14 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+trait Foo {
+    fn f(x: u32) -> u32;
+}
+
+impl Foo for () {
+    #[panics_if(x == 0u32)]
+    fn f(x: u32) -> u32 {
+        assert!(x != 0);
+        x
+    }
+}
@@ -0,0 +1,26 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Foo {
+    #[panics_if(x == 0u32)]
+    fn f(x: u32) -> u32;
+}
+
+impl Foo for () {
+    // Never panics, while callers through the trait assume that `f(0)` does not return
+    fn f(x: u32) -> u32 {
+        x
+    }
+}
+
+#[may_panic]
+#[ensures(false)]
+pub fn unsound<T: Foo>() {
+    T::f(0u32);
+}
+
+#[may_panic]
+#[ensures(false)]
+pub fn derive_false() {
+    unsound::<()>()
+}
@@ -0,0 +1,16 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Foo {
+    #[panics_if(x == 0u32)]
+    fn f(x: u32) -> u32;
+}
+
+impl Foo for () {
+    // Panics in more cases than allowed by the trait: the refinement cannot be proven
+    #[panics_if(x <= 1u32)]
+    fn f(x: u32) -> u32 {
+        assert!(x > 1);
+        x
+    }
+}
@@ -0,0 +1,6 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[pure]
+#[may_panic]
+pub fn f() {}
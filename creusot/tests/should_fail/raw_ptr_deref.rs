@@ -0,0 +1,9 @@
+extern crate creusot_contracts;
+
+pub unsafe fn read(p: *const u32) -> u32 {
+    *p
+}
+
+pub unsafe fn write(p: *mut (u32, u32)) {
+    (*p).1 = 0;
+}
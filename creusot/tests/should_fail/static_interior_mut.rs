@@ -0,0 +1,8 @@
+extern crate creusot_contracts;
+use std::sync::atomic::AtomicU32;
+
+static C: AtomicU32 = AtomicU32::new(0);
+
+pub fn f() -> &'static AtomicU32 {
+    &C
+}
@@ -0,0 +1,11 @@
+extern crate creusot_contracts;
+
+pub union IntOrBool {
+    int: u32,
+    boolean: bool,
+}
+
+pub fn read_inactive() -> u32 {
+    let u = IntOrBool { boolean: true };
+    unsafe { u.int }
+}
@@ -0,0 +1,16 @@
+extern crate creusot_contracts;
+
+#[repr(C)]
+pub union Bits {
+    float: f32,
+    int: u32,
+}
+
+pub fn to_bits(x: f32) -> u32 {
+    let b = Bits { float: x };
+    unsafe { b.int }
+}
+
+pub fn set(b: &mut Bits) {
+    b.int = 0;
+}
@@ -0,0 +1,6 @@
+#![feature(f16)]
+extern crate creusot_contracts;
+
+pub fn to_f16(x: f32) -> f16 {
+    x as f16
+}
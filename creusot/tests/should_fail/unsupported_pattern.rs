@@ -0,0 +1,8 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[logic]
+fn array_let(a: [Int; 2]) -> Int {
+    let [x, y] = a;
+    x + y
+}
@@ -0,0 +1,7 @@
+extern crate creusot_contracts;
+use creusot_contracts::{logic::Mapping, *};
+
+#[logic]
+fn closure_param() -> Mapping<[Int; 2], Int> {
+    |[x, _]: [Int; 2]| x
+}
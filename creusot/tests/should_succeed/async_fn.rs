@@ -0,0 +1,38 @@
+// EDITION=2021
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[ensures(result == x)]
+pub async fn id(x: u32) -> u32 {
+    x
+}
+
+#[requires(x@ < 100)]
+#[ensures((^x)@ == x@ + 1)]
+pub async fn incr(x: &mut u32) {
+    *x += 1;
+}
+
+pub struct S(pub u32);
+
+impl S {
+    #[ensures(result == self.0)]
+    pub async fn get(&self) -> u32 {
+        self.0
+    }
+}
+
+#[ensures(result == t)]
+pub async fn generic<T>(t: T) -> T {
+    t
+}
+
+#[ensures(result@ == 2)]
+pub async fn awaits() -> u32 {
+    let mut x = id(0).await;
+    incr(&mut x).await;
+    incr(&mut x).await;
+    let s = S(x);
+    let y = s.get().await;
+    generic(y).await
+}
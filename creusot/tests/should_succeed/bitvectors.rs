@@ -0,0 +1,34 @@
+extern crate creusot_contracts;
+use creusot_contracts::{
+    logic::{BV32, BV8},
+    *,
+};
+
+#[ensures(result.to_bv() == x.to_bv().add(y.to_bv()))]
+pub fn wrapping_add(x: u32, y: u32) -> u32 {
+    x.wrapping_add(y)
+}
+
+#[ensures(result.to_bv() == x.to_bv().mul(y.to_bv()))]
+#[ensures(result@ == (x@ * y@ + 128).rem_euclid(256) - 128)]
+pub fn wrapping_mul_signed(x: i8, y: i8) -> i8 {
+    x.wrapping_mul(y)
+}
+
+#[ensures(result.0.to_bv() == x.to_bv().sub(y.to_bv()))]
+pub fn overflowing_sub(x: u8, y: u8) -> (u8, bool) {
+    x.overflowing_sub(y)
+}
+
+#[ensures(result@ == BV32::of_int(x@).xor(BV32::of_int(y@)).to_uint())]
+pub fn xor(x: u32, y: u32) -> u32 {
+    x ^ y
+}
+
+pub fn conversions() {
+    proof_assert! { BV8::of_int(256) == BV8::zeros() };
+    proof_assert! { BV8::of_int(-1) == BV8::ones() };
+    proof_assert! { BV8::ones().to_uint() == 255 && BV8::ones().to_int() == -1 };
+    proof_assert! { 200u8.to_bv().to_int() == -56 };
+    proof_assert! { BV8::of_int(1).shl(7).nth(7) };
+}
@@ -0,0 +1,51 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[ensures(result == a & b)]
+pub fn bit_and(a: u32, b: u32) -> u32 {
+    a & b
+}
+
+#[ensures(result == a | b)]
+pub fn bit_or(a: u32, b: u32) -> u32 {
+    a | b
+}
+
+#[ensures(result == a ^ b)]
+pub fn bit_xor(a: i64, b: i64) -> i64 {
+    a ^ b
+}
+
+#[ensures(result == !a)]
+pub fn bit_not(a: u8) -> u8 {
+    !a
+}
+
+#[ensures(result == (a ^ b))]
+pub fn bool_xor(a: bool, b: bool) -> bool {
+    a ^ b
+}
+
+#[requires(b@ < 32)]
+#[ensures(result == a << b)]
+pub fn shift_left(a: u32, b: u8) -> u32 {
+    a << b
+}
+
+#[requires(b@ < 64)]
+#[ensures(result == a >> b)]
+pub fn shift_right(a: i64, b: usize) -> i64 {
+    a >> b
+}
+
+#[ensures(result@ <= a@)]
+pub fn mask(a: u64) -> u64 {
+    a & 0xffu64
+}
+
+#[logic]
+#[open]
+#[ensures(x & 1u32 == 0u32 || x & 1u32 == 1u32)]
+pub fn low_bit(x: u32) -> u32 {
+    x & 1u32
+}
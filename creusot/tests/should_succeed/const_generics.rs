@@ -0,0 +1,47 @@
+extern crate creusot_contracts;
+
+use creusot_contracts::*;
+
+#[ensures(result@ == N@)]
+pub fn length<T, const N: usize>(a: &[T; N]) -> usize {
+    a.len()
+}
+
+#[ensures(a@.len() == N@)]
+pub fn model_length<T, const N: usize>(a: [T; N]) {}
+
+#[requires(N@ > 0)]
+#[ensures(result == a[0])]
+pub fn first<const N: usize>(a: [u32; N]) -> u32 {
+    a[0]
+}
+
+#[ensures(forall<i: Int> 0 <= i && i < N@ ==> (^a)[i] == 0u32)]
+pub fn zero<const N: usize>(a: &mut [u32; N]) {
+    let mut i = 0;
+    #[invariant(a@.len() == N@)]
+    #[invariant(forall<j: Int> 0 <= j && j < i@ ==> a[j] == 0u32)]
+    while i < N {
+        a[i] = 0;
+        i += 1;
+    }
+}
+
+pub struct Matrix<const R: usize, const C: usize>([[i32; C]; R]);
+
+impl<const R: usize, const C: usize> Matrix<R, C> {
+    #[requires(r@ < R@ && c@ < C@)]
+    pub fn get(&self, r: usize, c: usize) -> i32 {
+        self.0[r][c]
+    }
+}
+
+#[ensures(result@ == 3)]
+pub fn call_length() -> usize {
+    length(&[1, 2, 3])
+}
+
+pub fn call_first() {
+    let x = first([4u32; 5]);
+    proof_assert! { x == 4u32 };
+}
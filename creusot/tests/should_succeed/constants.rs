@@ -0,0 +1,61 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub struct Config {
+    pub size: usize,
+    pub verbose: bool,
+    pub name: &'static str,
+}
+
+pub const DEFAULT: Config = Config { size: 16, verbose: false, name: "default" };
+
+pub const ORIGIN: (i32, i32) = (0, 0);
+
+pub const FALLBACK: Option<u32> = Some(42);
+
+pub const SQUARES: [u32; 5] = [0, 1, 4, 9, 16];
+
+pub static DIGITS: [char; 4] = ['0', '1', '2', '3'];
+
+pub static LIMIT: u64 = 1 << 20;
+
+#[ensures(result == DEFAULT.size)]
+pub fn default_size() -> usize {
+    DEFAULT.size
+}
+
+#[ensures(result@ == 16)]
+pub fn default_size_value() -> usize {
+    DEFAULT.size
+}
+
+#[ensures(result == ORIGIN)]
+pub fn origin() -> (i32, i32) {
+    ORIGIN
+}
+
+#[ensures(result@ == 42)]
+pub fn fallback(x: Option<u32>) -> u32 {
+    match FALLBACK {
+        Some(y) => y,
+        None => 0,
+    }
+}
+
+#[requires(i@ < 5)]
+#[ensures(result@ == i@ * i@)]
+pub fn square(i: usize) -> u32 {
+    SQUARES[i]
+}
+
+#[requires(i@ < 4)]
+#[ensures(result == DIGITS@[i@])]
+pub fn digit(i: usize) -> char {
+    DIGITS[i]
+}
+
+#[ensures(result == LIMIT)]
+#[ensures(result@ == 1048576)]
+pub fn limit() -> u64 {
+    LIMIT
+}
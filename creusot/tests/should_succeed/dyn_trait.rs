@@ -0,0 +1,81 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub trait Counter {
+    #[logic]
+    fn count(self) -> Int;
+
+    #[ensures(result@ == self.count())]
+    fn get(&self) -> u32;
+
+    #[requires(self.count() < 100)]
+    #[ensures((^self).count() == self.count() + 1)]
+    fn incr(&mut self);
+}
+
+pub struct Simple(pub u32);
+
+impl Counter for Simple {
+    #[logic]
+    #[open]
+    fn count(self) -> Int {
+        pearlite! { self.0@ }
+    }
+
+    #[ensures(result@ == self.count())]
+    fn get(&self) -> u32 {
+        self.0
+    }
+
+    #[requires(self.count() < 100)]
+    #[ensures((^self).count() == self.count() + 1)]
+    fn incr(&mut self) {
+        self.0 += 1;
+    }
+}
+
+#[ensures(result@ == c.count())]
+pub fn get_ref(c: &dyn Counter) -> u32 {
+    c.get()
+}
+
+#[ensures(result@ == c.count())]
+pub fn get_box(c: Box<dyn Counter>) -> u32 {
+    c.get()
+}
+
+#[requires(c.count() < 100)]
+#[ensures((^c).count() == c.count() + 1)]
+pub fn incr_mut(c: &mut dyn Counter) {
+    c.incr()
+}
+
+pub fn counters() {
+    let mut s = Simple(3);
+    let d: &mut dyn Counter = &mut s;
+    incr_mut(d);
+    proof_assert!(s.0@ == 4);
+
+    let r = get_ref(&s);
+    proof_assert!(r@ == 4);
+
+    let b: Box<dyn Counter> = Box::new(Simple(10));
+    let r = get_box(b);
+    assert!(r == 10);
+}
+
+#[requires(f.precondition((x,)))]
+#[ensures(f.postcondition((x,), result))]
+pub fn apply(f: &dyn Fn(i32) -> i32, x: i32) -> i32 {
+    f(x)
+}
+
+pub fn closures() {
+    let r = apply(
+        &#[requires(x@ < 100)]
+        #[ensures(result@ == x@ + 1)]
+        |x: i32| x + 1,
+        10,
+    );
+    assert!(r == 11);
+}
@@ -0,0 +1,87 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[ensures(result == x + y)]
+pub fn add(x: f64, y: f64) -> f64 {
+    x + y
+}
+
+#[ensures(result == -x * y)]
+pub fn neg_mul(x: f32, y: f32) -> f32 {
+    -x * y
+}
+
+#[ensures(result == x % y)]
+pub fn rem(x: f64, y: f64) -> f64 {
+    x % y
+}
+
+#[ensures(result == 1.5f32 * x)]
+pub fn literal(x: f32) -> f32 {
+    1.5 * x
+}
+
+#[ensures(result.is_nan_log())]
+pub fn nan() -> f32 {
+    f32::NAN
+}
+
+#[ensures(result.is_infinite_log() && result.is_negative_log())]
+pub fn neg_infinity() -> f64 {
+    f64::NEG_INFINITY
+}
+
+#[ensures(result == x.eq_log(x))]
+pub fn self_eq(x: f32) -> bool {
+    x == x
+}
+
+#[ensures(result == x.lt_log(y))]
+pub fn lt(x: f64, y: f64) -> bool {
+    x < y
+}
+
+#[ensures(result == x.abs_log())]
+pub fn abs(x: f64) -> f64 {
+    x.abs()
+}
+
+#[ensures(result == x.sqrt_log())]
+pub fn sqrt(x: f32) -> f32 {
+    x.sqrt()
+}
+
+#[ensures(x.is_nan_log() ==> result)]
+pub fn is_nan(x: f64) -> bool {
+    x.is_nan() || x != x
+}
+
+#[requires(x.ge_log(0.0) && x.lt_log(256.0))]
+pub fn to_u8(x: f32) -> u8 {
+    x as u8
+}
+
+#[ensures(x.is_nan_log() ==> result == 0u8)]
+#[ensures(x.is_infinite_log() && x.is_positive_log() ==> result == 255u8)]
+pub fn to_u8_saturating(x: f32) -> u8 {
+    x as u8
+}
+
+pub fn from_int(x: i32) -> f64 {
+    x as f64
+}
+
+pub fn widen(x: f32) -> f64 {
+    x as f64
+}
+
+pub fn narrow(x: f64) -> f32 {
+    x as f32
+}
+
+#[logic]
+#[open]
+#[ensures(result == x + x)]
+pub fn double(x: f64) -> f64 {
+    x * 2.0
+}
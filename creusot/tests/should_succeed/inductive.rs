@@ -0,0 +1,53 @@
+extern crate creusot_contracts;
+use creusot_contracts::{logic::Mapping, *};
+
+#[inductive]
+#[open]
+pub fn even(n: Int) -> bool {
+    pearlite! { n == 0 || (n >= 2 && even(n - 2)) }
+}
+
+#[ensures(even(result@))]
+pub fn four() -> u32 {
+    proof_assert! { even(0) && even(2) };
+    4
+}
+
+// Reachability in a transition system, as the reflexive transitive closure of `step`
+#[predicate]
+#[open]
+pub fn step(s: Int, t: Int) -> bool {
+    pearlite! { t == s + 1 || (s > 0 && t == s - 1) }
+}
+
+#[inductive]
+#[open]
+pub fn reachable(s: Int, t: Int) -> bool {
+    pearlite! { s == t || exists<u: Int> step(s, u) && reachable(u, t) }
+}
+
+#[requires(reachable(x@, 10))]
+#[ensures(reachable(result@, 10))]
+pub fn back(x: u32) -> u32 {
+    if x > 0 {
+        proof_assert! { step(x@ - 1, x@) };
+        x - 1
+    } else {
+        x
+    }
+}
+
+// A stream of integers, and the coinductive predicate stating that all of its elements starting
+// from `i` are positive
+#[coinductive]
+#[open]
+pub fn always_positive(s: Mapping<Int, Int>, i: Int) -> bool {
+    pearlite! { s.get(i) > 0 && always_positive(s, i + 1) }
+}
+
+#[requires(always_positive(*s, 0))]
+#[ensures(result@ > 0)]
+pub fn head(s: Snapshot<Mapping<Int, Int>>) -> u32 {
+    proof_assert! { s.get(0) > 0 };
+    1
+}
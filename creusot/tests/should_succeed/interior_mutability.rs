@@ -0,0 +1,75 @@
+extern crate creusot_contracts;
+use creusot_contracts::{
+    std::{
+        cell::{Inv, PredCell, PredRefCell},
+        rc::Rc,
+        sync::{Arc, PredMutex},
+    },
+    *,
+};
+
+pub struct Even;
+
+impl Inv<u32> for Even {
+    #[predicate]
+    #[open]
+    fn inv(x: u32) -> bool {
+        pearlite! { x@ % 2 == 0 }
+    }
+}
+
+pub fn adds_two(c: &PredCell<u32, Even>) {
+    let v = c.get();
+    if v < 100000 {
+        c.set(v + 2);
+    } else {
+        c.set(0);
+    }
+}
+
+#[ensures(result@ % 2 == 0)]
+pub fn shared_cell() -> u32 {
+    let c = Rc::new(PredCell::new(0));
+    let d = c.clone();
+    adds_two(&c);
+    adds_two(&d);
+    d.get()
+}
+
+#[may_panic]
+#[ensures(result@ % 2 == 0)]
+pub fn ref_cell(c: &PredRefCell<u32, Even>) -> u32 {
+    {
+        let mut b = c.borrow_mut();
+        let v = *b;
+        if v < 100000 {
+            b.set(v + 2);
+        }
+    }
+    *c.borrow()
+}
+
+pub fn mutex(m: Arc<PredMutex<u32, Even>>) {
+    let m2 = m.clone();
+    let mut guard = m2.lock();
+    let v = *guard;
+    if v < 100000 {
+        guard.set(v + 2);
+    } else {
+        guard.set(0);
+    }
+}
+
+#[ensures(result == 1u32)]
+pub fn rc_model() -> u32 {
+    let a = Rc::new(1u32);
+    let b = a.clone();
+    *b
+}
+
+#[ensures(result == (1u32, 2u32))]
+pub fn arc_model() -> (u32, u32) {
+    let a = Arc::new((1u32, 2u32));
+    let b = Arc::clone(&a);
+    *b.as_ref()
+}
@@ -0,0 +1,132 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[ensures(result@.len() == v@.len())]
+pub fn map_is_zero(v: &[u32]) -> Vec<bool> {
+    v.iter().map(|x| *x == 0).collect()
+}
+
+#[ensures(result@ <= v@.len())]
+pub fn count_positive(v: &[u32]) -> usize {
+    v.iter().filter(|x| **x > 0).count()
+}
+
+pub fn filter_map_nonzero(v: &[u32]) -> Vec<u32> {
+    v.iter().filter_map(|x| if *x == 0 { None } else { Some(*x) }).collect()
+}
+
+#[requires(n@ + m@ <= usize::MAX@)]
+#[ensures(result@ == n@ + m@)]
+pub fn chain_ranges(n: usize, m: usize) -> usize {
+    (0..n).chain(0..m).count()
+}
+
+#[ensures(result@ == n@)]
+pub fn rev_range(n: usize) -> usize {
+    let mut i = 0;
+    #[invariant(i@ == produced.len())]
+    #[invariant(forall<j: Int> 0 <= j && j < produced.len() ==> produced[j]@ == n@ - j - 1)]
+    for _ in (0..n).rev() {
+        i += 1;
+    }
+    i
+}
+
+#[ensures(result == (v@.len() > 0))]
+pub fn rev_slice(v: &[u32]) -> bool {
+    let mut it = v.iter().rev();
+    match it.next() {
+        Some(x) => {
+            proof_assert! { *x == v[v@.len() - 1] };
+            true
+        }
+        None => false,
+    }
+}
+
+pub fn step_by_range(n: usize) {
+    #[invariant(forall<j: Int> 0 <= j && j < produced.len() ==> produced[j]@ == 2 * j)]
+    for x in (0..n).step_by(2) {
+        proof_assert! { x@ < n@ };
+    }
+}
+
+#[requires(v@.len() > 0)]
+pub fn peek_first(v: &[u32]) {
+    let mut it = v.iter().peekable();
+    let first = it.peek().map(|x| **x);
+    let next = it.next().map(|x| *x);
+    proof_assert! { first == next };
+}
+
+pub fn flat_map_vecs(v: &[Vec<u32>]) -> Vec<u32> {
+    v.iter().flat_map(|w| w.iter().copied()).collect()
+}
+
+pub fn take_while_nonzero(v: &[u32]) -> usize {
+    let mut i = 0;
+    #[invariant(forall<j: Int> 0 <= j && j < produced.len() ==> *produced[j] != 0u32)]
+    for _ in v.iter().take_while(|x| **x != 0) {
+        i += 1;
+    }
+    i
+}
+
+pub fn skip_while_zero(v: &[u32]) -> Option<&u32> {
+    v.iter().skip_while(|x| **x == 0).next()
+}
+
+#[ensures(result == exists<i: Int> 0 <= i && i < v@.len() && v[i] == 0u32)]
+pub fn any_zero(v: &[u32]) -> bool {
+    v.iter().any(|x| *x == 0)
+}
+
+#[ensures(result == forall<i: Int> 0 <= i && i < v@.len() ==> v[i] > 0u32)]
+pub fn all_positive(v: &[u32]) -> bool {
+    v.iter().all(|x| *x > 0)
+}
+
+#[ensures(match result { Some(i) => v[i@] == 0u32, None => true })]
+pub fn position_zero(v: &[u32]) -> Option<usize> {
+    v.iter().position(|x| *x == 0)
+}
+
+#[ensures(match result { Some(x) => *x > 10u32, None => true })]
+pub fn find_large(v: &[u32]) -> Option<&u32> {
+    v.iter().find(|x| **x > 10)
+}
+
+pub fn fold_max(v: &[u32]) -> u32 {
+    v.iter().fold(0, |acc, x| if *x > acc { *x } else { acc })
+}
+
+#[ensures(result@ == n@ * (n@ - 1) / 2)]
+#[requires(n@ < 1000)]
+pub fn sum_range(n: u64) -> u64 {
+    (0..n).sum()
+}
+
+#[ensures(match result { Some(m) => forall<i: Int> 0 <= i && i < v@.len() ==> v[i] <= *m, None => v@.len() == 0 })]
+pub fn max_elem(v: &[u32]) -> Option<&u32> {
+    v.iter().max()
+}
+
+#[ensures(match result { Some(m) => forall<i: Int> 0 <= i && i < v@.len() ==> *m <= v[i], None => v@.len() == 0 })]
+pub fn min_elem(v: &[u32]) -> Option<&u32> {
+    v.iter().min()
+}
+
+#[requires(v@.len() >= 2)]
+pub fn windows_pairs(v: &[u32]) {
+    #[invariant(forall<j: Int> 0 <= j && j < produced.len() ==> produced[j]@.len() == 2)]
+    for w in v.windows(2) {
+        let _ = w;
+    }
+}
+
+pub fn chunks_len(v: &[u32]) {
+    #[invariant(forall<j: Int> 0 <= j && j < produced.len() ==> produced[j]@.len() <= 3)]
+    for c in v.chunks(3) {
+        let _ = c;
+    }
+}
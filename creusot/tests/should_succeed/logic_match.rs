@@ -0,0 +1,79 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[logic]
+#[open]
+pub fn sign(x: i32) -> Int {
+    match x {
+        0 => 0,
+        n if n < 0i32 => -1,
+        _ => 1,
+    }
+}
+
+#[logic]
+#[open]
+pub fn digit_class(x: u8) -> Int {
+    match x {
+        0..=9 => 0,
+        10..100 => 1,
+        _ => 2,
+    }
+}
+
+#[logic]
+#[open]
+pub fn is_lower(c: char) -> bool {
+    match c {
+        'a'..='z' => true,
+        _ => false,
+    }
+}
+
+#[logic]
+#[open]
+pub fn option_value(o: Option<u32>) -> Int {
+    pearlite! {
+        match o {
+            None => 0,
+            Some(0) => 1,
+            Some(x) if x@ < 10 => 2,
+            Some(_) => 3,
+        }
+    }
+}
+
+#[logic]
+#[open]
+pub fn head_or_zero(s: &[u32]) -> u32 {
+    match s {
+        [] => 0u32,
+        [x] => *x,
+        [x, .., y] => if *x <= *y { *x } else { *y },
+    }
+}
+
+#[logic]
+#[open]
+pub fn first_of_pair(a: [u32; 2]) -> u32 {
+    match a {
+        [x, _] => x,
+    }
+}
+
+#[ensures(sign(-5i32) == -1)]
+#[ensures(digit_class(50u8) == 1)]
+#[ensures(is_lower('q'))]
+#[ensures(option_value(Some(3u32)) == 2)]
+pub fn check() {}
+
+#[requires(s@.len() == 1)]
+#[ensures(result == head_or_zero(s))]
+pub fn head(s: &[u32]) -> u32 {
+    s[0]
+}
+
+#[ensures(result == first_of_pair(a))]
+pub fn first(a: [u32; 2]) -> u32 {
+    a[0]
+}
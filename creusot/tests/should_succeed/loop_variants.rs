@@ -0,0 +1,71 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[terminates]
+#[ensures(result == 0u64)]
+pub fn while_loop(mut n: u64) -> u64 {
+    #[variant(n)]
+    while n > 0 {
+        n -= 1;
+    }
+    n
+}
+
+#[pure]
+#[requires(n@ <= 100)]
+#[ensures(result@ == n@ * (n@ + 1) / 2)]
+pub fn sum_loop(n: u32) -> u32 {
+    let mut i = 0;
+    let mut sum = 0;
+    #[invariant(i <= n)]
+    #[invariant(sum@ == i@ * (i@ + 1) / 2)]
+    #[variant(n@ - i@)]
+    loop {
+        if i == n {
+            return sum;
+        }
+        i += 1;
+        sum += i;
+    }
+}
+
+#[requires(v@.len() <= 1000)]
+#[ensures(result@ <= v@.len())]
+pub fn for_loop(v: &Vec<bool>) -> usize {
+    let mut count = 0;
+    #[variant(v@.len() - produced.len())]
+    #[invariant(count@ <= produced.len())]
+    for b in v.iter() {
+        if *b {
+            count += 1;
+        }
+    }
+    count
+}
+
+#[terminates]
+pub fn ghost_variant(v: &Vec<u8>) {
+    let mut i = 0;
+    let mut n = snapshot! { v@.len() };
+    #[invariant(i@ <= v@.len())]
+    #[invariant(*n == v@.len() - i@)]
+    #[variant(*n)]
+    while i < v.len() {
+        i += 1;
+        n = snapshot! { *n - 1 };
+    }
+}
+
+#[terminates]
+pub fn nested(n: usize) {
+    let mut i = n;
+    #[variant(i)]
+    while i > 0 {
+        i -= 1;
+        let mut j = i;
+        #[variant(&j)]
+        while j > 0 {
+            j -= 1;
+        }
+    }
+}
@@ -0,0 +1,39 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[panics_if(x == 0u32)]
+pub fn nonzero(x: u32) -> u32 {
+    assert!(x != 0);
+    x
+}
+
+#[requires(y > 0u32)]
+pub fn call_nonzero(y: u32) -> u32 {
+    nonzero(y)
+}
+
+#[may_panic]
+pub fn fail_if(b: bool) {
+    if b {
+        panic!("failed")
+    }
+}
+
+#[may_panic]
+#[ensures(result == x)]
+pub fn propagate(x: u32) -> u32 {
+    fail_if(x > 10);
+    nonzero(x)
+}
+
+#[panics_if(x > 10u32 || y > 10u32)]
+#[ensures(result == x + y)]
+pub fn both(x: u32, y: u32) -> u32 {
+    if x > 10 {
+        unreachable!()
+    }
+    if y > 10 {
+        panic!("too large")
+    }
+    x + y
+}
@@ -0,0 +1,59 @@
+extern crate creusot_contracts;
+use creusot_contracts::{ptr_own::PtrOwn, *};
+use std::ptr::NonNull;
+
+// `PtrOwn::new` and `PtrOwn::alloc` panic on zero-sized types, which Creusot does not know
+// `i32`, `u64` and `u8` are not.
+
+#[may_panic]
+#[ensures(result@ == 3)]
+pub fn boxed() -> i32 {
+    let (ptr, mut own) = PtrOwn::new(1i32);
+    *PtrOwn::as_mut(ptr, &mut own) += 2;
+    proof_assert!(own.val() == Some(3i32));
+    *PtrOwn::to_box(ptr, own)
+}
+
+#[requires(p == own_p.ptr() && own_p.val() != None)]
+#[requires(q == own_q.ptr() && own_q.val() != None)]
+#[ensures((^own_p).ptr() == p && (^own_q).ptr() == q)]
+#[ensures((^own_p).val() == (*own_q).val() && (^own_q).val() == (*own_p).val())]
+pub fn swap(p: *const u32, q: *const u32, own_p: &mut PtrOwn<u32>, own_q: &mut PtrOwn<u32>) {
+    let x = PtrOwn::read(p, own_p);
+    let y = PtrOwn::read(q, own_q);
+    PtrOwn::write(p, own_p, y);
+    PtrOwn::write(q, own_q, x);
+}
+
+#[may_panic]
+pub fn alloc_write() {
+    let (ptr, mut own) = PtrOwn::<u64>::alloc();
+    PtrOwn::write(ptr, &mut own, 42);
+    let v = PtrOwn::take(ptr, &mut own);
+    proof_assert!(v@ == 42);
+    PtrOwn::dealloc(ptr, own);
+}
+
+#[may_panic]
+pub fn non_null() {
+    let (ptr, own) = PtrOwn::new(5u8);
+    let nn = NonNull::new(ptr as *mut u8).unwrap();
+    let p = nn.as_ptr().wrapping_add(2).wrapping_sub(2);
+    let b = PtrOwn::to_box(p, own);
+    proof_assert!(*b == 5u8);
+}
+
+pub fn address_of(x: &mut u32) -> *const u32 {
+    let p = std::ptr::addr_of_mut!(*x);
+    p as *const u32
+}
+
+#[may_panic]
+pub fn offset() {
+    let (ptr, own) = PtrOwn::new(7u16);
+    let end = PtrOwn::offset(ptr, &own, 1);
+    let p = PtrOwn::offset(end, &own, -1);
+    proof_assert!(end == ptr.byte_offset_logic(creusot_contracts::std::mem::size_of_logic::<u16>()));
+    let b = PtrOwn::to_box(unsafe { p.add(0) }, own);
+    proof_assert!(*b == 7u16);
+}
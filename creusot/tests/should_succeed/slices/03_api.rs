@@ -0,0 +1,56 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[ensures((^s)@.permutation_of(s@))]
+#[ensures(forall<i: Int, j: Int> 0 <= i && i <= j && j < s@.len() ==> (^s)[i] <= (^s)[j])]
+pub fn sort_ints(s: &mut [u32]) {
+    s.sort();
+}
+
+#[ensures((^s)@.permutation_of(s@))]
+#[ensures(forall<i: Int, j: Int> 0 <= i && i <= j && j < s@.len() ==> (^s)[i] <= (^s)[j])]
+pub fn sort_unstable_ints(s: &mut [u32]) {
+    s.sort_unstable();
+}
+
+#[ensures((^s)@ == s@.reverse())]
+pub fn reverse(s: &mut [u32]) {
+    s.reverse();
+}
+
+#[requires(s@.len() == 3)]
+#[ensures((^s)[0] == s[1] && (^s)[1] == s[2] && (^s)[2] == s[0])]
+pub fn rotate_one(s: &mut [u32]) {
+    s.rotate_left(1);
+}
+
+#[ensures(forall<i: Int> 0 <= i && i < s@.len() ==> (^s)[i] == 0u32)]
+pub fn zero(s: &mut [u32]) {
+    s.fill(0);
+}
+
+#[requires(s@.len() > 0 && s[0] == 1u32)]
+pub fn search(s: &[u32]) {
+    let c = s.contains(&1);
+    proof_assert! { s@[0] == 1u32 };
+    assert!(c);
+    assert!(s.starts_with(&s[..1]));
+}
+
+#[ensures(result@ == s@)]
+pub fn copy(s: &[u32]) -> Vec<u32> {
+    s.to_vec()
+}
+
+#[ensures(result@ == a@.concat(b@))]
+pub fn concat(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let parts = [a, b];
+    let r = parts.concat();
+    proof_assert! { parts@[1]@.concat(Seq::EMPTY) == parts@[1]@ };
+    r
+}
+
+#[ensures((^s)@.permutation_of(s@))]
+pub fn sort_by_desc(s: &mut [u32]) {
+    s.sort_by(|a, b| b.cmp(a));
+}
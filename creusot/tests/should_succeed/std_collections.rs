@@ -0,0 +1,77 @@
+extern crate creusot_contracts;
+use creusot_contracts::{
+    std::collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    *,
+};
+
+#[ensures(result@ == 1)]
+pub fn hash_map_insert_get() -> u32 {
+    let mut m = HashMap::new();
+    m.insert(1u64, 1u32);
+    m.insert(2u64, 2u32);
+    match m.get(&1) {
+        Some(v) => *v,
+        None => 0,
+    }
+}
+
+#[requires(m@.contains(k@) ==> m@.lookup(k@)@ < 1000)]
+#[ensures((^m)@.contains(k@))]
+#[ensures(m@.contains(k@) ==> (^m)@.lookup(k@)@ == m@.lookup(k@)@ + 1)]
+#[ensures(!m@.contains(k@) ==> (^m)@.lookup(k@)@ == 1)]
+pub fn count(m: &mut HashMap<u64, u32>, k: u64) {
+    *m.entry(k).or_insert(0) += 1;
+}
+
+#[requires(m@.contains(k@))]
+#[ensures((^m)@.lookup(k@) == 0u32)]
+#[ensures(forall<j: Int> j != k@ ==> (^m)@.get(j) == m@.get(j))]
+pub fn reset(m: &mut HashMap<u64, u32>, k: u64) {
+    if let Some(v) = m.get_mut(&k) {
+        *v = 0;
+    }
+}
+
+#[ensures(result)]
+pub fn hash_map_remove() -> bool {
+    let mut m = HashMap::new();
+    m.insert(7u64, true);
+    let r = m.remove(&7);
+    r == Some(true) && !m.contains_key(&7) && m.is_empty()
+}
+
+#[ensures(result == 2usize)]
+pub fn hash_set_dedup() -> usize {
+    let mut s = HashSet::new();
+    s.insert(3u32);
+    s.insert(5u32);
+    s.insert(3u32);
+    s.len()
+}
+
+#[ensures(result)]
+pub fn btree_map_ops() -> bool {
+    let mut m = BTreeMap::new();
+    m.insert(2i32, 20u32);
+    m.insert(1i32, 10u32);
+    m.get(&1) == Some(&10) && m.contains_key(&2) && m.len() == 2
+}
+
+#[ensures(result)]
+pub fn btree_set_ops() -> bool {
+    let mut s = BTreeSet::new();
+    let a = s.insert(4u8);
+    let b = s.insert(4u8);
+    a && !b && s.contains(&4) && s.remove(&4) && s.is_empty()
+}
+
+#[ensures(result@ == m@.len())]
+pub fn btree_map_count(m: &BTreeMap<u64, u32>) -> usize {
+    let mut n = 0;
+    #[invariant(n@ == produced.len())]
+    #[invariant(produced.len() <= m@.len())]
+    for _ in m.iter() {
+        n += 1;
+    }
+    n
+}
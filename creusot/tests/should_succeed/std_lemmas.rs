@@ -0,0 +1,31 @@
+// Translates `creusot_contracts::logic::lemmas` along with the proofs it gives, and uses some of them.
+extern crate creusot_contracts;
+use creusot_contracts::{logic::lemmas, *};
+
+#[path = "../../../creusot-contracts/src/logic/lemmas.rs"]
+pub mod proofs;
+
+#[requires(a@.permutation_of(b@))]
+#[requires(b@.permutation_of(c@))]
+pub fn permutations(a: &[u32], b: &[u32], c: &[u32]) {
+    proof_assert! {
+        lemmas::permutation_trans(a@, b@, c@);
+        lemmas::permutation_symm(a@, c@);
+        c@.permutation_of(a@)
+    };
+}
+
+pub fn occurrences(v: &Vec<u32>) {
+    proof_assert! {
+        lemmas::count_occ_concat(v@, v@, 0u32);
+        lemmas::count_occ(v@.concat(v@), 0u32) == 2 * lemmas::count_occ(v@, 0u32)
+    };
+}
+
+#[requires(v@.sorted())]
+#[requires(v@.len() > 0 ==> v[v@.len() - 1] <= x)]
+#[ensures((^v)@.sorted())]
+pub fn push_sorted(v: &mut Vec<u32>, x: u32) {
+    proof_assert! { lemmas::sorted_push(v@, x); true };
+    v.push(x);
+}
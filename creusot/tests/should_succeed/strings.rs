@@ -0,0 +1,57 @@
+extern crate creusot_contracts;
+use creusot_contracts::{std::string::int_of_str, *};
+use std::num::ParseIntError;
+
+pub fn literals() {
+    let s = "héllo";
+    proof_assert!(s@.len() == 5);
+    proof_assert!(s@[1] == 'é');
+    proof_assert!(s@.to_utf8().len() == 6);
+    let mut t = String::new();
+    t.push_str(s);
+    t.push('!');
+    proof_assert!(t@ == "héllo!"@);
+}
+
+#[ensures(result@ == s@.to_utf8().len())]
+pub fn byte_len(s: &str) -> usize {
+    s.len()
+}
+
+#[ensures(result == (c == ' ' || c == '\t'))]
+pub fn is_blank(c: char) -> bool {
+    c == ' ' || c == '\t'
+}
+
+#[ensures(result@ == c@)]
+pub fn code(c: char) -> u32 {
+    c as u32
+}
+
+#[requires(s@.len() < 1000)]
+#[ensures(result@ <= s@.len())]
+pub fn count_digits(s: &str) -> usize {
+    let mut n = 0;
+    #[invariant(n@ <= produced.len())]
+    for c in s.chars() {
+        if c.is_ascii_digit() {
+            n += 1;
+        }
+    }
+    n
+}
+
+#[ensures(result == (s@.len() > 0 && s@[0] == '#'))]
+pub fn is_comment(s: &str) -> bool {
+    s.starts_with('#')
+}
+
+#[ensures(result == None ==> forall<i: Int> 0 <= i && i < s@.len() ==> s@[i] != '=')]
+pub fn find_eq(s: &str) -> Option<usize> {
+    s.find('=')
+}
+
+#[ensures(forall<n: u32> result == Ok(n) ==> int_of_str(s@, false) == Some(n@))]
+pub fn parse_u32(s: &str) -> Result<u32, ParseIntError> {
+    s.parse()
+}
@@ -0,0 +1,50 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[logic]
+#[open]
+pub fn sorted_range(s: Seq<u32>, l: Int, u: Int) -> bool {
+    pearlite! {
+        forall<i: Int, j: Int> #[trigger(s[i], s[j])] l <= i && i < j && j < u ==> s[i] <= s[j]
+    }
+}
+
+#[logic]
+#[open]
+pub fn all_zero(s: Seq<u32>) -> bool {
+    pearlite! { forall<i: Int> #[trigger(s[i])] 0 <= i && i < s.len() ==> s[i] == 0u32 }
+}
+
+#[logic]
+#[open]
+pub fn has_zero(s: Seq<u32>) -> bool {
+    pearlite! { exists<i: Int> #[trigger(s[i])] 0 <= i && i < s.len() && s[i] == 0u32 }
+}
+
+#[logic]
+#[open]
+pub fn multi_trigger(s: Seq<u32>, t: Seq<u32>) -> bool {
+    pearlite! { forall<i: Int> #[trigger(s[i])] #[trigger(t[i])] s[i] == t[i] }
+}
+
+#[requires(all_zero(v@))]
+#[ensures(result == 0u32)]
+pub fn first(v: &Vec<u32>) -> u32 {
+    if v.len() == 0 {
+        0
+    } else {
+        v[0]
+    }
+}
+
+#[requires(sorted_range(v@, 0, v@.len()))]
+#[requires(v@.len() >= 2)]
+#[ensures(result)]
+pub fn first_two_sorted(v: &Vec<u32>) -> bool {
+    v[0] <= v[1]
+}
+
+#[requires(has_zero(v@))]
+#[requires(multi_trigger(v@, w@))]
+#[ensures(has_zero(w@))]
+pub fn copy_zero(v: &Vec<u32>, w: &Vec<u32>) {}
@@ -0,0 +1,46 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+pub union IntOrBool {
+    int: u32,
+    boolean: bool,
+}
+
+#[ensures(result == IntOrBool { int: x })]
+pub fn make_int(x: u32) -> IntOrBool {
+    IntOrBool { int: x }
+}
+
+#[requires(exists<x: u32> u == IntOrBool { int: x })]
+#[ensures(IntOrBool { int: result } == u)]
+pub fn read_int(u: IntOrBool) -> u32 {
+    unsafe { u.int }
+}
+
+#[ensures(result)]
+pub fn write_then_read() -> bool {
+    let mut u = IntOrBool { int: 3 };
+    u.boolean = true;
+    unsafe { u.boolean }
+}
+
+#[requires(exists<x: u32> *u == IntOrBool { int: x })]
+#[ensures(*u == IntOrBool { int: 0u32 } ==> ^u == IntOrBool { int: 1u32 })]
+#[ensures(*u != IntOrBool { int: 0u32 } ==> ^u == *u)]
+pub fn set_if_zero(u: &mut IntOrBool) {
+    if unsafe { u.int } == 0 {
+        u.int = 1;
+    }
+}
+
+#[repr(C)]
+pub union Bits {
+    float: f32,
+    int: u32,
+}
+
+#[trusted]
+pub fn to_bits(x: f32) -> u32 {
+    let b = Bits { float: x };
+    unsafe { b.int }
+}
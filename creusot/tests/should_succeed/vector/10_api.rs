@@ -0,0 +1,62 @@
+extern crate creusot_contracts;
+use creusot_contracts::*;
+
+#[requires(v@.len() >= 2)]
+#[ensures((^v)@ == v@.subsequence(0, 2))]
+pub fn truncate(v: &mut Vec<u32>) {
+    v.truncate(2);
+}
+
+#[requires(v@.len() == 3)]
+#[ensures(result == v[0])]
+#[ensures((^v)@.len() == 2 && (^v)[0] == v[2] && (^v)[1] == v[1])]
+pub fn swap_remove(v: &mut Vec<u32>) -> u32 {
+    v.swap_remove(0)
+}
+
+#[ensures(forall<i: Int> 0 <= i && i < (^v)@.len() ==> (^v)[i] != 0u32)]
+pub fn retain_nonzero(v: &mut Vec<u32>) {
+    v.retain(|x| *x != 0);
+}
+
+#[ensures(forall<i: Int> 0 <= i && i < (^v)@.len() - 1 ==> (^v)[i] != (^v)[i + 1])]
+pub fn dedup(v: &mut Vec<u32>) {
+    v.dedup();
+}
+
+#[requires(v@.len() >= 4)]
+#[ensures(result@ == v@.subsequence(1, 3))]
+#[ensures((^v)@.len() == v@.len() - 2)]
+pub fn drain_middle(v: &mut Vec<u32>) -> Vec<u32> {
+    v.drain(1..3).collect()
+}
+
+#[ensures((^v)@.len() == 0)]
+pub fn drain_all(v: &mut Vec<u32>) {
+    let d = v.drain(..);
+    let _ = d;
+}
+
+#[requires(v@.len() >= 1)]
+#[ensures(result@.len() == v@.len() - 1)]
+#[ensures((^v)@ == Seq::singleton(v[0]))]
+pub fn split_off(v: &mut Vec<u32>) -> Vec<u32> {
+    v.split_off(1)
+}
+
+#[ensures((^v)@ == v@.concat(w@))]
+#[ensures((^w)@.len() == 0)]
+pub fn append(v: &mut Vec<u32>, w: &mut Vec<u32>) {
+    v.append(w);
+}
+
+#[ensures((^v)@ == v@.concat(s@))]
+pub fn extend_from_slice(v: &mut Vec<u32>, s: &[u32]) {
+    v.extend_from_slice(s);
+}
+
+#[ensures((^v)@.len() == 10)]
+#[ensures(forall<i: Int> v@.len() <= i && i < 10 ==> (^v)[i] == 7u32)]
+pub fn resize(v: &mut Vec<u32>) {
+    v.resize(10, 7);
+}
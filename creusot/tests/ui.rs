@@ -101,6 +101,12 @@ fn run_creusot(
     cmd.arg("--why3-config-file").arg(&config_paths.why3_config);
 
     cmd.args(&["--", "-Zno-codegen", "--crate-type=lib"]);
+    // Tests can pick their edition by writing `EDITION=<year>` on their first line.
+    if let Some(edition) =
+        header_line.split("EDITION=").nth(1).and_then(|s| s.split_whitespace().next())
+    {
+        cmd.arg(format!("--edition={edition}"));
+    }
     cmd.args(&["--extern", &format!("creusot_contracts={}", creusot_contract_path)]);
 
     let mut dep_path = base_path;
@@ -12,6 +12,7 @@ use std::mem;
 mod kw {
     syn::custom_keyword!(forall);
     syn::custom_keyword!(exists);
+    syn::custom_keyword!(trigger);
     syn::custom_keyword!(absurd);
     syn::custom_keyword!(pearlite);
 }
@@ -441,6 +442,7 @@ ast_struct! {
         pub lt_token: Token![<],
         pub args: Punctuated<QuantArg, Token![,]>,
         pub gt_token: Token![>],
+        pub triggers: Vec<Trigger>,
 
         pub term: Box<Term>
     }
@@ -452,6 +454,7 @@ ast_struct! {
         pub lt_token: Token![<],
         pub args: Punctuated<QuantArg, Token![,]>,
         pub gt_token: Token![>],
+        pub triggers: Vec<Trigger>,
 
         pub term: Box<Term>
     }
@@ -465,6 +468,19 @@ ast_struct! {
     }
 }
 
+ast_struct! {
+    /// A trigger of a quantifier: `#[trigger(a, b)]`.
+    ///
+    /// The terms form a single multi-pattern, several triggers may be given as alternatives.
+    pub struct Trigger {
+        pub pound_token: Token![#],
+        pub bracket_token: token::Bracket,
+        pub trigger_token: kw::trigger,
+        pub paren_token: token::Paren,
+        pub terms: Punctuated<Term, Token![,]>,
+    }
+}
+
 ast_struct! {
     pub struct TermAbsurd {
         pub absurd_token: kw::absurd
@@ -1411,9 +1427,14 @@ pub(crate) mod parsing {
 
             let gt_token: Token![>] = input.parse()?;
 
+            let mut triggers = Vec::new();
+            while input.peek(Token![#]) {
+                triggers.push(input.parse()?);
+            }
+
             let term = input.parse()?;
 
-            Ok(TermForall { forall_token, lt_token, args, gt_token, term })
+            Ok(TermForall { forall_token, lt_token, args, gt_token, triggers, term })
         }
     }
 
@@ -1436,9 +1457,14 @@ pub(crate) mod parsing {
 
             let gt_token: Token![>] = input.parse()?;
 
+            let mut triggers = Vec::new();
+            while input.peek(Token![#]) {
+                triggers.push(input.parse()?);
+            }
+
             let term = input.parse()?;
 
-            Ok(TermExists { exists_token, lt_token, args, gt_token, term })
+            Ok(TermExists { exists_token, lt_token, args, gt_token, triggers, term })
         }
     }
 
@@ -1451,6 +1477,22 @@ pub(crate) mod parsing {
         }
     }
 
+    impl Parse for Trigger {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let pound_token = input.parse()?;
+            let attr;
+            let bracket_token = bracketed!(attr in input);
+            let trigger_token = attr.parse()?;
+            let content;
+            let paren_token = parenthesized!(content in attr);
+            let terms = content.parse_terminated(Term::parse, Token![,])?;
+            if terms.is_empty() {
+                return Err(content.error("expected at least one term in trigger"));
+            }
+            Ok(Trigger { pound_token, bracket_token, trigger_token, paren_token, terms })
+        }
+    }
+
     impl Parse for TermAbsurd {
         fn parse(input: ParseStream) -> Result<Self> {
             Ok(TermAbsurd { absurd_token: input.parse()? })
@@ -1949,6 +1991,9 @@ pub(crate) mod printing {
                 input.to_tokens(tokens);
             }
             self.gt_token.to_tokens(tokens);
+            for trigger in &self.triggers {
+                trigger.to_tokens(tokens);
+            }
             self.term.to_tokens(tokens);
         }
     }
@@ -1961,6 +2006,9 @@ pub(crate) mod printing {
                 input.to_tokens(tokens);
             }
             self.gt_token.to_tokens(tokens);
+            for trigger in &self.triggers {
+                trigger.to_tokens(tokens);
+            }
             self.term.to_tokens(tokens);
         }
     }
@@ -1973,6 +2021,18 @@ pub(crate) mod printing {
         }
     }
 
+    impl ToTokens for Trigger {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.pound_token.to_tokens(tokens);
+            self.bracket_token.surround(tokens, |tokens| {
+                self.trigger_token.to_tokens(tokens);
+                self.paren_token.surround(tokens, |tokens| {
+                    self.terms.to_tokens(tokens);
+                })
+            })
+        }
+    }
+
     impl ToTokens for TermAbsurd {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             self.absurd_token.to_tokens(tokens);
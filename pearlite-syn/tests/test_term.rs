@@ -104,6 +104,7 @@ fn test_forall() {
             },
         ],
         gt_token: Gt,
+        triggers: [],
         term: TermLit {
             lit: Lit::Bool {
                 value: true,
@@ -142,6 +143,94 @@ fn test_exists() {
             },
         ],
         gt_token: Gt,
+        triggers: [],
+        term: TermLit {
+            lit: Lit::Bool {
+                value: true,
+            },
+        },
+    }
+    "###);
+}
+
+#[test]
+fn test_forall_trigger() {
+    snapshot!(quote!(forall<x : Int> #[trigger(f(x))] true) as Term, @r###"
+    TermForall {
+        forall_token: Keyword [forall],
+        lt_token: Lt,
+        args: [
+            QuantArg {
+                ident: Ident(
+                    x,
+                ),
+                colon_token: Colon,
+                ty: Type::Path {
+                    qself: None,
+                    path: Path {
+                        leading_colon: None,
+                        segments: [
+                            PathSegment {
+                                ident: Ident(
+                                    Int,
+                                ),
+                                arguments: PathArguments::None,
+                            },
+                        ],
+                    },
+                },
+            },
+        ],
+        gt_token: Gt,
+        triggers: [
+            Trigger {
+                pound_token: Pound,
+                bracket_token: Bracket,
+                trigger_token: Keyword [trigger],
+                paren_token: Paren,
+                terms: [
+                    TermCall {
+                        func: TermPath {
+                            inner: ExprPath {
+                                attrs: [],
+                                qself: None,
+                                path: Path {
+                                    leading_colon: None,
+                                    segments: [
+                                        PathSegment {
+                                            ident: Ident(
+                                                f,
+                                            ),
+                                            arguments: PathArguments::None,
+                                        },
+                                    ],
+                                },
+                            },
+                        },
+                        paren_token: Paren,
+                        args: [
+                            TermPath {
+                                inner: ExprPath {
+                                    attrs: [],
+                                    qself: None,
+                                    path: Path {
+                                        leading_colon: None,
+                                        segments: [
+                                            PathSegment {
+                                                ident: Ident(
+                                                    x,
+                                                ),
+                                                arguments: PathArguments::None,
+                                            },
+                                        ],
+                                    },
+                                },
+                            },
+                        ],
+                    },
+                ],
+            },
+        ],
         term: TermLit {
             lit: Lit::Bool {
                 value: true,
@@ -266,6 +266,13 @@ pub struct FunLitElt {
     pub value: ConcreteTerm,
 }
 
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+pub struct RecordField {
+    pub field: String,
+    pub value: ConcreteTerm,
+}
+
 #[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(Deserialize))]
 #[cfg_attr(feature = "serde", serde(tag = "type", content = "val"))]
@@ -334,6 +341,7 @@ pub enum ConcreteTerm {
         #[cfg_attr(feature = "serde", serde(rename = "funliteral_others"))]
         other: Box<ConcreteTerm>,
     },
+    Record(Vec<RecordField>),
     Proj {
         #[cfg_attr(feature = "serde", serde(rename = "proj_name"))]
         name: String,
@@ -36,6 +36,7 @@ pub enum Decl {
     Module(Module),
     TyDecl(TyDecl),
     PredDecl(Predicate),
+    IndDecl(IndDecl),
     Clone(DeclClone),
     UseDecl(Use),
     Axiom(Axiom),
@@ -130,6 +131,10 @@ pub struct Contract {
     pub requires: Vec<Exp>,
     pub ensures: Vec<Exp>,
     pub variant: Vec<Exp>,
+    /// Whether the function may exit by panicking
+    pub may_panic: bool,
+    /// Conditions under which the function panics, if any is given
+    pub panics_if: Vec<Exp>,
 }
 
 impl Contract {
@@ -138,13 +143,19 @@ impl Contract {
     }
 
     pub fn is_empty(&self) -> bool {
-        self.requires.is_empty() && self.ensures.is_empty() && self.variant.is_empty()
+        self.requires.is_empty()
+            && self.ensures.is_empty()
+            && self.variant.is_empty()
+            && self.panics_if.is_empty()
+            && !self.may_panic
     }
 
     pub fn extend(&mut self, other: Contract) {
         self.requires.extend(other.requires);
         self.ensures.extend(other.ensures);
         self.variant.extend(other.variant);
+        self.may_panic |= other.may_panic;
+        self.panics_if.extend(other.panics_if);
     }
 
     pub fn ensures_conj(&self) -> Exp {
@@ -156,6 +167,16 @@ impl Contract {
         postcond
     }
 
+    /// The condition under which the function panics, when `panics_if` clauses are given
+    pub fn panics_disj(&self) -> Option<Exp> {
+        let mut panics = self.panics_if.clone();
+
+        let cond = panics.pop()?;
+        let mut cond = panics.into_iter().rfold(cond, Exp::log_or);
+        cond.reassociate();
+        Some(cond)
+    }
+
     pub fn requires_conj(&self) -> Exp {
         let mut requires = self.requires.clone();
 
@@ -179,6 +200,10 @@ impl Contract {
             req_visitor.visit_mut(req);
         }
 
+        for panic in self.panics_if.iter_mut() {
+            req_visitor.visit_mut(panic);
+        }
+
         for ens in self.ensures.iter_mut() {
             ens_visitor.visit_mut(ens);
         }
@@ -195,6 +220,10 @@ impl Contract {
             qfvs.extend(req.qfvs());
         }
 
+        for panic in &self.panics_if {
+            qfvs.extend(panic.qfvs());
+        }
+
         for ens in &self.ensures {
             qfvs.extend(ens.qfvs());
         }
@@ -257,6 +286,22 @@ pub struct Predicate {
     pub body: Exp,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub enum IndKind {
+    Inductive,
+    Coinductive,
+}
+
+/// An (co)inductive predicate, given by its introduction rules
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct IndDecl {
+    pub kind: IndKind,
+    pub sig: Signature,
+    pub rules: Vec<(Ident, Exp)>,
+}
+
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 pub enum TyDecl {
@@ -74,6 +74,7 @@ impl Print for Decl {
             Decl::Module(modl) => modl.pretty(alloc),
             Decl::Scope(scope) => scope.pretty(alloc),
             Decl::PredDecl(p) => p.pretty(alloc),
+            Decl::IndDecl(i) => i.pretty(alloc),
             Decl::TyDecl(t) => t.pretty(alloc),
             Decl::Clone(c) => c.pretty(alloc),
             Decl::ValDecl(v) => v.pretty(alloc),
@@ -292,6 +293,33 @@ impl Print for Predicate {
     }
 }
 
+impl Print for IndDecl {
+    fn pretty<'b, 'a: 'b, A: DocAllocator<'a>>(&'a self, alloc: &'a A) -> DocBuilder<'a, A>
+    where
+        A::Doc: Clone,
+    {
+        let kind = match self.kind {
+            IndKind::Inductive => "inductive ",
+            IndKind::Coinductive => "coinductive ",
+        };
+        let rules = self.rules.iter().map(|(name, rule)| {
+            alloc
+                .text("| ")
+                .append(name.pretty(alloc))
+                .append(" :")
+                .append(alloc.line().append(rule.pretty(alloc)).nest(4))
+                .group()
+        });
+
+        alloc
+            .text(kind)
+            .append(self.sig.pretty(alloc).append(alloc.line_()).append(alloc.text(" =")))
+            .group()
+            .append(alloc.hardline())
+            .append(alloc.intersperse(rules, alloc.hardline()).indent(2))
+    }
+}
+
 fn arg_list<'b: 'a, 'a, A: DocAllocator<'a>>(alloc: &'a A, args: &'a [Binder]) -> DocBuilder<'a, A>
 where
     A::Doc: Clone,
@@ -669,7 +697,13 @@ impl Print for Exp {
                         ", ",
                     ),
                 )
-                .append(alloc.intersperse(trig.iter().map(|t| t.pretty(alloc)), alloc.space()))
+                .append(if trig.is_empty() {
+                    alloc.nil()
+                } else {
+                    alloc.space().append(
+                        alloc.intersperse(trig.iter().map(|t| t.pretty(alloc)), " | ").brackets(),
+                    )
+                })
                 .append(" . ")
                 .append(exp.pretty(alloc)),
             Exp::Exists(binders, trig, exp) => alloc
@@ -682,7 +716,13 @@ impl Print for Exp {
                         ", ",
                     ),
                 )
-                .append(alloc.intersperse(trig.iter().map(|t| t.pretty(alloc)), alloc.space()))
+                .append(if trig.is_empty() {
+                    alloc.nil()
+                } else {
+                    alloc.space().append(
+                        alloc.intersperse(trig.iter().map(|t| t.pretty(alloc)), " | ").brackets(),
+                    )
+                })
                 .append(" . ")
                 .append(exp.pretty(alloc)),
             Exp::Impl(hyp, exp) => {